@@ -0,0 +1,42 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Run with `--features rayon` to measure the parallel processing of the
+// segments.
+
+#![feature(test)]
+
+extern crate test;
+
+use core::num::NonZeroU32;
+
+use abcrypt::{Decryptor, Encryptor, argon2::Params};
+use test::Bencher;
+
+const PASSPHRASE: &str = "passphrase";
+const DATA_SIZE: usize = 16 * 1024 * 1024;
+const SEGMENT_SIZE: NonZeroU32 = NonZeroU32::new(64 * 1024).unwrap();
+
+fn encryptor(plaintext: &Vec<u8>) -> Encryptor<'_> {
+    Encryptor::with_params(plaintext, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+        .and_then(|c| c.with_segment_size(SEGMENT_SIZE))
+        .unwrap()
+}
+
+#[bench]
+fn encrypt_segmented(b: &mut Bencher) {
+    let plaintext = vec![u8::default(); DATA_SIZE];
+    let cipher = encryptor(&plaintext);
+    let mut buf = vec![u8::default(); cipher.out_len()];
+    b.iter(|| cipher.encrypt(&mut buf));
+}
+
+#[bench]
+fn decrypt_segmented(b: &mut Bencher) {
+    let plaintext = vec![u8::default(); DATA_SIZE];
+    let ciphertext = encryptor(&plaintext).encrypt_to_vec();
+    let cipher = Decryptor::new(&ciphertext, PASSPHRASE).unwrap();
+    let mut buf = vec![u8::default(); cipher.out_len()];
+    b.iter(|| cipher.decrypt(&mut buf).unwrap());
+}
@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! AEAD algorithms for encrypting the payload.
+
+#[cfg(feature = "aes-gcm-siv")]
+use aes_gcm_siv::{Aes256GcmSiv, Nonce};
+use chacha20poly1305::{AeadInPlace, Key, KeyInit, Tag, XChaCha20Poly1305, XNonce};
+
+use crate::{AAD, Error, Result};
+
+/// The AEAD algorithm used to encrypt the payload.
+///
+/// The AEAD other than [`Aead::XChaCha20Poly1305`] is stored in the header as
+/// version 2 of the abcrypt encrypted data format.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Aead {
+    /// XChaCha20-Poly1305.
+    ///
+    /// This is the default AEAD.
+    #[default]
+    XChaCha20Poly1305,
+
+    /// AES-256-GCM-SIV.
+    ///
+    /// This uses the first 12 bytes of the nonce stored in the header.
+    #[cfg(feature = "aes-gcm-siv")]
+    Aes256GcmSiv,
+}
+
+impl Aead {
+    /// Returns the identifier of this AEAD stored in the header.
+    pub(crate) const fn id(self) -> u8 {
+        match self {
+            Self::XChaCha20Poly1305 => 0,
+            #[cfg(feature = "aes-gcm-siv")]
+            Self::Aes256GcmSiv => 1,
+        }
+    }
+
+    /// Returns the AEAD of the identifier `id` stored in the header.
+    pub(crate) const fn from_id(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(Self::XChaCha20Poly1305),
+            #[cfg(feature = "aes-gcm-siv")]
+            1 => Ok(Self::Aes256GcmSiv),
+            id => Err(Error::UnsupportedAead(id)),
+        }
+    }
+
+    /// Returns the number of bytes of the nonce used by this AEAD.
+    pub(crate) const fn nonce_size(self) -> usize {
+        match self {
+            Self::XChaCha20Poly1305 => 24,
+            #[cfg(feature = "aes-gcm-siv")]
+            Self::Aes256GcmSiv => 12,
+        }
+    }
+
+    /// Returns the nonce for the segment `index` of the segmented payload.
+    ///
+    /// This follows the STREAM construction: the last 5 bytes of the nonce
+    /// used by this AEAD are replaced with the 32-bit big-endian segment
+    /// index and the flag which marks the last segment.
+    pub(crate) fn segment_nonce(self, nonce: &XNonce, index: u32, last: bool) -> XNonce {
+        let mut nonce = *nonce;
+        let end = self.nonce_size();
+        nonce[(end - 5)..(end - 1)].copy_from_slice(&index.to_be_bytes());
+        nonce[end - 1] = last.into();
+        nonce
+    }
+
+    /// Encrypts `buf` in place, and returns the authentication tag.
+    ///
+    /// Returns [`Err`] if `buf` is too long to be encrypted with this AEAD.
+    pub(crate) fn encrypt_in_place_detached(
+        self,
+        key: &Key,
+        nonce: &XNonce,
+        buf: &mut [u8],
+    ) -> Result<Tag> {
+        match self {
+            Self::XChaCha20Poly1305 => {
+                XChaCha20Poly1305::new(key).encrypt_in_place_detached(nonce, AAD, buf)
+            }
+            #[cfg(feature = "aes-gcm-siv")]
+            Self::Aes256GcmSiv => Aes256GcmSiv::new(key).encrypt_in_place_detached(
+                Nonce::from_slice(&nonce[..12]),
+                AAD,
+                buf,
+            ),
+        }
+        .map_err(|_| Error::PlaintextTooLong)
+    }
+
+    /// Decrypts `buf` in place after verifying the authentication tag `tag`.
+    ///
+    /// `offset` is the byte offset of `buf` in the encrypted data, which is
+    /// reported if the authentication tag is invalid.
+    pub(crate) fn decrypt_in_place_detached(
+        self,
+        key: &Key,
+        nonce: &XNonce,
+        buf: &mut [u8],
+        tag: &Tag,
+        offset: u64,
+    ) -> Result<()> {
+        match self {
+            Self::XChaCha20Poly1305 => {
+                XChaCha20Poly1305::new(key).decrypt_in_place_detached(nonce, AAD, buf, tag)
+            }
+            #[cfg(feature = "aes-gcm-siv")]
+            Self::Aes256GcmSiv => Aes256GcmSiv::new(key).decrypt_in_place_detached(
+                Nonce::from_slice(&nonce[..12]),
+                AAD,
+                buf,
+                tag,
+            ),
+        }
+        .map_err(|_| Error::InvalidMac { offset })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(Aead::default(), Aead::XChaCha20Poly1305);
+    }
+
+    #[test]
+    fn id() {
+        assert_eq!(Aead::XChaCha20Poly1305.id(), 0);
+        #[cfg(feature = "aes-gcm-siv")]
+        assert_eq!(Aead::Aes256GcmSiv.id(), 1);
+    }
+
+    #[test]
+    fn segment_nonce() {
+        let nonce = XNonce::from([u8::MAX; 24]);
+        let segment_nonce = Aead::XChaCha20Poly1305.segment_nonce(&nonce, 0x0102_0304, true);
+        assert_eq!(segment_nonce[..19], [u8::MAX; 19]);
+        assert_eq!(segment_nonce[19..], [0x01, 0x02, 0x03, 0x04, 0x01]);
+        #[cfg(feature = "aes-gcm-siv")]
+        {
+            let segment_nonce = Aead::Aes256GcmSiv.segment_nonce(&nonce, 1, false);
+            assert_eq!(segment_nonce[..7], [u8::MAX; 7]);
+            assert_eq!(segment_nonce[7..12], [0x00, 0x00, 0x00, 0x01, 0x00]);
+        }
+    }
+
+    #[test]
+    fn from_id() {
+        assert_eq!(Aead::from_id(0).unwrap(), Aead::XChaCha20Poly1305);
+        #[cfg(feature = "aes-gcm-siv")]
+        assert_eq!(Aead::from_id(1).unwrap(), Aead::Aes256GcmSiv);
+        #[cfg(not(feature = "aes-gcm-siv"))]
+        assert_eq!(Aead::from_id(1).unwrap_err(), Error::UnsupportedAead(1));
+        assert_eq!(Aead::from_id(2).unwrap_err(), Error::UnsupportedAead(2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Aead::XChaCha20Poly1305,
+            &[Token::UnitVariant {
+                name: "Aead",
+                variant: "xChaCha20Poly1305",
+            }],
+        );
+        #[cfg(feature = "aes-gcm-siv")]
+        assert_tokens(
+            &Aead::Aes256GcmSiv,
+            &[Token::UnitVariant {
+                name: "Aead",
+                variant: "aes256GcmSiv",
+            }],
+        );
+    }
+}
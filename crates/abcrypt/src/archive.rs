@@ -0,0 +1,619 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Encrypted archives of multiple files.
+//!
+//! An archive is stored as a single abcrypt encrypted data whose plaintext
+//! consists of an index of the entries followed by the data of each entry. The
+//! payload is segmented, and each entry is read from only the segments which
+//! contain it, so an entry can be listed and extracted without decrypting the
+//! other entries. Since every segment is authenticated independently, the data
+//! of an entry is authenticated when it is extracted.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::{io::Cursor, time::Duration};
+//! #
+//! # use abcrypt::{
+//! #     archive::{Archive, Builder},
+//! #     argon2::Params,
+//! # };
+//! #
+//! let passphrase = "passphrase";
+//!
+//! let mut builder = Builder::new();
+//! builder
+//!     .append("data.txt", b"Hello, world!\n", 0o644, Duration::ZERO)
+//!     .unwrap();
+//! let params = Params::new(32, 3, 4, None).unwrap();
+//! let ciphertext = builder.encrypt(passphrase, params).unwrap();
+//!
+//! let mut archive = Archive::new(Cursor::new(ciphertext), passphrase).unwrap();
+//! assert_eq!(archive.list()[0].path(), "data.txt");
+//! let mut buf = Vec::new();
+//! archive.extract_one("data.txt", &mut buf).unwrap();
+//! assert_eq!(buf, b"Hello, world!\n");
+//! ```
+
+use core::{num::NonZeroU32, str, time::Duration};
+use std::{
+    fs::{self, File},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+    string::String,
+    time::SystemTime,
+    vec::Vec,
+};
+
+use argon2::Params;
+
+use crate::{Encryptor, Error, Result, SeekableDecryptor};
+
+/// The recommended segment size in bytes for encrypting an archive.
+///
+/// This is 64 KiB.
+pub const SEGMENT_SIZE: NonZeroU32 = NonZeroU32::new(64 * 1024).unwrap();
+
+/// The magic number of the archive.
+const MAGIC_NUMBER: &[u8; 7] = b"abcarch";
+
+/// The version of the archive.
+const VERSION: u8 = 1;
+
+/// The number of bytes of the magic number, the version and the length of the
+/// index.
+const HEADER_SIZE: usize = 12;
+
+/// The number of bytes of an entry in the index, excluding the path.
+const ENTRY_FIXED_SIZE: usize = 2 + 8 + 4 + 8 + 4;
+
+/// The bits of the mode which are read from the files and restored.
+///
+/// The setuid, setgid and sticky bits are excluded so that extracting the
+/// archive does not create files with the special bits chosen by whoever
+/// created it.
+#[cfg(unix)]
+const MODE_MASK: u32 = 0o777;
+
+/// An entry of an archive.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// #
+/// # use abcrypt::archive::Builder;
+/// #
+/// let mut builder = Builder::new();
+/// builder
+///     .append("data.txt", b"Hello, world!\n", 0o644, Duration::ZERO)
+///     .unwrap();
+/// let entry = &builder.entries()[0];
+/// assert_eq!(entry.path(), "data.txt");
+/// assert_eq!(entry.size(), 14);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    path: String,
+    size: u64,
+    mode: u32,
+    modified: Duration,
+    offset: u64,
+}
+
+impl Entry {
+    /// Gets the path of this entry.
+    ///
+    /// The components of the path are separated by `/`.
+    #[must_use]
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Gets the number of bytes of the data of this entry.
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Gets the file mode (the Unix permission bits) of this entry.
+    #[must_use]
+    #[inline]
+    pub const fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// Gets the modification time of this entry as the duration since the
+    /// Unix epoch.
+    #[must_use]
+    #[inline]
+    pub const fn modified(&self) -> Duration {
+        self.modified
+    }
+
+    /// Encodes this entry into the index.
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let path_len = u16::try_from(self.path.len()).expect("path should be at most 64 KiB");
+        buf.extend_from_slice(&path_len.to_le_bytes());
+        buf.extend_from_slice(self.path.as_bytes());
+        buf.extend_from_slice(&self.size.to_le_bytes());
+        buf.extend_from_slice(&self.mode.to_le_bytes());
+        buf.extend_from_slice(&self.modified.as_secs().to_le_bytes());
+        buf.extend_from_slice(&self.modified.subsec_nanos().to_le_bytes());
+    }
+
+    /// Decodes the entries from the index.
+    ///
+    /// The offsets of the entries start from `offset`.
+    fn decode_all(mut data: &[u8], mut offset: u64) -> Result<Vec<Self>> {
+        let mut entries: Vec<Self> = Vec::new();
+        while !data.is_empty() {
+            let path_len = data
+                .get(..2)
+                .map(|len| usize::from(u16::from_le_bytes([len[0], len[1]])))
+                .ok_or(Error::InvalidArchive)?;
+            let entry = data
+                .get(2..(ENTRY_FIXED_SIZE + path_len))
+                .ok_or(Error::InvalidArchive)?;
+            data = &data[(ENTRY_FIXED_SIZE + path_len)..];
+
+            let (path, fields) = entry.split_at(path_len);
+            let path = str::from_utf8(path).map_err(|_| Error::InvalidArchive)?;
+            if !is_valid_path(path) || entries.iter().any(|e| e.path == path) {
+                return Err(Error::InvalidArchive);
+            }
+            let size = u64::from_le_bytes(fields[..8].try_into().unwrap());
+            let mode = u32::from_le_bytes(fields[8..12].try_into().unwrap());
+            let secs = u64::from_le_bytes(fields[12..20].try_into().unwrap());
+            let nanos = u32::from_le_bytes(fields[20..].try_into().unwrap());
+            if nanos >= 1_000_000_000 {
+                return Err(Error::InvalidArchive);
+            }
+            entries.push(Self {
+                path: path.into(),
+                size,
+                mode,
+                modified: Duration::new(secs, nanos),
+                offset,
+            });
+            offset = offset.checked_add(size).ok_or(Error::InvalidArchive)?;
+        }
+        Ok(entries)
+    }
+}
+
+/// Builder for an archive.
+///
+/// The data of the entries are kept in memory until the archive is encrypted.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    entries: Vec<Entry>,
+    data: Vec<u8>,
+}
+
+impl Builder {
+    /// Creates a new empty `Builder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::archive::Builder;
+    /// #
+    /// let builder = Builder::new();
+    /// assert!(builder.entries().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an entry with `data` to the archive.
+    ///
+    /// `path` is a relative path whose components are separated by `/`, and
+    /// `modified` is the modification time as the duration since the Unix
+    /// epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `path` is not a valid relative path, or an entry
+    /// with the same path already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// #
+    /// # use abcrypt::archive::Builder;
+    /// #
+    /// let mut builder = Builder::new();
+    /// builder
+    ///     .append("dir/data.txt", b"Hello, world!\n", 0o644, Duration::ZERO)
+    ///     .unwrap();
+    /// assert!(
+    ///     builder
+    ///         .append("../data.txt", b"Hello, world!\n", 0o644, Duration::ZERO)
+    ///         .is_err()
+    /// );
+    /// ```
+    pub fn append(
+        &mut self,
+        path: impl Into<String>,
+        data: impl AsRef<[u8]>,
+        mode: u32,
+        modified: Duration,
+    ) -> Result<&mut Self> {
+        let path = path.into();
+        if !is_valid_path(&path) || self.entries.iter().any(|e| e.path == path) {
+            return Err(Error::InvalidArchive);
+        }
+        let data = data.as_ref();
+        self.entries.push(Entry {
+            path,
+            size: data.len() as u64,
+            mode,
+            modified,
+            offset: self.data.len() as u64,
+        });
+        self.data.extend_from_slice(data);
+        Ok(self)
+    }
+
+    /// Appends the file at `src` to the archive as `path`.
+    ///
+    /// The mode and the modification time are read from the file. The setuid,
+    /// setgid and sticky bits of the mode are not stored. On non-Unix
+    /// platforms, the mode is `0o644`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if reading the file fails or [`Builder::append`]
+    /// returns an error.
+    pub fn append_file(
+        &mut self,
+        path: impl Into<String>,
+        src: impl AsRef<Path>,
+    ) -> io::Result<&mut Self> {
+        let src = src.as_ref();
+        let data = fs::read(src)?;
+        let (mode, modified) = file_attributes(&fs::metadata(src)?);
+        Ok(self.append(path, data, mode, modified)?)
+    }
+
+    /// Appends the regular files in the directory `src` recursively to the
+    /// archive under `path`.
+    ///
+    /// If `path` is empty, the files are appended at the top level of the
+    /// archive. Directories are not stored as entries, and the entries other
+    /// than the regular files and the directories, such as the symbolic links,
+    /// are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if reading the directory fails, the file names are not
+    /// valid UTF-8, or [`Builder::append`] returns an error.
+    pub fn append_dir_all(&mut self, path: &str, src: impl AsRef<Path>) -> io::Result<&mut Self> {
+        let mut entries = fs::read_dir(src)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(fs::DirEntry::file_name);
+        for entry in entries {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_str().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "file name is not valid UTF-8")
+            })?;
+            let name = if path.is_empty() {
+                file_name.into()
+            } else {
+                format!("{path}/{file_name}")
+            };
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                self.append_dir_all(&name, entry.path())?;
+            } else if file_type.is_file() {
+                self.append_file(name, entry.path())?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Gets the entries of the archive.
+    #[must_use]
+    #[inline]
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the plaintext of the archive.
+    ///
+    /// The plaintext should be encrypted by [`Encryptor`] with
+    /// [`Encryptor::with_segment_size`] so that the entries can be extracted
+    /// individually. [`SEGMENT_SIZE`] is recommended as the segment size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of the entries is larger than 4 GiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::Cursor, time::Duration};
+    /// #
+    /// # use abcrypt::{
+    /// #     Encryptor,
+    /// #     archive::{self, Archive, Builder},
+    /// #     argon2::Params,
+    /// # };
+    /// #
+    /// let passphrase = "passphrase";
+    ///
+    /// let mut builder = Builder::new();
+    /// builder
+    ///     .append("data.txt", b"Hello, world!\n", 0o644, Duration::ZERO)
+    ///     .unwrap();
+    /// let plaintext = builder.build();
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(&plaintext, passphrase, params)
+    ///     .and_then(|c| c.with_segment_size(archive::SEGMENT_SIZE))
+    ///     .unwrap();
+    /// let ciphertext = cipher.encrypt_to_vec();
+    ///
+    /// let archive = Archive::new(Cursor::new(ciphertext), passphrase).unwrap();
+    /// assert_eq!(archive.list().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn build(&self) -> Vec<u8> {
+        let mut index = Vec::new();
+        for entry in &self.entries {
+            entry.encode(&mut index);
+        }
+        let index_len = u32::try_from(index.len()).expect("index should be at most 4 GiB");
+
+        let mut buf = Vec::with_capacity(HEADER_SIZE + index.len() + self.data.len());
+        buf.extend_from_slice(MAGIC_NUMBER);
+        buf.push(VERSION);
+        buf.extend_from_slice(&index_len.to_le_bytes());
+        buf.extend_from_slice(&index);
+        buf.extend_from_slice(&self.data);
+        buf
+    }
+
+    /// Encrypts the archive with the given Argon2 parameters into a newly
+    /// allocated [`Vec`].
+    ///
+    /// The payload is segmented by [`SEGMENT_SIZE`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the Argon2 context is invalid.
+    pub fn encrypt(&self, passphrase: impl AsRef<[u8]>, params: Params) -> Result<Vec<u8>> {
+        let plaintext = self.build();
+        let cipher = Encryptor::with_params(&plaintext, passphrase, params)?
+            .with_segment_size(SEGMENT_SIZE)?;
+        Ok(cipher.encrypt_to_vec())
+    }
+}
+
+/// Reader for an encrypted archive.
+///
+/// The errors of the abcrypt encrypted data format and the archive are
+/// returned as [`io::Error`] with the kind [`io::ErrorKind::InvalidData`], and
+/// the original [`Error`] can be retrieved by [`io::Error::get_ref`].
+#[derive(Debug)]
+pub struct Archive<R> {
+    inner: SeekableDecryptor<R>,
+    entries: Vec<Entry>,
+}
+
+impl<R: Read + Seek> Archive<R> {
+    /// Creates a new `Archive`.
+    ///
+    /// This decrypts the index of the entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by
+    /// [`SeekableDecryptor::new`] occurs, or the plaintext is not a valid
+    /// archive.
+    pub fn new(inner: R, passphrase: impl AsRef<[u8]>) -> io::Result<Self> {
+        let mut inner = SeekableDecryptor::new(inner, passphrase)?;
+        let len = inner.len();
+        if len < HEADER_SIZE as u64 {
+            return Err(Error::InvalidArchive.into());
+        }
+        let mut header = [u8::default(); HEADER_SIZE];
+        inner.read_exact(&mut header)?;
+        if &header[..7] != MAGIC_NUMBER || header[7] != VERSION {
+            return Err(Error::InvalidArchive.into());
+        }
+        let index_len = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+        let data_offset = (HEADER_SIZE as u64) + u64::from(index_len);
+        if data_offset > len {
+            return Err(Error::InvalidArchive.into());
+        }
+        let mut index = vec![u8::default(); index_len as usize];
+        inner.read_exact(&mut index)?;
+
+        let entries = Entry::decode_all(&index, data_offset)?;
+        let end = entries.last().map_or(data_offset, |e| e.offset + e.size);
+        if end != len {
+            return Err(Error::InvalidArchive.into());
+        }
+        Ok(Self { inner, entries })
+    }
+
+    /// Returns the entries of this archive.
+    #[must_use]
+    #[inline]
+    pub fn list(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Extracts the data of the entry at `path` to `writer`.
+    ///
+    /// Only the segments which contain the entry are decrypted. Returns the
+    /// number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the entry does not exist, decrypting the entry
+    /// fails, or writing to `writer` fails.
+    pub fn extract_one(&mut self, path: &str, writer: &mut impl Write) -> io::Result<u64> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|e| e.path == path)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such entry in archive"))?;
+        let (offset, size) = (entry.offset, entry.size);
+        self.inner.seek(SeekFrom::Start(offset))?;
+        io::copy(&mut (&mut self.inner).take(size), writer)
+    }
+
+    /// Extracts all entries into the directory `dir`.
+    ///
+    /// The parent directories of the entries are created if they do not exist,
+    /// and the existing files are overwritten. The modification time and, on
+    /// Unix, the mode of the entries are restored. The setuid, setgid and
+    /// sticky bits of the mode are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if decrypting any entry fails or writing the files
+    /// fails.
+    pub fn extract_all(&mut self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        for i in 0..self.entries.len() {
+            let entry = self.entries[i].clone();
+            let dst = entry
+                .path
+                .split('/')
+                .fold(dir.to_path_buf(), |p, c| p.join(c));
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = File::create(&dst)?;
+            self.extract_one(&entry.path, &mut file)?;
+            file.set_modified(SystemTime::UNIX_EPOCH + entry.modified)?;
+            // The mode is restored last because it may make the file read-only.
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+
+                fs::set_permissions(&dst, fs::Permissions::from_mode(entry.mode & MODE_MASK))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Unwraps this `Archive`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+/// Returns `true` if `path` is a relative path which can be extracted safely.
+fn is_valid_path(path: &str) -> bool {
+    u16::try_from(path.len()).is_ok()
+        && path
+            .split('/')
+            .all(|c| !c.is_empty() && c != "." && c != ".." && !c.contains(['\\', ':', '\0']))
+}
+
+/// Returns the mode and the modification time of the file.
+fn file_attributes(metadata: &fs::Metadata) -> (u32, Duration) {
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+
+        metadata.permissions().mode() & MODE_MASK
+    };
+    #[cfg(not(unix))]
+    let mode = 0o644;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    (mode, modified)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_valid_path() {
+        for path in ["a", "a/b", "a/b/c.txt", ".a", "a..b"] {
+            assert!(super::is_valid_path(path));
+        }
+        for path in [
+            "", "/a", "a/", "a//b", ".", "..", "a/../b", "a\\b", "C:a", "a\0",
+        ] {
+            assert!(!super::is_valid_path(path));
+        }
+    }
+
+    #[test]
+    fn encode_and_decode() {
+        let mut builder = Builder::new();
+        builder
+            .append("a", b"abc", 0o644, Duration::new(1, 2))
+            .unwrap()
+            .append("b/c", b"", 0o755, Duration::ZERO)
+            .unwrap();
+        let plaintext = builder.build();
+        let index_len = u32::from_le_bytes(plaintext[8..12].try_into().unwrap()) as usize;
+        assert_eq!(index_len, 2 * ENTRY_FIXED_SIZE + 4);
+
+        let entries =
+            Entry::decode_all(&plaintext[12..(12 + index_len)], (12 + index_len) as u64).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path(), "a");
+        assert_eq!(entries[0].offset, (12 + index_len) as u64);
+        assert_eq!(entries[0].modified(), Duration::new(1, 2));
+        assert_eq!(entries[1].path(), "b/c");
+        assert_eq!(entries[1].mode(), 0o755);
+        assert_eq!(entries[1].offset, (15 + index_len) as u64);
+    }
+
+    #[test]
+    fn decode_invalid_index() {
+        let mut builder = Builder::new();
+        builder.append("a", b"abc", 0o644, Duration::ZERO).unwrap();
+        let plaintext = builder.build();
+        let index = &plaintext[12..=(12 + ENTRY_FIXED_SIZE)];
+
+        assert_eq!(
+            Entry::decode_all(&index[..(index.len() - 1)], 0).unwrap_err(),
+            Error::InvalidArchive
+        );
+        let mut duplicate = index.to_vec();
+        duplicate.extend_from_slice(index);
+        assert_eq!(
+            Entry::decode_all(&duplicate, 0).unwrap_err(),
+            Error::InvalidArchive
+        );
+        let mut traversal = index.to_vec();
+        traversal[2] = b'/';
+        assert_eq!(
+            Entry::decode_all(&traversal, 0).unwrap_err(),
+            Error::InvalidArchive
+        );
+    }
+
+    #[test]
+    fn append_duplicate_path() {
+        let mut builder = Builder::new();
+        builder.append("a", b"abc", 0o644, Duration::ZERO).unwrap();
+        assert_eq!(
+            builder
+                .append("a", b"def", 0o644, Duration::ZERO)
+                .unwrap_err(),
+            Error::InvalidArchive
+        );
+    }
+}
@@ -4,24 +4,32 @@
 
 //! The Argon2 context.
 
+use core::{fmt, str::FromStr};
+
 use argon2::Algorithm;
 
-use crate::{Error, Result, format::Header};
+use crate::{Error, Kdf, Params, Result, TAG_SIZE, armor, format::Header, params};
 
 /// The Argon2 context used for the encrypted data.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "Argon2Repr", into = "Argon2Repr"))]
 pub struct Argon2 {
     variant: Algorithm,
     version: argon2::Version,
+    params: Params,
 }
 
 impl Argon2 {
     /// Creates a new instance of the Argon2 context from `ciphertext`.
     ///
+    /// `ciphertext` can be the [armored](crate::armor) data.
+    ///
     /// # Errors
     ///
     /// Returns [`Err`] if any of the following are true:
     ///
+    /// - `ciphertext` is armored and the ASCII armor is invalid.
     /// - `ciphertext` is shorter than 164 bytes.
     /// - The magic number is invalid.
     /// - The version number is the unsupported abcrypt version number.
@@ -29,6 +37,11 @@ impl Argon2 {
     /// - The Argon2 type is invalid.
     /// - The Argon2 version is invalid.
     /// - The Argon2 parameters are invalid.
+    /// - The extensions of the header are invalid.
+    /// - The header contains the unrecognized critical extension.
+    /// - The AEAD is unrecognized or not enabled.
+    /// - The passphrase normalization form is unrecognized or not enabled.
+    /// - The key derivation function is not Argon2.
     ///
     /// # Examples
     ///
@@ -42,14 +55,28 @@ impl Argon2 {
     #[inline]
     pub fn new(ciphertext: impl AsRef<[u8]>) -> Result<Self> {
         let inner = |ciphertext: &[u8]| -> Result<Self> {
-            let header = Header::parse(ciphertext)?;
-            let variant = header.argon2_type().into();
-            let version = header.argon2_version().into();
-            Ok(Self { variant, version })
+            let mut buf = [u8::default(); Header::MAX_SIZE + TAG_SIZE];
+            let ciphertext = armor::dearmor_header(ciphertext, &mut buf)?;
+            match Header::parse(ciphertext)?.kdf() {
+                Kdf::Argon2(argon2) => Ok(argon2),
+                #[allow(unreachable_patterns)]
+                kdf => Err(Error::UnsupportedKdf(kdf.id())),
+            }
         };
         inner(ciphertext.as_ref())
     }
 
+    /// Creates a new instance of the Argon2 context from the fields of the
+    /// header.
+    pub(crate) fn from_parts(variant: Variant, version: Version, params: Params) -> Self {
+        let (variant, version) = (variant.into(), version.into());
+        Self {
+            variant,
+            version,
+            params,
+        }
+    }
+
     /// Gets the Argon2 type.
     ///
     /// # Examples
@@ -85,10 +112,158 @@ impl Argon2 {
     pub const fn version(&self) -> argon2::Version {
         self.version
     }
+
+    /// Gets the Argon2 parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Argon2;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    ///
+    /// let argon2 = Argon2::new(ciphertext).unwrap();
+    /// assert_eq!(argon2.params().memory_cost(), 32);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn params(&self) -> Params {
+        self.params
+    }
+}
+
+impl fmt::Display for Argon2 {
+    /// Formats the Argon2 context as the [PHC string format].
+    ///
+    /// The salt and the hash are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Argon2;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    ///
+    /// let argon2 = Argon2::new(ciphertext).unwrap();
+    /// assert_eq!(format!("{argon2}"), "$argon2id$v=19$m=32,t=3,p=4");
+    /// ```
+    ///
+    /// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "${}$v={}${}",
+            self.variant(),
+            u32::from(self.version()),
+            self.params()
+        )
+    }
+}
+
+impl FromStr for Argon2 {
+    type Err = Error;
+
+    /// Parses the [PHC string format] into the Argon2 context.
+    ///
+    /// The string consists of the Argon2 type, the optional Argon2 version and
+    /// the Argon2 parameters, such as `$argon2id$v=19$m=19456,t=2,p=1`. If the
+    /// Argon2 version is omitted, it is assumed to be version 0x10. The salt
+    /// and the hash must not be included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `s` is not the valid PHC string.
+    /// - The Argon2 type is unrecognized.
+    /// - The Argon2 version is invalid.
+    /// - The Argon2 parameters are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{
+    /// #     Argon2,
+    /// #     argon2::{Algorithm, Version},
+    /// # };
+    /// #
+    /// let argon2: Argon2 = "$argon2id$v=19$m=19456,t=2,p=1".parse().unwrap();
+    /// assert_eq!(argon2.variant(), Algorithm::Argon2id);
+    /// assert_eq!(argon2.version(), Version::V0x13);
+    /// assert_eq!(argon2.params().memory_cost(), 19456);
+    /// ```
+    ///
+    /// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+    #[inline]
+    fn from_str(s: &str) -> Result<Self> {
+        let mut fields = s
+            .strip_prefix('$')
+            .ok_or(Error::InvalidPhcString)?
+            .split('$');
+        let variant = fields
+            .next()
+            .and_then(|id| id.parse().ok())
+            .ok_or(Error::InvalidPhcString)?;
+        let (version, params) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(version), Some(params), None) => {
+                let version = version
+                    .strip_prefix("v=")
+                    .and_then(params::parse_decimal)
+                    .ok_or(Error::InvalidPhcString)?;
+                let version = Version::try_from(version)?.into();
+                (version, params)
+            }
+            (Some(params), None, None) => (argon2::Version::V0x10, params),
+            _ => return Err(Error::InvalidPhcString),
+        };
+        let params = params.parse()?;
+        Ok(Self {
+            variant,
+            version,
+            params,
+        })
+    }
+}
+
+/// The serialized representation of [`Argon2`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Argon2", rename_all = "camelCase")]
+struct Argon2Repr {
+    variant: Variant,
+    version: Version,
+    params: Params,
+}
+
+#[cfg(feature = "serde")]
+impl From<Argon2Repr> for Argon2 {
+    #[inline]
+    fn from(argon2: Argon2Repr) -> Self {
+        Self {
+            variant: argon2.variant.into(),
+            version: argon2.version.into(),
+            params: argon2.params,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Argon2> for Argon2Repr {
+    #[inline]
+    fn from(argon2: Argon2) -> Self {
+        Self {
+            variant: argon2.variant().into(),
+            version: argon2.version().into(),
+            params: argon2.params(),
+        }
+    }
 }
 
 /// Type of Argon2.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[repr(u32)]
 pub enum Variant {
     /// Argon2d.
@@ -146,6 +321,8 @@ impl From<Algorithm> for Variant {
 
 /// Version of Argon2.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "u32", try_from = "u32"))]
 #[repr(u32)]
 pub enum Version {
     /// Version 0x10.
@@ -296,4 +473,42 @@ mod tests {
         assert_eq!(Version::from(argon2::Version::V0x10), Version::V0x10);
         assert_eq!(Version::from(argon2::Version::V0x13), Version::V0x13);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_variant() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Variant::Argon2d,
+            &[Token::UnitVariant {
+                name: "Variant",
+                variant: "argon2d",
+            }],
+        );
+        assert_tokens(
+            &Variant::Argon2i,
+            &[Token::UnitVariant {
+                name: "Variant",
+                variant: "argon2i",
+            }],
+        );
+        assert_tokens(
+            &Variant::Argon2id,
+            &[Token::UnitVariant {
+                name: "Variant",
+                variant: "argon2id",
+            }],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_version() {
+        use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+        assert_tokens(&Version::V0x10, &[Token::U32(0x10)]);
+        assert_tokens(&Version::V0x13, &[Token::U32(0x13)]);
+        assert_de_tokens_error::<Version>(&[Token::U32(0x12)], "invalid Argon2 version `0x12`");
+    }
 }
@@ -0,0 +1,541 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! ASCII armor for the abcrypt encrypted data format.
+//!
+//! The armored data is the encrypted data encoded with Base64, wrapped at 64
+//! characters per line, and enclosed between the [`BEGIN_LINE`] and the
+//! [`END_LINE`]. An optional checksum line, which is `=` followed by the
+//! Base64-encoded CRC-24 of the encrypted data, can precede the [`END_LINE`].
+//!
+//! ```text
+//! -----BEGIN ABCRYPT ENCRYPTED DATA-----
+//! YWJjcnlwdAECAAAAEwAAACAAAAADAAAABAAAAP7Su3Lz5Izzi8xtx0+HWoe426yB
+//! ...
+//! =pB5i
+//! -----END ABCRYPT ENCRYPTED DATA-----
+//! ```
+
+use base64ct::{Base64, Decoder, Encoding};
+
+use crate::{Error, Result};
+
+/// The line that marks the beginning of the armored data.
+pub const BEGIN_LINE: &str = "-----BEGIN ABCRYPT ENCRYPTED DATA-----";
+
+/// The line that marks the end of the armored data.
+pub const END_LINE: &str = "-----END ABCRYPT ENCRYPTED DATA-----";
+
+/// The number of Base64 characters per line.
+const LINE_WIDTH: usize = 64;
+
+/// The prefix of the checksum line.
+const CHECKSUM_PREFIX: u8 = b'=';
+
+/// The initial value of CRC-24.
+const CRC24_INIT: u32 = 0x00b7_04ce;
+
+/// The generator polynomial of CRC-24.
+const CRC24_POLY: u32 = 0x0186_4cfb;
+
+/// The mask of CRC-24.
+const CRC24_MASK: u32 = 0x00ff_ffff;
+
+/// Returns `true` if `data` is armored.
+///
+/// This only checks that `data` starts with the [`BEGIN_LINE`], ignoring
+/// leading whitespace.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+/// assert!(!abcrypt::armor::is_armored(ciphertext));
+///
+/// let armored = abcrypt::armor::encode(ciphertext);
+/// assert!(abcrypt::armor::is_armored(armored));
+/// # }
+/// ```
+#[must_use]
+#[inline]
+pub fn is_armored(data: impl AsRef<[u8]>) -> bool {
+    data.as_ref()
+        .trim_ascii_start()
+        .starts_with(BEGIN_LINE.as_bytes())
+}
+
+/// Encodes `data` into the armored data without the checksum.
+///
+/// # Examples
+///
+/// ```
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+///
+/// let armored = abcrypt::armor::encode(ciphertext);
+/// assert!(armored.starts_with("-----BEGIN ABCRYPT ENCRYPTED DATA-----\n"));
+/// assert!(armored.ends_with("-----END ABCRYPT ENCRYPTED DATA-----\n"));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+#[inline]
+pub fn encode(data: impl AsRef<[u8]>) -> alloc::string::String {
+    encode_inner(data.as_ref(), false, BEGIN_LINE, END_LINE)
+}
+
+/// Encodes `data` into the armored data with the checksum.
+///
+/// # Examples
+///
+/// ```
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+///
+/// let armored = abcrypt::armor::encode_with_checksum(ciphertext);
+/// assert!(armored.contains("\n=pB5i\n"));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+#[inline]
+pub fn encode_with_checksum(data: impl AsRef<[u8]>) -> alloc::string::String {
+    encode_inner(data.as_ref(), true, BEGIN_LINE, END_LINE)
+}
+
+/// Encodes `data` into the armored data with the checksum, enclosed between
+/// `begin_line` and `end_line` instead of the [`BEGIN_LINE`] and the
+/// [`END_LINE`].
+#[cfg(feature = "sharing")]
+pub(crate) fn encode_with_lines(
+    data: &[u8],
+    begin_line: &str,
+    end_line: &str,
+) -> alloc::string::String {
+    encode_inner(data, true, begin_line, end_line)
+}
+
+#[cfg(feature = "alloc")]
+fn encode_inner(
+    data: &[u8],
+    checksum: bool,
+    begin_line: &str,
+    end_line: &str,
+) -> alloc::string::String {
+    let encoded = Base64::encode_string(data);
+    let mut armored = alloc::string::String::with_capacity(
+        begin_line.len() + encoded.len() + encoded.len() / LINE_WIDTH + end_line.len() + 8,
+    );
+    armored.push_str(begin_line);
+    armored.push('\n');
+    for line in encoded.as_bytes().chunks(LINE_WIDTH) {
+        armored.push_str(core::str::from_utf8(line).expect("Base64 should be ASCII"));
+        armored.push('\n');
+    }
+    if checksum {
+        armored.push(char::from(CHECKSUM_PREFIX));
+        armored.push_str(&Base64::encode_string(&crc24(data).to_be_bytes()[1..]));
+        armored.push('\n');
+    }
+    armored.push_str(end_line);
+    armored.push('\n');
+    armored
+}
+
+/// Decodes the armored data `armored`.
+///
+/// The checksum is verified if it is present.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - The [`BEGIN_LINE`] or the [`END_LINE`] is missing.
+/// - The Base64 is invalid or is not wrapped at 64 characters per line.
+/// - The checksum does not match the decoded data.
+///
+/// # Examples
+///
+/// ```
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+///
+/// let armored = abcrypt::armor::encode_with_checksum(ciphertext);
+/// let decoded = abcrypt::armor::decode(armored).unwrap();
+/// assert_eq!(decoded, ciphertext);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn decode(armored: impl AsRef<[u8]>) -> Result<alloc::vec::Vec<u8>> {
+    decode_inner(armored.as_ref(), BEGIN_LINE, END_LINE)
+}
+
+/// Decodes the armored data `armored` enclosed between `begin_line` and
+/// `end_line` instead of the [`BEGIN_LINE`] and the [`END_LINE`].
+#[cfg(feature = "sharing")]
+pub(crate) fn decode_with_lines(
+    armored: &[u8],
+    begin_line: &str,
+    end_line: &str,
+) -> Result<alloc::vec::Vec<u8>> {
+    decode_inner(armored, begin_line, end_line)
+}
+
+#[cfg(feature = "alloc")]
+fn decode_inner(armored: &[u8], begin_line: &str, end_line: &str) -> Result<alloc::vec::Vec<u8>> {
+    let armored = Armored::parse(armored, begin_line, end_line)?;
+    let mut buf = alloc::vec::Vec::new();
+    if let Some(mut decoder) = armored.decoder()? {
+        decoder
+            .decode_to_end(&mut buf)
+            .map_err(|_| Error::InvalidArmor)?;
+    }
+    armored.verify_checksum()?;
+    Ok(buf)
+}
+
+/// Decodes at most the first `buf.len()` bytes of the armored data `armored`
+/// into `buf`.
+///
+/// The checksum is verified if it is present.
+pub(crate) fn decode_prefix<'b>(armored: &[u8], buf: &'b mut [u8]) -> Result<&'b [u8]> {
+    let armored = Armored::parse(armored, BEGIN_LINE, END_LINE)?;
+    armored.verify_checksum()?;
+    let Some(mut decoder) = armored.decoder()? else {
+        return Ok(&[]);
+    };
+    let len = decoder.remaining_len().min(buf.len());
+    decoder
+        .decode(&mut buf[..len])
+        .map_err(|_| Error::InvalidArmor)
+}
+
+/// Returns `ciphertext` as is, or at most the first `buf.len()` bytes of the
+/// decoded data if `ciphertext` is armored.
+///
+/// This is enough to parse the header without allocating.
+pub(crate) fn dearmor_header<'a>(ciphertext: &'a [u8], buf: &'a mut [u8]) -> Result<&'a [u8]> {
+    if is_armored(ciphertext) {
+        decode_prefix(ciphertext, buf)
+    } else {
+        Ok(ciphertext)
+    }
+}
+
+/// The parsed armored data.
+#[derive(Clone, Copy, Debug)]
+struct Armored<'a> {
+    body: &'a [u8],
+    checksum: Option<&'a [u8]>,
+}
+
+impl<'a> Armored<'a> {
+    /// Parses `data` enclosed between `begin_line` and `end_line` into the
+    /// Base64 body and the checksum.
+    fn parse(data: &'a [u8], begin_line: &str, end_line: &str) -> Result<Self> {
+        let data = data
+            .trim_ascii()
+            .strip_prefix(begin_line.as_bytes())
+            .and_then(|d| d.strip_suffix(end_line.as_bytes()))
+            .ok_or(Error::InvalidArmor)?;
+        let data = strip_line_ending(data).ok_or(Error::InvalidArmor)?;
+        if data.is_empty() {
+            return Ok(Self {
+                body: data,
+                checksum: None,
+            });
+        }
+        let data = strip_trailing_line_ending(data).ok_or(Error::InvalidArmor)?;
+        let last_line_start = data
+            .iter()
+            .rposition(|&b| b == b'\n' || b == b'\r')
+            .map_or(0, |pos| pos + 1);
+        let (body, checksum) = match data[last_line_start..].split_first() {
+            Some((&CHECKSUM_PREFIX, checksum)) => {
+                let body = &data[..last_line_start];
+                let body = strip_trailing_line_ending(body).unwrap_or(body);
+                (body, Some(checksum))
+            }
+            _ => (data, None),
+        };
+        Ok(Self { body, checksum })
+    }
+
+    /// Returns the Base64 decoder of the body, or [`None`] if the body is
+    /// empty.
+    fn decoder(&self) -> Result<Option<Decoder<'a, Base64>>> {
+        if self.body.is_empty() {
+            return Ok(None);
+        }
+        Decoder::new_wrapped(self.body, LINE_WIDTH)
+            .map(Some)
+            .map_err(|_| Error::InvalidArmor)
+    }
+
+    /// Verifies the checksum against the decoded body if it is present.
+    ///
+    /// The body is decoded in chunks, so this does not allocate.
+    fn verify_checksum(&self) -> Result<()> {
+        let Some(checksum) = self.checksum else {
+            return Ok(());
+        };
+        let mut crc = CRC24_INIT;
+        if let Some(mut decoder) = self.decoder()? {
+            let mut buf = [u8::default(); 48];
+            while decoder.remaining_len() > 0 {
+                let len = decoder.remaining_len().min(buf.len());
+                let chunk = decoder
+                    .decode(&mut buf[..len])
+                    .map_err(|_| Error::InvalidArmor)?;
+                crc = crc24_update(crc, chunk);
+            }
+        }
+        let mut buf = [u8::default(); 3];
+        match Base64::decode(checksum, &mut buf) {
+            Ok(checksum) if checksum == &(crc & CRC24_MASK).to_be_bytes()[1..] => Ok(()),
+            _ => Err(Error::InvalidArmor),
+        }
+    }
+}
+
+/// Strips the line ending at the start of `data`.
+fn strip_line_ending(data: &[u8]) -> Option<&[u8]> {
+    data.strip_prefix(b"\r\n")
+        .or_else(|| data.strip_prefix(b"\n"))
+        .or_else(|| data.strip_prefix(b"\r"))
+}
+
+/// Strips the line ending at the end of `data`.
+fn strip_trailing_line_ending(data: &[u8]) -> Option<&[u8]> {
+    data.strip_suffix(b"\r\n")
+        .or_else(|| data.strip_suffix(b"\n"))
+        .or_else(|| data.strip_suffix(b"\r"))
+}
+
+/// Computes the CRC-24 of `data` as defined in RFC 4880 Section 6.1.
+#[cfg(feature = "alloc")]
+const fn crc24(data: &[u8]) -> u32 {
+    crc24_update(CRC24_INIT, data) & CRC24_MASK
+}
+
+/// Updates the intermediate CRC-24 value `crc` with `data`.
+const fn crc24_update(mut crc: u32, data: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < data.len() {
+        crc ^= (data[i] as u32) << 16;
+        let mut j = 0;
+        while j < 8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_DATA_ENC: &[u8] = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn crc24() {
+        assert_eq!(super::crc24(&[]), 0x00b7_04ce);
+        assert_eq!(super::crc24(b"123456789"), 0x0021_cf02);
+    }
+
+    #[test]
+    fn strip_line_ending() {
+        assert_eq!(super::strip_line_ending(b"\r\nA").unwrap(), b"A");
+        assert_eq!(super::strip_line_ending(b"\nA").unwrap(), b"A");
+        assert_eq!(super::strip_line_ending(b"\rA").unwrap(), b"A");
+        assert!(super::strip_line_ending(b"A").is_none());
+    }
+
+    #[test]
+    fn strip_trailing_line_ending() {
+        assert_eq!(super::strip_trailing_line_ending(b"A\r\n").unwrap(), b"A");
+        assert_eq!(super::strip_trailing_line_ending(b"A\n").unwrap(), b"A");
+        assert_eq!(super::strip_trailing_line_ending(b"A\r").unwrap(), b"A");
+        assert!(super::strip_trailing_line_ending(b"A").is_none());
+    }
+
+    #[test]
+    fn parse() {
+        let armored = Armored::parse(
+            b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\nAAAA\n-----END ABCRYPT ENCRYPTED DATA-----",
+            BEGIN_LINE,
+            END_LINE,
+        )
+        .unwrap();
+        assert_eq!(armored.body, b"AAAA");
+        assert!(armored.checksum.is_none());
+
+        let armored = Armored::parse(
+            b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\r\nAAAA\r\n=AAAA\r\n-----END ABCRYPT ENCRYPTED DATA-----\r\n", BEGIN_LINE, END_LINE)
+        .unwrap();
+        assert_eq!(armored.body, b"AAAA");
+        assert_eq!(armored.checksum.unwrap(), b"AAAA");
+
+        let armored = Armored::parse(
+            b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\n-----END ABCRYPT ENCRYPTED DATA-----\n",
+            BEGIN_LINE,
+            END_LINE,
+        )
+        .unwrap();
+        assert!(armored.body.is_empty());
+        assert!(armored.checksum.is_none());
+    }
+
+    #[test]
+    fn parse_with_invalid_armor() {
+        assert_eq!(
+            Armored::parse(
+                b"AAAA\n-----END ABCRYPT ENCRYPTED DATA-----",
+                BEGIN_LINE,
+                END_LINE
+            )
+            .unwrap_err(),
+            Error::InvalidArmor
+        );
+        assert_eq!(
+            Armored::parse(
+                b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\nAAAA\n",
+                BEGIN_LINE,
+                END_LINE
+            )
+            .unwrap_err(),
+            Error::InvalidArmor
+        );
+        assert_eq!(
+            Armored::parse(
+                b"-----BEGIN ABCRYPT ENCRYPTED DATA-----AAAA\n-----END ABCRYPT ENCRYPTED DATA-----",
+                BEGIN_LINE,
+                END_LINE
+            )
+            .unwrap_err(),
+            Error::InvalidArmor
+        );
+        assert_eq!(
+            Armored::parse(
+                b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\nAAAA-----END ABCRYPT ENCRYPTED DATA-----",
+                BEGIN_LINE,
+                END_LINE
+            )
+            .unwrap_err(),
+            Error::InvalidArmor
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode() {
+        let armored = super::encode(TEST_DATA_ENC);
+        let lines = armored.lines().collect::<alloc::vec::Vec<_>>();
+        assert_eq!(lines.first().unwrap(), &BEGIN_LINE);
+        assert_eq!(lines.last().unwrap(), &END_LINE);
+        assert_eq!(lines.len(), 2 + TEST_DATA_ENC.len().div_ceil(48));
+        assert!(
+            lines[1..lines.len() - 2]
+                .iter()
+                .all(|l| l.len() == LINE_WIDTH)
+        );
+        assert!(!lines[lines.len() - 2].starts_with('='));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_with_checksum() {
+        let armored = super::encode_with_checksum(TEST_DATA_ENC);
+        let lines = armored.lines().collect::<alloc::vec::Vec<_>>();
+        assert_eq!(lines.len(), 3 + TEST_DATA_ENC.len().div_ceil(48));
+        assert_eq!(lines[lines.len() - 2].len(), 5);
+        assert!(lines[lines.len() - 2].starts_with('='));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn encode_empty_data() {
+        assert_eq!(
+            super::encode([]),
+            "-----BEGIN ABCRYPT ENCRYPTED DATA-----\n-----END ABCRYPT ENCRYPTED DATA-----\n"
+        );
+        assert_eq!(super::decode(super::encode([])).unwrap(), []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode() {
+        assert_eq!(
+            super::decode(super::encode(TEST_DATA_ENC)).unwrap(),
+            TEST_DATA_ENC
+        );
+        assert_eq!(
+            super::decode(super::encode_with_checksum(TEST_DATA_ENC)).unwrap(),
+            TEST_DATA_ENC
+        );
+        assert_eq!(
+            super::decode(super::encode(TEST_DATA_ENC).replace('\n', "\r\n")).unwrap(),
+            TEST_DATA_ENC
+        );
+        assert_eq!(
+            super::decode(alloc::format!("\n  {}  \n", super::encode(TEST_DATA_ENC))).unwrap(),
+            TEST_DATA_ENC
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_with_invalid_checksum() {
+        let armored = super::encode_with_checksum(TEST_DATA_ENC);
+        let (body, checksum) = armored.rsplit_once("\n=").unwrap();
+        let checksum = checksum.replacen(&checksum[..1], "A", 1);
+        let armored = alloc::format!("{body}\n={checksum}");
+        assert_eq!(super::decode(armored).unwrap_err(), Error::InvalidArmor);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn decode_with_invalid_base64() {
+        let armored = super::encode(TEST_DATA_ENC).replacen('A', "*", 1);
+        assert_eq!(super::decode(armored).unwrap_err(), Error::InvalidArmor);
+
+        let armored = super::encode(TEST_DATA_ENC).replacen('\n', "", 2);
+        assert_eq!(super::decode(armored).unwrap_err(), Error::InvalidArmor);
+    }
+
+    #[test]
+    fn decode_prefix() {
+        let armored = b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\nAQIDBAUG\n-----END ABCRYPT ENCRYPTED DATA-----\n";
+        let mut buf = [u8::default(); 4];
+        assert_eq!(
+            super::decode_prefix(armored, &mut buf).unwrap(),
+            [1, 2, 3, 4]
+        );
+
+        let mut buf = [u8::default(); 7];
+        assert_eq!(
+            super::decode_prefix(armored, &mut buf).unwrap(),
+            [1, 2, 3, 4, 5, 6]
+        );
+
+        let armored =
+            b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\n-----END ABCRYPT ENCRYPTED DATA-----\n";
+        let mut buf = [u8::default(); 4];
+        assert!(super::decode_prefix(armored, &mut buf).unwrap().is_empty());
+    }
+
+    #[test]
+    fn is_armored() {
+        assert!(!super::is_armored(TEST_DATA_ENC));
+        assert!(super::is_armored(
+            b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\n-----END ABCRYPT ENCRYPTED DATA-----\n"
+        ));
+        assert!(super::is_armored(
+            b" \n-----BEGIN ABCRYPT ENCRYPTED DATA-----\n-----END ABCRYPT ENCRYPTED DATA-----\n"
+        ));
+    }
+}
@@ -4,13 +4,27 @@
 
 //! Decrypts from the abcrypt encrypted data format.
 
-use argon2::Argon2;
-use chacha20poly1305::{AeadInPlace, KeyInit, Tag, XChaCha20Poly1305};
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::{ops::Range, time::Duration};
+
+use blake2::digest::MacError;
+use chacha20poly1305::Tag;
+#[cfg(feature = "ed25519")]
+use ed25519_dalek::VerifyingKey;
 
+#[cfg(feature = "sharing")]
+use crate::sharing::FileKey;
+#[cfg(feature = "ed25519")]
+use crate::signature;
 use crate::{
-    AAD, Error, HEADER_SIZE, Result, TAG_SIZE,
+    Aead, Candidate, Error, Kdf, KeyId, Normalization, Result, TAG_SIZE, Validity,
+    extension::Extensions,
     format::{DerivedKey, Header},
+    payload, segment,
 };
+#[cfg(feature = "alloc")]
+use crate::{Metadata, armor};
 
 /// Decryptor for the abcrypt encrypted data format.
 #[derive(Clone, Debug)]
@@ -18,7 +32,9 @@ pub struct Decryptor<'c> {
     header: Header,
     dk: DerivedKey,
     ciphertext: &'c [u8],
-    tag: Tag,
+    #[cfg_attr(not(feature = "ed25519"), allow(dead_code))]
+    signature: &'c [u8],
+    now: Option<Duration>,
 }
 
 impl<'c> Decryptor<'c> {
@@ -35,8 +51,17 @@ impl<'c> Decryptor<'c> {
     /// - The Argon2 type is invalid.
     /// - The Argon2 version is invalid.
     /// - The Argon2 parameters are invalid.
+    /// - The extensions of the header are invalid.
+    /// - The header contains the unrecognized critical extension.
+    /// - The AEAD is unrecognized or not enabled.
+    /// - The passphrase normalization form is unrecognized or not enabled.
     /// - The Argon2 context is invalid.
+    /// - The passphrase is required to be normalized and is not valid UTF-8.
     /// - The MAC (authentication tag) of the header is invalid.
+    /// - The key commitment of the header is invalid.
+    /// - The length of the segmented payload is invalid.
+    /// - The encrypted data is signed and shorter than the signature.
+    /// - The encrypted data is a record log.
     ///
     /// # Examples
     ///
@@ -50,58 +75,187 @@ impl<'c> Decryptor<'c> {
     /// ```
     pub fn new(ciphertext: &'c impl AsRef<[u8]>, passphrase: impl AsRef<[u8]>) -> Result<Self> {
         let inner = |ciphertext: &'c [u8], passphrase: &[u8]| -> Result<Self> {
+            let (header, dk) = open(ciphertext, passphrase)?;
+            let ciphertext = &ciphertext[header.size()..];
+            Self::with_ciphertext(header, dk, ciphertext)
+        };
+        inner(ciphertext.as_ref(), passphrase.as_ref())
+    }
+
+    /// Creates a new `Decryptor` with the [`FileKey`] instead of the
+    /// passphrase.
+    ///
+    /// The file key can be recovered from the key shares by
+    /// [`sharing::combine`](crate::sharing::combine).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by [`Decryptor::new`]
+    /// occurs. If `ciphertext` is not encrypted with the file key, this returns
+    /// [`Error::UnsupportedKdf`]. If `key` is not the file key of `ciphertext`,
+    /// this returns [`Error::InvalidHeaderMac`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, sharing::FileKey};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let key = FileKey::generate();
+    /// let ciphertext = Encryptor::with_file_key(data, &key).encrypt_to_vec();
+    ///
+    /// let cipher = Decryptor::with_file_key(&ciphertext, &key).unwrap();
+    /// # assert_eq!(cipher.decrypt_to_vec().unwrap(), data);
+    /// ```
+    #[cfg(feature = "sharing")]
+    pub fn with_file_key(ciphertext: &'c impl AsRef<[u8]>, key: &FileKey) -> Result<Self> {
+        let inner = |ciphertext: &'c [u8]| -> Result<Self> {
             let mut header = Header::parse(ciphertext)?;
+            let kdf = header.kdf();
+            if !matches!(kdf, Kdf::FileKey) {
+                return Err(Error::UnsupportedKdf(kdf.id()));
+            }
+            let dk = key.expand(&header.salt());
+            verify(&mut header, ciphertext, &dk)?;
+            let ciphertext = &ciphertext[header.size()..];
+            Self::with_ciphertext(header, dk, ciphertext)
+        };
+        inner(ciphertext.as_ref())
+    }
 
-            // The derived key size is 96 bytes. The first 256 bits are for
-            // XChaCha20-Poly1305 key, and the last 512 bits are for BLAKE2b-512-MAC key.
-            let mut dk = [u8::default(); DerivedKey::SIZE];
-            let argon2 = Argon2::new(
-                header.argon2_type().into(),
-                header.argon2_version().into(),
-                header.params().into(),
-            );
-            #[cfg(feature = "alloc")]
-            argon2
-                .hash_password_into(passphrase, &header.salt(), &mut dk)
-                .map_err(Error::InvalidArgon2Context)?;
-            #[cfg(not(feature = "alloc"))]
+    /// Creates a new `Decryptor` with the first matching passphrase in
+    /// `candidates`.
+    ///
+    /// The candidates are tried in order. If the header has the [`KeyId`], the
+    /// candidates with a different key identifier are skipped without deriving
+    /// the key, so only the candidates which can match cost the key
+    /// derivation. This returns the index of the matching candidate in
+    /// `candidates` and the `Decryptor`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - None of the candidates matches the passphrase. In this case, this
+    ///   returns [`Error::InvalidHeaderMac`].
+    /// - Any of the errors returned by [`Decryptor::new`] other than the
+    ///   invalid MAC of the header occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Candidate, Decryptor};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let candidates = [Candidate::new("password"), Candidate::new("passphrase")];
+    ///
+    /// let (index, cipher) = Decryptor::with_any(&ciphertext, &candidates).unwrap();
+    /// assert_eq!(index, 1);
+    /// ```
+    pub fn with_any(
+        ciphertext: &'c impl AsRef<[u8]>,
+        candidates: &[Candidate<'_>],
+    ) -> Result<(usize, Self)> {
+        let inner = |ciphertext: &'c [u8]| -> Result<(usize, Self)> {
+            let header = Header::parse(ciphertext)?;
+            let key_id = header.key_id();
+            for (i, candidate) in candidates
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.matches(key_id))
             {
-                let mut memory_blocks = crate::MEMORY_BLOCKS;
-                argon2
-                    .hash_password_into_with_memory(
-                        passphrase,
-                        &header.salt(),
-                        &mut dk,
-                        &mut memory_blocks,
-                    )
-                    .map_err(Error::InvalidArgon2Context)?;
+                match unlock(header.clone(), ciphertext, candidate.passphrase()) {
+                    Ok((header, dk)) => {
+                        let ciphertext = &ciphertext[header.size()..];
+                        return Self::with_ciphertext(header, dk, ciphertext).map(|c| (i, c));
+                    }
+                    Err(Error::InvalidHeaderMac(_) | Error::InvalidPassphrase) => {}
+                    Err(err) => return Err(err),
+                }
             }
-            let dk = DerivedKey::new(dk);
-
-            header.verify_mac(&dk.mac(), ciphertext[84..HEADER_SIZE].into())?;
-            let (ciphertext, tag) =
-                ciphertext[HEADER_SIZE..].split_at(ciphertext.len() - HEADER_SIZE - TAG_SIZE);
-            let tag = *Tag::from_slice(tag);
-            Ok(Self {
-                header,
-                dk,
-                ciphertext,
-                tag,
-            })
+            Err(Error::InvalidHeaderMac(MacError))
         };
-        inner(ciphertext.as_ref(), passphrase.as_ref())
+        inner(ciphertext.as_ref())
+    }
+
+    /// Creates a new `Decryptor` from the header and the ciphertext which are
+    /// stored separately.
+    ///
+    /// `header` and `ciphertext` are the parts of the encrypted data returned
+    /// by [`Encryptor::encrypt_detached`](crate::Encryptor::encrypt_detached).
+    /// Any bytes after the header in `header` are ignored. The byte offsets in
+    /// the errors are the offsets in the concatenation of `header` and
+    /// `ciphertext`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `header` is shorter than 148 bytes.
+    /// - `ciphertext` is shorter than 16 bytes.
+    /// - Any of the errors returned by [`Decryptor::new`] occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let (header, ciphertext) = ciphertext.split_at(148);
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::from_parts(&header, &ciphertext, passphrase).unwrap();
+    /// ```
+    pub fn from_parts(
+        header: &impl AsRef<[u8]>,
+        ciphertext: &'c impl AsRef<[u8]>,
+        passphrase: impl AsRef<[u8]>,
+    ) -> Result<Self> {
+        let inner = |data: &[u8], ciphertext: &'c [u8], passphrase: &[u8]| -> Result<Self> {
+            let header = Header::parse_detached(data)?;
+            let (header, dk) = unlock(header, data, passphrase)?;
+            Self::with_ciphertext(header, dk, ciphertext)
+        };
+        inner(header.as_ref(), ciphertext.as_ref(), passphrase.as_ref())
+    }
+
+    /// Creates a new `Decryptor` after validating the length of `ciphertext`.
+    ///
+    /// If the encrypted data is signed, the signature is split off from the
+    /// end of `ciphertext`.
+    const fn with_ciphertext(header: Header, dk: DerivedKey, ciphertext: &'c [u8]) -> Result<Self> {
+        let (ciphertext, signature) = match split_ciphertext(&header, ciphertext) {
+            Ok(parts) => parts,
+            Err(err) => return Err(err),
+        };
+        Ok(Self {
+            header,
+            dk,
+            ciphertext,
+            signature,
+            now: None,
+        })
     }
 
     /// Decrypts the ciphertext into `buf`.
     ///
+    /// Returns the number of bytes of the plaintext written into `buf`. If the
+    /// plaintext was padded or the metadata was encrypted together when
+    /// encrypting, they are removed and the bytes after the plaintext in `buf`
+    /// are unspecified.
+    ///
     /// # Errors
     ///
-    /// Returns [`Err`] if the MAC (authentication tag) of the ciphertext is
-    /// invalid.
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The encrypted data is used outside its validity period.
+    /// - The MAC (authentication tag) of the ciphertext is invalid.
+    /// - The padding of the plaintext is invalid.
+    /// - The metadata of the plaintext is invalid.
     ///
     /// # Panics
     ///
-    /// Panics if `buf` and the decrypted data have different lengths.
+    /// Panics if `buf` and [`Decryptor::out_len`] have different lengths.
     ///
     /// # Examples
     ///
@@ -114,32 +268,380 @@ impl<'c> Decryptor<'c> {
     ///
     /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
     /// let mut buf = [u8::default(); 14];
-    /// cipher.decrypt(&mut buf).unwrap();
+    /// let len = cipher.decrypt(&mut buf).unwrap();
+    /// assert_eq!(len, 14);
+    /// # assert_eq!(buf, *data);
+    /// ```
+    pub fn decrypt(&self, buf: &mut (impl AsMut<[u8]> + ?Sized)) -> Result<usize> {
+        let inner = |decryptor: &Self, buf: &mut [u8]| -> Result<usize> {
+            let (_, plaintext) = decryptor.decrypt_payload(buf)?;
+            let len = plaintext.len();
+            buf.copy_within(plaintext, 0);
+            Ok(len)
+        };
+        inner(self, buf.as_mut())
+    }
+
+    /// Decrypts the ciphertext into `buf`, returning an error if `buf` has the
+    /// wrong length.
+    ///
+    /// This is the same as [`Decryptor::decrypt`], except that this returns
+    /// [`Err`] instead of panicking if `buf` and [`Decryptor::out_len`] have
+    /// different lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `buf` and [`Decryptor::out_len`] have different
+    /// lengths or any of the errors returned by [`Decryptor::decrypt`] occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Error};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// let mut buf = [u8::default(); 15];
+    /// assert_eq!(
+    ///     cipher.try_decrypt(&mut buf).unwrap_err(),
+    ///     Error::InvalidOutputLength {
+    ///         expected: 14,
+    ///         actual: 15
+    ///     }
+    /// );
+    /// let mut buf = [u8::default(); 14];
+    /// let len = cipher.try_decrypt(&mut buf).unwrap();
+    /// assert_eq!(len, 14);
     /// # assert_eq!(buf, *data);
     /// ```
-    pub fn decrypt(&self, buf: &mut (impl AsMut<[u8]> + ?Sized)) -> Result<()> {
-        let inner = |decryptor: &Self, buf: &mut [u8]| -> Result<()> {
-            buf.copy_from_slice(decryptor.ciphertext);
+    pub fn try_decrypt(&self, buf: &mut (impl AsMut<[u8]> + ?Sized)) -> Result<usize> {
+        let buf = buf.as_mut();
+        let expected = self.out_len();
+        if buf.len() != expected {
+            return Err(Error::InvalidOutputLength {
+                expected,
+                actual: buf.len(),
+            });
+        }
+        self.decrypt(buf)
+    }
 
-            let cipher = XChaCha20Poly1305::new(&decryptor.dk.encrypt());
-            cipher.decrypt_in_place_detached(
-                &decryptor.header.nonce(),
-                AAD,
+    /// Returns the [`Kdf`] used to derive the key from the passphrase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Kdf};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert!(matches!(cipher.kdf(), Kdf::Argon2(_)));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kdf(&self) -> Kdf {
+        self.header.kdf()
+    }
+
+    /// Returns the [`Aead`] used to encrypt the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Aead, Decryptor};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(cipher.aead(), Aead::XChaCha20Poly1305);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn aead(&self) -> Aead {
+        self.header.aead()
+    }
+
+    /// Returns the number of bytes of each segment of the payload if the
+    /// payload is segmented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/segmented/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(cipher.segment_size(), Some(8));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn segment_size(&self) -> Option<usize> {
+        self.header.segment_size()
+    }
+
+    /// Returns the Unicode [`Normalization`] form applied to the passphrase if
+    /// the passphrase was normalized when encrypting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(cipher.normalization(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn normalization(&self) -> Option<Normalization> {
+        self.header.normalization()
+    }
+
+    /// Returns the [`KeyId`] stored in the header if the encrypted data has the
+    /// key identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(cipher.key_id(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn key_id(&self) -> Option<KeyId> {
+        self.header.key_id()
+    }
+
+    /// Returns the [`Validity`] period of the encrypted data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert!(cipher.validity().is_unbounded());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn validity(&self) -> Validity {
+        self.header.validity()
+    }
+
+    /// Sets the current time used to check the [`Validity`] period of the
+    /// encrypted data.
+    ///
+    /// `now` is the duration since the Unix epoch. If this is not set, the
+    /// system time is used with the `std` feature. Without the `std` feature,
+    /// decrypting the data which has the validity period fails unless this is
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase)
+    ///     .unwrap()
+    ///     .with_time(Duration::from_secs(1_800_000_000));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_time(mut self, now: Duration) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Returns `true` if the encrypted data is committed to the key used to
+    /// encrypt the payload.
+    ///
+    /// See [`Encryptor::with_key_commitment`](crate::Encryptor::with_key_commitment)
+    /// for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert!(!cipher.has_key_commitment());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn has_key_commitment(&self) -> bool {
+        self.header.has_key_commitment()
+    }
+
+    /// Returns `true` if the encrypted data is signed.
+    ///
+    /// See [`Encryptor::sign_with`](crate::Encryptor::sign_with) for more
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert!(!cipher.has_signature());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn has_signature(&self) -> bool {
+        self.header.verifying_key().is_some()
+    }
+
+    /// Verifies the signature of the encrypted data with the specified Ed25519
+    /// [`VerifyingKey`].
+    ///
+    /// The signature covers the header and the ciphertext, so this does not
+    /// require decrypting the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The encrypted data is not signed.
+    /// - The encrypted data is signed with the key other than `key`.
+    /// - The signature is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, Error, argon2::Params, ed25519_dalek::SigningKey};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    /// let key = SigningKey::from_bytes(&[1; 32]);
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.sign_with(&key))
+    ///     .unwrap();
+    /// let mut buf = [u8::default(); 287];
+    /// cipher.encrypt(&mut buf);
+    ///
+    /// let cipher = Decryptor::new(&buf, passphrase).unwrap();
+    /// assert!(cipher.verify_signature(&key.verifying_key()).is_ok());
+    ///
+    /// let other = SigningKey::from_bytes(&[2; 32]);
+    /// assert_eq!(
+    ///     cipher.verify_signature(&other.verifying_key()).unwrap_err(),
+    ///     Error::InvalidSignature
+    /// );
+    /// ```
+    #[cfg(feature = "ed25519")]
+    pub fn verify_signature(&self, key: &VerifyingKey) -> Result<()> {
+        if self.header.verifying_key() != Some(key.as_bytes()) {
+            return Err(Error::InvalidSignature);
+        }
+        let mut header = [u8::default(); Header::MAX_SIZE];
+        self.header.write(&mut header);
+        signature::verify(
+            key,
+            &header[..self.header.size()],
+            self.ciphertext,
+            self.signature,
+        )
+    }
+
+    /// Returns an iterator over the [`Extension`](crate::Extension)s stored in
+    /// the header.
+    ///
+    /// The extensions are authenticated by the MAC of the header. The
+    /// extensions of the unknown types are also returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Extension};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// let mut extensions = cipher.extensions();
+    /// assert_eq!(extensions.next(), Some(Extension::new(0x4000, b"abcrypt")));
+    /// assert_eq!(extensions.next(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn extensions(&self) -> Extensions<'_> {
+        self.header.extensions().iter()
+    }
+
+    /// Decrypts the ciphertext into `buf` in place, and returns the ranges of
+    /// the metadata and the plaintext in `buf`.
+    fn decrypt_payload(&self, buf: &mut [u8]) -> Result<(Range<usize>, Range<usize>)> {
+        self.header.validity().check_at(self.now)?;
+        let (aead, key, nonce) = (self.header.aead(), self.dk.encrypt(), self.header.nonce());
+        if let Some(segment_size) = self.header.segment_size() {
+            let offset = self.header.size() as u64;
+            segment::decrypt(
+                aead,
+                &key,
+                &nonce,
+                self.ciphertext,
                 buf,
-                &decryptor.tag,
+                segment_size,
+                offset,
             )?;
-            Ok(())
-        };
-        inner(self, buf.as_mut())
+        } else {
+            let (ciphertext, tag) = self.ciphertext.split_at(self.out_len());
+            buf.copy_from_slice(ciphertext);
+            let offset = self.header.size() as u64;
+            aead.decrypt_in_place_detached(&key, &nonce, buf, Tag::from_slice(tag), offset)?;
+        }
+        if self.header.has_extended_payload() {
+            payload::parse(buf)
+        } else {
+            Ok((Range::default(), 0..buf.len()))
+        }
     }
 
     /// Decrypts the ciphertext and into a newly allocated
     /// [`Vec`](alloc::vec::Vec).
     ///
+    /// If the plaintext was padded or the metadata was encrypted together when
+    /// encrypting, they are removed.
+    ///
     /// # Errors
     ///
-    /// Returns [`Err`] if the MAC (authentication tag) of the ciphertext is
-    /// invalid.
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The encrypted data is used outside its validity period.
+    /// - The MAC (authentication tag) of the ciphertext is invalid.
+    /// - The padding of the plaintext is invalid.
+    /// - The metadata of the plaintext is invalid.
     ///
     /// # Examples
     ///
@@ -158,12 +660,126 @@ impl<'c> Decryptor<'c> {
     #[inline]
     pub fn decrypt_to_vec(&self) -> Result<alloc::vec::Vec<u8>> {
         let mut buf = vec![u8::default(); self.out_len()];
-        self.decrypt(&mut buf)?;
+        let len = self.decrypt(&mut buf)?;
+        buf.truncate(len);
         Ok(buf)
     }
 
+    /// Decrypts the ciphertext and appends the plaintext to `buf`.
+    ///
+    /// Returns the number of bytes of the plaintext appended to `buf`. The
+    /// existing contents of `buf` are kept, so this can reuse the allocation
+    /// of `buf` for multiple decryptions. If this returns [`Err`], `buf` is
+    /// restored to its original length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by [`Decryptor::decrypt`]
+    /// occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// let mut buf = b"frame".to_vec();
+    /// let len = cipher.decrypt_append(&mut buf).unwrap();
+    /// assert_eq!(len, 14);
+    /// assert_eq!(buf, b"frameHello, world!\n");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn decrypt_append(&self, buf: &mut alloc::vec::Vec<u8>) -> Result<usize> {
+        let start = buf.len();
+        buf.resize(start + self.out_len(), u8::default());
+        let result = self.decrypt(&mut buf[start..]);
+        buf.truncate(start + *result.as_ref().unwrap_or(&usize::MIN));
+        result
+    }
+
+    /// Decrypts the ciphertext and appends the plaintext to `buf`.
+    ///
+    /// This is the same as [`Decryptor::decrypt_append`], except that this
+    /// appends to [`BytesMut`](bytes::BytesMut).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by [`Decryptor::decrypt`]
+    /// occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, bytes::BytesMut};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// let mut buf = BytesMut::from(b"frame".as_slice());
+    /// let len = cipher.decrypt_append_bytes(&mut buf).unwrap();
+    /// assert_eq!(len, 14);
+    /// assert_eq!(buf, b"frameHello, world!\n".as_slice());
+    /// ```
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn decrypt_append_bytes(&self, buf: &mut bytes::BytesMut) -> Result<usize> {
+        let start = buf.len();
+        buf.resize(start + self.out_len(), u8::default());
+        let result = self.decrypt(&mut buf[start..]);
+        buf.truncate(start + *result.as_ref().unwrap_or(&usize::MIN));
+        result
+    }
+
+    /// Decrypts the ciphertext and into a newly allocated
+    /// [`Vec`](alloc::vec::Vec), and returns it with the [`Metadata`].
+    ///
+    /// If the metadata was not encrypted together when encrypting, the
+    /// returned metadata is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The encrypted data is used outside its validity period.
+    /// - The MAC (authentication tag) of the ciphertext is invalid.
+    /// - The padding of the plaintext is invalid.
+    /// - The metadata of the plaintext is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let ciphertext = include_bytes!("../tests/data/v2/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// let (plaintext, metadata) = cipher.decrypt_to_vec_with_metadata().unwrap();
+    /// assert_eq!(metadata.file_name(), Some("data.txt"));
+    /// # assert_eq!(plaintext, data);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decrypt_to_vec_with_metadata(&self) -> Result<(alloc::vec::Vec<u8>, Metadata)> {
+        let mut buf = vec![u8::default(); self.out_len()];
+        let (metadata, plaintext) = self.decrypt_payload(&mut buf)?;
+        let metadata = Metadata::decode(&buf[metadata])?;
+        buf.truncate(plaintext.end);
+        buf.drain(..plaintext.start);
+        Ok((buf, metadata))
+    }
+
     /// Returns the number of output bytes of the decrypted data.
     ///
+    /// If the plaintext was padded or the metadata was encrypted together when
+    /// encrypting, this includes them, so this is an upper bound of the length
+    /// of the plaintext.
+    ///
     /// # Examples
     ///
     /// ```
@@ -178,19 +794,148 @@ impl<'c> Decryptor<'c> {
     #[must_use]
     #[inline]
     pub const fn out_len(&self) -> usize {
-        self.ciphertext.len()
+        payload_out_len(&self.header, self.ciphertext)
     }
+
+    /// Returns the number of output bytes of the decrypted data of
+    /// `ciphertext`.
+    ///
+    /// This is the same as [`Decryptor::out_len`], but this only parses the
+    /// header and does not derive the key, so this can be used to allocate the
+    /// buffer for [`Decryptor::decrypt`] before the passphrase is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `ciphertext` is shorter than 164 bytes.
+    /// - The magic number is invalid.
+    /// - The version number is the unsupported abcrypt version number.
+    /// - The version number is the unrecognized abcrypt version number.
+    /// - The Argon2 type is invalid.
+    /// - The Argon2 version is invalid.
+    /// - The Argon2 parameters are invalid.
+    /// - The extensions of the header are invalid.
+    /// - The header contains the unrecognized critical extension.
+    /// - The AEAD is unrecognized or not enabled.
+    /// - The passphrase normalization form is unrecognized or not enabled.
+    /// - The length of the segmented payload is invalid.
+    /// - The encrypted data is signed and shorter than the signature.
+    /// - The encrypted data is a record log.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Decryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let out_len = Decryptor::out_len_of(ciphertext).unwrap();
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(out_len, cipher.out_len());
+    /// ```
+    #[inline]
+    pub fn out_len_of(ciphertext: impl AsRef<[u8]>) -> Result<usize> {
+        let inner = |ciphertext: &[u8]| -> Result<usize> {
+            let header = Header::parse(ciphertext)?;
+            let (ciphertext, _) = split_ciphertext(&header, &ciphertext[header.size()..])?;
+            Ok(payload_out_len(&header, ciphertext))
+        };
+        inner(ciphertext.as_ref())
+    }
+}
+
+/// Splits `ciphertext` after the header into the payload and the signature
+/// after validating its length.
+///
+/// If the encrypted data is not signed, the signature is empty.
+const fn split_ciphertext<'c>(
+    header: &Header,
+    ciphertext: &'c [u8],
+) -> Result<(&'c [u8], &'c [u8])> {
+    if header.is_record_log() {
+        return Err(Error::InvalidRecordLog);
+    }
+    let signature_size = header.signature_size();
+    let len = ciphertext.len().saturating_sub(signature_size);
+    let header_size = header.size();
+    let min = match header.segment_size() {
+        Some(segment_size) if segment::payload_len(len, segment_size).is_none() => {
+            Some(segment::min_len(len, segment_size))
+        }
+        None if len < TAG_SIZE => Some(TAG_SIZE),
+        _ => None,
+    };
+    if let Some(min) = min {
+        return Err(Error::InvalidLength {
+            len: header_size + ciphertext.len(),
+            min: header_size + min + signature_size,
+        });
+    }
+    Ok(ciphertext.split_at(len))
+}
+
+/// Returns the number of output bytes of the decrypted data of the payload
+/// which was validated by [`split_ciphertext`].
+const fn payload_out_len(header: &Header, payload: &[u8]) -> usize {
+    if let Some(segment_size) = header.segment_size() {
+        match segment::payload_len(payload.len(), segment_size) {
+            Some(len) => len,
+            None => usize::MIN,
+        }
+    } else {
+        payload.len() - TAG_SIZE
+    }
+}
+
+/// Parses the header of `data` and derives the key from `passphrase`, and
+/// verifies the MAC of the header.
+pub fn open(data: &[u8], passphrase: &[u8]) -> Result<(Header, DerivedKey)> {
+    unlock(Header::parse(data)?, data, passphrase)
+}
+
+/// Derives the key from `passphrase`, and verifies the MAC and the key
+/// commitment of `header` parsed from `data`.
+///
+/// If `header` records that the passphrase was normalized when encrypting,
+/// `passphrase` is normalized in the same way before deriving the key. In that
+/// case, `passphrase` must be valid UTF-8.
+pub fn unlock(mut header: Header, data: &[u8], passphrase: &[u8]) -> Result<(Header, DerivedKey)> {
+    #[cfg(feature = "normalization")]
+    let normalized = header
+        .normalization()
+        .map(|n| {
+            core::str::from_utf8(passphrase)
+                .map(|p| n.normalize(p))
+                .map_err(|_| Error::InvalidPassphrase)
+        })
+        .transpose()?;
+    #[cfg(feature = "normalization")]
+    let passphrase = normalized.as_ref().map_or(passphrase, |p| p.as_bytes());
+    let dk = header.kdf().derive_key(passphrase, &header.salt())?;
+    verify(&mut header, data, &dk)?;
+    Ok((header, dk))
+}
+
+/// Verifies the MAC and the key commitment of `header` parsed from `data` with
+/// `dk`.
+fn verify(header: &mut Header, data: &[u8], dk: &DerivedKey) -> Result<()> {
+    header.verify_mac(&dk.mac(), data[header.mac_offset()..header.size()].into())?;
+    header.verify_key_commitment(&dk.encrypt())
 }
 
 /// Decrypts `ciphertext` and into a newly allocated [`Vec`](alloc::vec::Vec).
 ///
 /// This is a convenience function for using [`Decryptor::new`] and
-/// [`Decryptor::decrypt_to_vec`].
+/// [`Decryptor::decrypt_to_vec`]. If `ciphertext` is [armored](crate::armor),
+/// it is decoded before decryption.
 ///
 /// # Errors
 ///
 /// Returns [`Err`] if any of the following are true:
 ///
+/// - `ciphertext` is armored and the ASCII armor is invalid.
 /// - `ciphertext` is shorter than 164 bytes.
 /// - The magic number is invalid.
 /// - The version number is the unsupported abcrypt version number.
@@ -198,9 +943,19 @@ impl<'c> Decryptor<'c> {
 /// - The Argon2 type is invalid.
 /// - The Argon2 version is invalid.
 /// - The Argon2 parameters are invalid.
+/// - The extensions of the header are invalid.
+/// - The header contains the unrecognized critical extension.
+/// - The AEAD is unrecognized or not enabled.
+/// - The passphrase normalization form is unrecognized or not enabled.
 /// - The Argon2 context is invalid.
+/// - The passphrase is required to be normalized and is not valid UTF-8.
 /// - The MAC (authentication tag) of the header is invalid.
+/// - The key commitment of the header is invalid.
+/// - The length of the segmented payload is invalid.
+/// - The encrypted data is used outside its validity period.
 /// - The MAC (authentication tag) of the ciphertext is invalid.
+/// - The padding of the plaintext is invalid.
+/// - The metadata of the plaintext is invalid.
 ///
 /// # Examples
 ///
@@ -218,5 +973,67 @@ pub fn decrypt(
     ciphertext: impl AsRef<[u8]>,
     passphrase: impl AsRef<[u8]>,
 ) -> Result<alloc::vec::Vec<u8>> {
-    Decryptor::new(&ciphertext, passphrase).and_then(|c| c.decrypt_to_vec())
+    let inner = |ciphertext: &[u8], passphrase: &[u8]| -> Result<alloc::vec::Vec<u8>> {
+        let ciphertext = if armor::is_armored(ciphertext) {
+            Cow::Owned(armor::decode(ciphertext)?)
+        } else {
+            Cow::Borrowed(ciphertext)
+        };
+        Decryptor::new(&ciphertext, passphrase).and_then(|c| c.decrypt_to_vec())
+    };
+    inner(ciphertext.as_ref(), passphrase.as_ref())
+}
+
+/// Decrypts `ciphertext` with the first matching passphrase in `candidates` and
+/// into a newly allocated [`Vec`](alloc::vec::Vec).
+///
+/// This is a convenience function for using [`Decryptor::with_any`] and
+/// [`Decryptor::decrypt_to_vec`]. This returns the index of the matching
+/// candidate in `candidates` and the plaintext. If `ciphertext` is
+/// [armored](crate::armor), it is decoded before decryption.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - None of the candidates matches the passphrase. In this case, this returns
+///   [`Error::InvalidHeaderMac`].
+/// - Any of the errors returned by [`decrypt`] other than the invalid MAC of
+///   the header occurs.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::{Candidate, Encryptor, KeyId, argon2::Params};
+/// #
+/// let data = b"Hello, world!\n";
+/// let params = Params::new(32, 3, 4, None).unwrap();
+/// let ciphertext = Encryptor::with_params(data, "passphrase", params)
+///     .and_then(|c| c.with_key_id(KeyId::new("2026-q1")))
+///     .map(|c| c.encrypt_to_vec())
+///     .unwrap();
+///
+/// let candidates = [
+///     Candidate::new("password").with_key_id(KeyId::new("2025-q4")),
+///     Candidate::new("passphrase").with_key_id(KeyId::new("2026-q1")),
+/// ];
+/// let (index, plaintext) = abcrypt::decrypt_with_any(ciphertext, &candidates).unwrap();
+/// assert_eq!(index, 1);
+/// # assert_eq!(plaintext, data);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decrypt_with_any(
+    ciphertext: impl AsRef<[u8]>,
+    candidates: &[Candidate<'_>],
+) -> Result<(usize, alloc::vec::Vec<u8>)> {
+    let inner = |ciphertext: &[u8]| -> Result<(usize, alloc::vec::Vec<u8>)> {
+        let ciphertext = if armor::is_armored(ciphertext) {
+            Cow::Owned(armor::decode(ciphertext)?)
+        } else {
+            Cow::Borrowed(ciphertext)
+        };
+        Decryptor::with_any(&ciphertext, candidates)
+            .and_then(|(i, c)| c.decrypt_to_vec().map(|p| (i, p)))
+    };
+    inner(ciphertext.as_ref())
 }
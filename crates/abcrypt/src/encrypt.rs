@@ -4,12 +4,24 @@
 
 //! Encrypts to the abcrypt encrypted data format.
 
-use argon2::{Algorithm, Argon2, Params, Version};
-use chacha20poly1305::{AeadInPlace, KeyInit, XChaCha20Poly1305};
+use core::num::NonZeroU32;
 
+use argon2::{Algorithm, Params, Version};
+#[cfg(feature = "ed25519")]
+use ed25519_dalek::SigningKey;
+
+#[cfg(feature = "alloc")]
+use crate::Metadata;
+#[cfg(feature = "normalization")]
+use crate::Normalization;
+#[cfg(feature = "sharing")]
+use crate::sharing::FileKey;
+#[cfg(feature = "ed25519")]
+use crate::signature;
 use crate::{
-    AAD, Error, HEADER_SIZE, Result, TAG_SIZE,
+    Aead, Argon2, Error, Extension, Kdf, KeyId, Padding, Result, TAG_SIZE, Validity,
     format::{DerivedKey, Header},
+    payload, segment,
 };
 
 /// Encryptor for the abcrypt encrypted data format.
@@ -18,6 +30,13 @@ pub struct Encryptor<'m> {
     header: Header,
     dk: DerivedKey,
     plaintext: &'m [u8],
+    padding: Option<Padding>,
+    #[cfg(feature = "alloc")]
+    metadata: alloc::vec::Vec<u8>,
+    // Kept separately from `metadata` so that `out_len` can be a `const fn`.
+    metadata_len: usize,
+    #[cfg(feature = "ed25519")]
+    signing_key: Option<SigningKey>,
 }
 
 impl<'m> Encryptor<'m> {
@@ -115,56 +134,501 @@ impl<'m> Encryptor<'m> {
         argon2_version: Version,
         params: Params,
     ) -> Result<Self> {
-        let inner = |plaintext: &'m [u8],
-                     passphrase: &[u8],
-                     argon2_type: Algorithm,
-                     argon2_version: Version,
-                     params: Params|
-         -> Result<Self> {
-            let mut header = Header::new(argon2_type, argon2_version, params);
-
-            // The derived key size is 96 bytes. The first 256 bits are for
-            // XChaCha20-Poly1305 key, and the last 512 bits are for BLAKE2b-512-MAC key.
-            let mut dk = [u8::default(); DerivedKey::SIZE];
-            let argon2 = Argon2::new(
-                header.argon2_type().into(),
-                header.argon2_version().into(),
-                header.params().into(),
-            );
-            #[cfg(feature = "alloc")]
-            argon2
-                .hash_password_into(passphrase, &header.salt(), &mut dk)
-                .map_err(Error::InvalidArgon2Context)?;
-            #[cfg(not(feature = "alloc"))]
-            {
-                let mut memory_blocks = crate::MEMORY_BLOCKS;
-                argon2
-                    .hash_password_into_with_memory(
-                        passphrase,
-                        &header.salt(),
-                        &mut dk,
-                        &mut memory_blocks,
-                    )
-                    .map_err(Error::InvalidArgon2Context)?;
-            }
-            let dk = DerivedKey::new(dk);
-
-            header.compute_mac(&dk.mac());
-            Ok(Self {
-                header,
-                dk,
-                plaintext,
-            })
-        };
-        inner(
+        let argon2 = Argon2::from_parts(argon2_type.into(), argon2_version.into(), params.into());
+        Self::with_kdf(plaintext.as_ref(), passphrase.as_ref(), argon2.into())
+    }
+
+    /// Creates a new `Encryptor` with scrypt and the specified
+    /// [`scrypt::Params`].
+    ///
+    /// The encrypted data is stored as version 3 of the abcrypt encrypted data
+    /// format, and the key derivation function is detected automatically when
+    /// decrypting. The output length of `params` is ignored because the
+    /// derived key is always 96 bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function does not currently fail, but returns [`Result`] for
+    /// consistency with [`Encryptor::with_context`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, Kdf};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    ///     .map(|c| c.encrypt_to_vec())
+    ///     .unwrap();
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert!(matches!(cipher.kdf(), Kdf::Scrypt(params) if params.log_n() == 10));
+    /// # assert_eq!(cipher.decrypt_to_vec().unwrap(), data);
+    /// ```
+    #[cfg(feature = "scrypt")]
+    #[inline]
+    pub fn with_scrypt(
+        plaintext: &'m impl AsRef<[u8]>,
+        passphrase: impl AsRef<[u8]>,
+        params: scrypt::Params,
+    ) -> Result<Self> {
+        Self::with_kdf(
             plaintext.as_ref(),
             passphrase.as_ref(),
-            argon2_type,
-            argon2_version,
-            params,
+            Kdf::Scrypt(params.into()),
         )
     }
 
+    /// Creates a new `Encryptor` with the specified [`Algorithm`], [`Version`]
+    /// and [`Params`], which normalizes the passphrase to the specified
+    /// Unicode [`Normalization`] form before deriving the key.
+    ///
+    /// The normalization form is stored in the header as version 2 of the
+    /// abcrypt encrypted data format, and the passphrase is normalized in the
+    /// same way when decrypting. This makes the key independent of how the
+    /// passphrase was entered (e.g., composed or decomposed accented
+    /// characters).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the Argon2 context is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{
+    /// #     Decryptor, Encryptor, Normalization,
+    /// #     argon2::{Algorithm, Params, Version},
+    /// # };
+    /// #
+    /// let data = b"Hello, world!\n";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let ciphertext = Encryptor::with_normalization(
+    ///     data,
+    ///     "caf\u{e9}",
+    ///     Algorithm::default(),
+    ///     Version::default(),
+    ///     params,
+    ///     Normalization::Nfc,
+    /// )
+    /// .map(|c| c.encrypt_to_vec())
+    /// .unwrap();
+    ///
+    /// // The decomposed form of the passphrase derives the same key.
+    /// let cipher = Decryptor::new(&ciphertext, "cafe\u{301}").unwrap();
+    /// assert_eq!(cipher.normalization(), Some(Normalization::Nfc));
+    /// # assert_eq!(cipher.decrypt_to_vec().unwrap(), data);
+    /// ```
+    #[cfg(feature = "normalization")]
+    pub fn with_normalization(
+        plaintext: &'m impl AsRef<[u8]>,
+        passphrase: &str,
+        argon2_type: Algorithm,
+        argon2_version: Version,
+        params: Params,
+        normalization: Normalization,
+    ) -> Result<Self> {
+        let argon2 = Argon2::from_parts(argon2_type.into(), argon2_version.into(), params.into());
+        let mut header = Header::new(argon2.into());
+        header.set_normalization(normalization)?;
+        let passphrase = normalization.normalize(passphrase);
+        Self::with_header(plaintext.as_ref(), passphrase.as_bytes(), header)
+    }
+
+    /// Creates a new `Encryptor` which encrypts with the random [`FileKey`]
+    /// instead of the passphrase.
+    ///
+    /// The file key is uniformly random, so it is expanded into the key with
+    /// BLAKE2b-512-MAC instead of the costly key derivation function. The
+    /// encrypted data is version 3 of the abcrypt encrypted data format and
+    /// records [`Kdf::FileKey`]. The file key can be split into the key shares
+    /// by [`sharing::split`](crate::sharing::split).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, Kdf, sharing::FileKey};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let key = FileKey::generate();
+    ///
+    /// let ciphertext = Encryptor::with_file_key(data, &key).encrypt_to_vec();
+    /// assert!(matches!(Kdf::new(&ciphertext).unwrap(), Kdf::FileKey));
+    /// # let cipher = Decryptor::with_file_key(&ciphertext, &key).unwrap();
+    /// # assert_eq!(cipher.decrypt_to_vec().unwrap(), data);
+    /// ```
+    #[cfg(feature = "sharing")]
+    #[must_use]
+    #[inline]
+    pub fn with_file_key(plaintext: &'m impl AsRef<[u8]>, key: &FileKey) -> Self {
+        let header = Header::new(Kdf::FileKey);
+        let dk = key.expand(&header.salt());
+        Self::with_key(plaintext.as_ref(), header, dk)
+    }
+
+    /// Creates a new `Encryptor` which derives the key with `kdf`.
+    #[inline]
+    fn with_kdf(plaintext: &'m [u8], passphrase: &[u8], kdf: Kdf) -> Result<Self> {
+        Self::with_header(plaintext, passphrase, Header::new(kdf))
+    }
+
+    /// Creates a new `Encryptor` which derives the key with the key derivation
+    /// function stored in `header`.
+    fn with_header(plaintext: &'m [u8], passphrase: &[u8], header: Header) -> Result<Self> {
+        let dk = header.kdf().derive_key(passphrase, &header.salt())?;
+        Ok(Self::with_key(plaintext, header, dk))
+    }
+
+    /// Creates a new `Encryptor` which encrypts with `dk`.
+    fn with_key(plaintext: &'m [u8], mut header: Header, dk: DerivedKey) -> Self {
+        header.compute_mac(&dk.mac());
+        Self {
+            header,
+            dk,
+            plaintext,
+            padding: None,
+            #[cfg(feature = "alloc")]
+            metadata: alloc::vec::Vec::new(),
+            metadata_len: usize::default(),
+            #[cfg(feature = "ed25519")]
+            signing_key: None,
+        }
+    }
+
+    /// Pads the plaintext with the specified [`Padding`] before encryption.
+    ///
+    /// This hides the exact length of the plaintext. The padded data is
+    /// encrypted as version 2 of the abcrypt encrypted data format, and the
+    /// padding is removed when decrypting. The metadata set by
+    /// [`Encryptor::with_metadata`] is padded together with the plaintext.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, Padding, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .unwrap()
+    ///     .with_padding(Padding::Padme);
+    /// assert_eq!(cipher.out_len(), 188);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.header.set_extended_payload();
+        self.header.compute_mac(&self.dk.mac());
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Encrypts the specified [`Metadata`] together with the plaintext.
+    ///
+    /// The metadata is encrypted as version 2 of the abcrypt encrypted data
+    /// format, and can be retrieved by
+    /// [`Decryptor::decrypt_to_vec_with_metadata`](crate::Decryptor::decrypt_to_vec_with_metadata).
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, Metadata, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let metadata = Metadata::new().with_file_name("data.txt");
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .unwrap()
+    ///     .with_metadata(&metadata);
+    /// assert_eq!(cipher.out_len(), 201);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn with_metadata(mut self, metadata: &Metadata) -> Self {
+        self.header.set_extended_payload();
+        self.header.compute_mac(&self.dk.mac());
+        self.metadata = metadata.encode();
+        self.metadata_len = self.metadata.len();
+        self
+    }
+
+    /// Encrypts the payload with the specified [`Aead`].
+    ///
+    /// The AEAD other than [`Aead::XChaCha20Poly1305`] is stored in the header
+    /// as version 2 of the abcrypt encrypted data format, and is detected
+    /// automatically when decrypting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "aes-gcm-siv")]
+    /// # {
+    /// # use abcrypt::{Aead, Decryptor, Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let ciphertext = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_aead(Aead::Aes256GcmSiv))
+    ///     .map(|c| c.encrypt_to_vec())
+    ///     .unwrap();
+    ///
+    /// let cipher = Decryptor::new(&ciphertext, passphrase).unwrap();
+    /// assert_eq!(cipher.aead(), Aead::Aes256GcmSiv);
+    /// # assert_eq!(cipher.decrypt_to_vec().unwrap(), data);
+    /// # }
+    /// ```
+    pub fn with_aead(mut self, aead: Aead) -> Result<Self> {
+        self.header.set_aead(aead)?;
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
+    /// Splits the payload into segments of `segment_size` bytes, and encrypts
+    /// each segment separately.
+    ///
+    /// The segmented payload is stored as version 2 of the abcrypt encrypted
+    /// data format. Each segment has its own authentication tag, so the
+    /// encrypted data can be decrypted partially by
+    /// [`SeekableDecryptor`](crate::SeekableDecryptor).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroU32;
+    /// #
+    /// # use abcrypt::{Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+    ///     .unwrap();
+    /// assert_eq!(cipher.out_len(), 227);
+    /// ```
+    pub fn with_segment_size(mut self, segment_size: NonZeroU32) -> Result<Self> {
+        self.header.set_segment_size(segment_size)?;
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
+    /// Commits the encrypted data to the key used to encrypt the payload.
+    ///
+    /// XChaCha20-Poly1305 and AES-256-GCM-SIV are not key-committing, so it is
+    /// possible to craft a payload which can be decrypted with more than one
+    /// key. This stores BLAKE2b-256-MAC keyed with the key in the header as the
+    /// critical extension of version 2 of the abcrypt encrypted data format,
+    /// and decrypting with any other key is rejected before decrypting the
+    /// payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_key_commitment())
+    ///     .unwrap();
+    /// let mut buf = [u8::default(); 223];
+    /// cipher.encrypt(&mut buf);
+    ///
+    /// let cipher = Decryptor::new(&buf, passphrase).unwrap();
+    /// assert!(cipher.has_key_commitment());
+    /// ```
+    pub fn with_key_commitment(mut self) -> Result<Self> {
+        self.header.set_key_commitment(&self.dk.encrypt())?;
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
+    /// Stores the specified [`KeyId`] in the header.
+    ///
+    /// The key identifier is stored as the non-critical extension of version 2
+    /// of the abcrypt encrypted data format. It allows
+    /// [`decrypt_with_any`](crate::decrypt_with_any) to skip the candidate
+    /// passphrases with a different key identifier without deriving the key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, KeyId, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    /// let key_id = KeyId::new("2026-q1");
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_key_id(key_id))
+    ///     .unwrap();
+    /// let mut buf = [u8::default(); 199];
+    /// cipher.encrypt(&mut buf);
+    ///
+    /// let cipher = Decryptor::new(&buf, passphrase).unwrap();
+    /// assert_eq!(cipher.key_id(), Some(key_id));
+    /// ```
+    pub fn with_key_id(mut self, key_id: KeyId) -> Result<Self> {
+        self.header.set_key_id(key_id)?;
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
+    /// Restricts the decryption to the specified [`Validity`] period.
+    ///
+    /// The validity period is stored as the critical extension of version 2 of
+    /// the abcrypt encrypted data format, so it is authenticated by the MAC of
+    /// the header and the implementations which do not recognize it reject the
+    /// data. Decrypting the data outside the validity period fails with
+    /// [`Error::Expired`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use abcrypt::{Decryptor, Encryptor, Error, Validity, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    /// let validity = Validity::new().with_not_after(Duration::from_secs(1_800_000_000));
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_validity(validity))
+    ///     .unwrap();
+    /// let mut buf = [u8::default(); 207];
+    /// cipher.encrypt(&mut buf);
+    ///
+    /// let cipher = Decryptor::new(&buf, passphrase)
+    ///     .unwrap()
+    ///     .with_time(Duration::from_secs(1_900_000_000));
+    /// assert_eq!(cipher.validity(), validity);
+    /// let mut buf = [u8::default(); 14];
+    /// assert_eq!(cipher.decrypt(&mut buf).unwrap_err(), Error::Expired);
+    /// ```
+    pub fn with_validity(mut self, validity: Validity) -> Result<Self> {
+        self.header.set_validity(validity)?;
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
+    /// Signs the encrypted data with the specified Ed25519 [`SigningKey`].
+    ///
+    /// This stores the public key of `key` in the header as the critical
+    /// extension of version 2 of the abcrypt encrypted data format, and
+    /// appends the signature over the header and the ciphertext to the
+    /// encrypted data. The signature can be verified by
+    /// [`Decryptor::verify_signature`](crate::Decryptor::verify_signature)
+    /// without decrypting the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Decryptor, Encryptor, argon2::Params, ed25519_dalek::SigningKey};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    /// let key = SigningKey::from_bytes(&[1; 32]);
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.sign_with(&key))
+    ///     .unwrap();
+    /// let mut buf = [u8::default(); 287];
+    /// cipher.encrypt(&mut buf);
+    ///
+    /// let cipher = Decryptor::new(&buf, passphrase).unwrap();
+    /// assert!(cipher.verify_signature(&key.verifying_key()).is_ok());
+    /// ```
+    #[cfg(feature = "ed25519")]
+    pub fn sign_with(mut self, key: &SigningKey) -> Result<Self> {
+        self.header
+            .set_verifying_key(key.verifying_key().to_bytes())?;
+        self.header.compute_mac(&self.dk.mac());
+        self.signing_key = Some(key.clone());
+        Ok(self)
+    }
+
+    /// Stores the specified [`Extension`] in the header.
+    ///
+    /// The extensions are stored as version 2 of the abcrypt encrypted data
+    /// format, and are authenticated by the MAC of the header. They can be read
+    /// by [`Decryptor::extensions`](crate::Decryptor::extensions).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The type of `extension` is reserved for the abcrypt encrypted data
+    ///   format (see [`Extension::PRIVATE_USE`]).
+    /// - The extension of the same type is already stored.
+    /// - The extensions are longer than 1024 bytes in total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, Extension, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params)
+    ///     .and_then(|c| c.with_extension(Extension::new(0x4000, b"abc")))
+    ///     .unwrap();
+    /// assert_eq!(cipher.out_len(), 194);
+    /// ```
+    pub fn with_extension(mut self, extension: Extension<'_>) -> Result<Self> {
+        if extension.ty() & !Extension::CRITICAL < Extension::PRIVATE_USE {
+            return Err(Error::InvalidExtension);
+        }
+        self.header.extensions_mut().push(extension)?;
+        self.header.set_extended_payload();
+        self.header.compute_mac(&self.dk.mac());
+        Ok(self)
+    }
+
     /// Encrypts the plaintext into `buf`.
     ///
     /// # Panics
@@ -189,18 +653,144 @@ impl<'m> Encryptor<'m> {
     /// # assert_ne!(buf.as_slice(), data);
     /// ```
     pub fn encrypt(&self, buf: &mut (impl AsMut<[u8]> + ?Sized)) {
-        let inner = |encryptor: &Self, buf: &mut [u8]| {
-            buf[..HEADER_SIZE].copy_from_slice(&encryptor.header.as_bytes());
-            let payload = &mut buf[HEADER_SIZE..(self.out_len() - TAG_SIZE)];
-            payload.copy_from_slice(encryptor.plaintext);
-
-            let cipher = XChaCha20Poly1305::new(&encryptor.dk.encrypt());
-            let tag = cipher
-                .encrypt_in_place_detached(&encryptor.header.nonce(), AAD, payload)
-                .expect("data too long");
-            buf[(self.out_len() - TAG_SIZE)..].copy_from_slice(&tag);
-        };
-        inner(self, buf.as_mut());
+        self.encrypt_into(buf.as_mut()).expect("data too long");
+    }
+
+    /// Encrypts the plaintext into `buf`, returning an error if `buf` has the
+    /// wrong length.
+    ///
+    /// This is the same as [`Encryptor::encrypt`], except that this returns
+    /// [`Err`] instead of panicking if `buf` and [`Encryptor::out_len`] have
+    /// different lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `buf` and [`Encryptor::out_len`] have different lengths.
+    /// - The plaintext is too long to be encrypted with the AEAD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, Error, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// let mut buf = [u8::default(); 177];
+    /// assert_eq!(
+    ///     cipher.try_encrypt(&mut buf).unwrap_err(),
+    ///     Error::InvalidOutputLength {
+    ///         expected: 178,
+    ///         actual: 177
+    ///     }
+    /// );
+    /// let mut buf = [u8::default(); 178];
+    /// cipher.try_encrypt(&mut buf).unwrap();
+    /// # assert_ne!(buf.as_slice(), data);
+    /// ```
+    pub fn try_encrypt(&self, buf: &mut (impl AsMut<[u8]> + ?Sized)) -> Result<()> {
+        let buf = buf.as_mut();
+        let expected = self.out_len();
+        if buf.len() != expected {
+            return Err(Error::InvalidOutputLength {
+                expected,
+                actual: buf.len(),
+            });
+        }
+        self.encrypt_into(buf)
+    }
+
+    /// Encrypts the plaintext into `header` and `ciphertext` which are stored
+    /// separately.
+    ///
+    /// `header` is the header of the encrypted data, and `ciphertext` is the
+    /// encrypted payload and the MAC followed by the signature if the
+    /// encrypted data is signed. Concatenating them gives the same bytes
+    /// as [`Encryptor::encrypt`]. Use [`Decryptor::from_parts`] to decrypt
+    /// them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the following are true:
+    ///
+    /// - `header` and [`Encryptor::header_len`] have different lengths.
+    /// - `ciphertext` and the encrypted payload and the MAC have different
+    ///   lengths.
+    /// - The end of the keystream will be reached with the given data length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// let mut header = [u8::default(); 148];
+    /// let mut ciphertext = [u8::default(); 30];
+    /// cipher.encrypt_detached(&mut header, &mut ciphertext);
+    /// # assert_ne!(ciphertext.as_slice(), data);
+    /// ```
+    ///
+    /// [`Decryptor::from_parts`]: crate::Decryptor::from_parts
+    pub fn encrypt_detached(
+        &self,
+        header: &mut (impl AsMut<[u8]> + ?Sized),
+        ciphertext: &mut (impl AsMut<[u8]> + ?Sized),
+    ) {
+        self.encrypt_parts(header.as_mut(), ciphertext.as_mut())
+            .expect("data too long");
+    }
+
+    /// Encrypts the plaintext into `buf`.
+    fn encrypt_into(&self, buf: &mut [u8]) -> Result<()> {
+        let (header, ciphertext) = buf.split_at_mut(self.header_len());
+        self.encrypt_parts(header, ciphertext)
+    }
+
+    /// Encrypts the plaintext into `header` and `ciphertext`.
+    fn encrypt_parts(&self, header: &mut [u8], ciphertext: &mut [u8]) -> Result<()> {
+        assert_eq!(
+            header.len(),
+            self.header_len(),
+            "`header` and the header have different lengths"
+        );
+        self.header.write(header);
+
+        #[cfg(feature = "ed25519")]
+        let (ciphertext, signature) =
+            ciphertext.split_at_mut(ciphertext.len() - self.header.signature_size());
+        let payload_len = self.payload_len();
+        let payload = &mut ciphertext[..payload_len];
+        if self.header.has_extended_payload() {
+            #[cfg(feature = "alloc")]
+            let metadata = self.metadata.as_slice();
+            #[cfg(not(feature = "alloc"))]
+            let metadata = &[];
+            payload::write(payload, metadata, self.plaintext);
+        } else {
+            payload.copy_from_slice(self.plaintext);
+        }
+
+        let (aead, key, nonce) = (self.header.aead(), self.dk.encrypt(), self.header.nonce());
+        if let Some(segment_size) = self.header.segment_size() {
+            segment::encrypt(aead, &key, &nonce, ciphertext, payload_len, segment_size)?;
+        } else {
+            let (payload, tag) = ciphertext.split_at_mut(payload_len);
+            tag.copy_from_slice(&aead.encrypt_in_place_detached(&key, &nonce, payload)?);
+        }
+
+        #[cfg(feature = "ed25519")]
+        if let Some(key) = &self.signing_key {
+            signature.copy_from_slice(&signature::sign(key, header, ciphertext).to_bytes());
+        }
+        Ok(())
     }
 
     /// Encrypts the plaintext and into a newly allocated
@@ -228,9 +818,98 @@ impl<'m> Encryptor<'m> {
         buf
     }
 
+    /// Encrypts the plaintext and appends it to `buf`.
+    ///
+    /// The existing contents of `buf` are kept, so this can reuse the
+    /// allocation of `buf` for multiple encryptions or write the encrypted data
+    /// after a frame header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// let mut buf = b"frame".to_vec();
+    /// cipher.encrypt_append(&mut buf);
+    /// assert_eq!(buf.len(), 5 + 178);
+    /// assert!(buf.starts_with(b"frameabcrypt"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn encrypt_append(&self, buf: &mut alloc::vec::Vec<u8>) {
+        let start = buf.len();
+        buf.resize(start + self.out_len(), u8::default());
+        self.encrypt(&mut buf[start..]);
+    }
+
+    /// Encrypts the plaintext and appends it to `buf`.
+    ///
+    /// This is the same as [`Encryptor::encrypt_append`], except that this
+    /// appends to [`BytesMut`](bytes::BytesMut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, argon2::Params, bytes::BytesMut};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// let mut buf = BytesMut::from(b"frame".as_slice());
+    /// cipher.encrypt_append_bytes(&mut buf);
+    /// assert_eq!(buf.len(), 5 + 178);
+    /// assert!(buf.starts_with(b"frameabcrypt"));
+    /// ```
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn encrypt_append_bytes(&self, buf: &mut bytes::BytesMut) {
+        let start = buf.len();
+        buf.resize(start + self.out_len(), u8::default());
+        self.encrypt(&mut buf[start..]);
+    }
+
+    /// Encrypts the plaintext into the header and the ciphertext which are
+    /// newly allocated [`Vec`](alloc::vec::Vec)s.
+    ///
+    /// This is a convenience method for using [`Encryptor::encrypt_detached`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// let (header, ciphertext) = cipher.encrypt_detached_to_vec();
+    /// # assert_eq!(header.len(), 148);
+    /// # assert_ne!(ciphertext, data);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn encrypt_detached_to_vec(&self) -> (alloc::vec::Vec<u8>, alloc::vec::Vec<u8>) {
+        let mut header = vec![u8::default(); self.header_len()];
+        let mut ciphertext = vec![u8::default(); self.out_len() - self.header_len()];
+        self.encrypt_detached(&mut header, &mut ciphertext);
+        (header, ciphertext)
+    }
+
     #[allow(clippy::missing_panics_doc)]
     /// Returns the number of output bytes of the encrypted data.
     ///
+    /// If the padding, the metadata or the signature is enabled, this includes
+    /// them.
+    ///
     /// # Examples
     ///
     /// ```
@@ -246,8 +925,48 @@ impl<'m> Encryptor<'m> {
     #[must_use]
     #[inline]
     pub const fn out_len(&self) -> usize {
-        assert!(self.plaintext.len() <= (usize::MAX - HEADER_SIZE - TAG_SIZE));
-        HEADER_SIZE + self.plaintext.len() + TAG_SIZE
+        let payload_len = self.payload_len();
+        let ciphertext_len = if let Some(segment_size) = self.header.segment_size() {
+            segment::ciphertext_len(payload_len, segment_size)
+        } else {
+            assert!(payload_len <= usize::MAX - TAG_SIZE);
+            payload_len + TAG_SIZE
+        };
+        let signature_size = self.header.signature_size();
+        assert!(ciphertext_len <= usize::MAX - signature_size);
+        let ciphertext_len = ciphertext_len + signature_size;
+        let header_size = self.header.size();
+        assert!(ciphertext_len <= (usize::MAX - header_size));
+        header_size + ciphertext_len
+    }
+
+    /// Returns the number of bytes of the header of the encrypted data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, argon2::Params};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = Params::new(32, 3, 4, None).unwrap();
+    /// let cipher = Encryptor::with_params(data, passphrase, params).unwrap();
+    /// assert_eq!(cipher.header_len(), 148);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn header_len(&self) -> usize {
+        self.header.size()
+    }
+
+    /// Returns the number of bytes of the payload before encryption.
+    const fn payload_len(&self) -> usize {
+        if self.header.has_extended_payload() {
+            payload::len(self.metadata_len, self.plaintext.len(), self.padding)
+        } else {
+            self.plaintext.len()
+        }
     }
 }
 
@@ -9,10 +9,21 @@ use core::{fmt, result};
 use blake2::digest::MacError;
 
 /// The error type for the abcrypt encrypted data format.
+///
+/// The variants have the context of the error, such as the actual length or
+/// the offending value. Use [`Error::kind`] to get the category of the error
+/// without the context.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
-    /// The encrypted data was shorter than 164 bytes.
-    InvalidLength,
+    /// The encrypted data was shorter than expected.
+    InvalidLength {
+        /// The number of bytes of the encrypted data.
+        len: usize,
+
+        /// The minimum number of bytes of the encrypted data.
+        min: usize,
+    },
 
     /// The magic number (file signature) was invalid.
     InvalidMagicNumber,
@@ -36,30 +47,248 @@ pub enum Error {
     InvalidArgon2Context(argon2::Error),
 
     /// The MAC (authentication tag) of the header was invalid.
+    ///
+    /// This means either the passphrase was incorrect or the header was
+    /// corrupted, and they can not be distinguished.
     InvalidHeaderMac(MacError),
 
     /// The MAC (authentication tag) of the ciphertext was invalid.
-    InvalidMac(chacha20poly1305::Error),
+    ///
+    /// This is only checked after the MAC of the header was verified with the
+    /// same passphrase, so this means the ciphertext was corrupted, not that
+    /// the passphrase was incorrect.
+    InvalidMac {
+        /// The byte offset of the corrupted ciphertext in the encrypted data.
+        ///
+        /// If the payload is segmented, this is the offset of the first
+        /// corrupted segment. Otherwise, this is the offset of the whole
+        /// ciphertext.
+        offset: u64,
+    },
+
+    /// The padding of the plaintext was invalid.
+    InvalidPadding,
+
+    /// The ASCII armor was invalid.
+    InvalidArmor,
+
+    /// The metadata of the plaintext was invalid.
+    InvalidMetadata,
+
+    /// The extensions of the header were invalid.
+    InvalidExtension,
+
+    /// The header contained the unrecognized critical extension.
+    UnknownCriticalExtension(u16),
+
+    /// The AEAD was unrecognized or not enabled by the feature flags.
+    UnsupportedAead(u8),
+
+    /// The scrypt parameters were invalid.
+    InvalidScryptParams {
+        /// The log2 of the scrypt parameter `N`.
+        log_n: u8,
+
+        /// The scrypt parameter `r`.
+        r: u32,
+
+        /// The scrypt parameter `p`.
+        p: u32,
+    },
+
+    /// The PHC string was invalid.
+    InvalidPhcString,
+
+    /// The archive was invalid.
+    InvalidArchive,
+
+    /// The output buffer had a different length from the expected one.
+    InvalidOutputLength {
+        /// The expected number of bytes of the output buffer.
+        expected: usize,
+
+        /// The actual number of bytes of the output buffer.
+        actual: usize,
+    },
+
+    /// The key derivation function was unrecognized or not enabled by the
+    /// feature flags.
+    ///
+    /// This is also returned if the encrypted data is encrypted with the file
+    /// key and a passphrase is given, or vice versa.
+    UnsupportedKdf(u32),
+
+    /// The key commitment of the header was invalid.
+    InvalidKeyCommitment,
+
+    /// The signature of the encrypted data was missing or invalid.
+    InvalidSignature,
+
+    /// The record log was invalid.
+    InvalidRecordLog,
+
+    /// The passphrase normalization form was unrecognized or not enabled by
+    /// the feature flags.
+    UnsupportedNormalization(u8),
+
+    /// The encrypted data was used outside its validity period.
+    Expired,
+
+    /// The parameters of the key sharing were invalid.
+    InvalidSharingParams {
+        /// The number of the key shares required to recover the file key.
+        threshold: u8,
+
+        /// The number of the key shares.
+        count: u8,
+    },
+
+    /// The key share was invalid or did not match the other key shares.
+    InvalidShare,
+
+    /// There were fewer distinct key shares than required.
+    InsufficientShares {
+        /// The number of the key shares required to recover the file key.
+        threshold: u8,
+
+        /// The number of the distinct key shares given.
+        count: usize,
+    },
+
+    /// The passphrase was not valid UTF-8 although the header requested
+    /// normalizing it.
+    InvalidPassphrase,
+
+    /// The plaintext was too long to be encrypted with the AEAD.
+    PlaintextTooLong,
+}
+
+impl Error {
+    /// Returns the corresponding [`ErrorKind`] for this error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Error, ErrorKind};
+    /// #
+    /// assert_eq!(
+    ///     Error::InvalidLength { len: 163, min: 164 }.kind(),
+    ///     ErrorKind::InvalidLength
+    /// );
+    /// assert_eq!(
+    ///     Error::UnknownVersion(u8::MAX).kind(),
+    ///     ErrorKind::UnknownVersion
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidLength { .. } => ErrorKind::InvalidLength,
+            Self::InvalidMagicNumber => ErrorKind::InvalidMagicNumber,
+            Self::UnsupportedVersion(_) => ErrorKind::UnsupportedVersion,
+            Self::UnknownVersion(_) => ErrorKind::UnknownVersion,
+            Self::InvalidArgon2Type(_) => ErrorKind::InvalidArgon2Type,
+            Self::InvalidArgon2Version(_) => ErrorKind::InvalidArgon2Version,
+            Self::InvalidArgon2Params(_) => ErrorKind::InvalidArgon2Params,
+            Self::InvalidArgon2Context(_) => ErrorKind::InvalidArgon2Context,
+            Self::InvalidHeaderMac(_) => ErrorKind::InvalidHeaderMac,
+            Self::InvalidMac { .. } => ErrorKind::InvalidMac,
+            Self::InvalidPadding => ErrorKind::InvalidPadding,
+            Self::InvalidArmor => ErrorKind::InvalidArmor,
+            Self::InvalidMetadata => ErrorKind::InvalidMetadata,
+            Self::InvalidExtension => ErrorKind::InvalidExtension,
+            Self::UnknownCriticalExtension(_) => ErrorKind::UnknownCriticalExtension,
+            Self::UnsupportedAead(_) => ErrorKind::UnsupportedAead,
+            Self::InvalidScryptParams { .. } => ErrorKind::InvalidScryptParams,
+            Self::InvalidPhcString => ErrorKind::InvalidPhcString,
+            Self::InvalidArchive => ErrorKind::InvalidArchive,
+            Self::InvalidOutputLength { .. } => ErrorKind::InvalidOutputLength,
+            Self::UnsupportedKdf(_) => ErrorKind::UnsupportedKdf,
+            Self::InvalidKeyCommitment => ErrorKind::InvalidKeyCommitment,
+            Self::InvalidSignature => ErrorKind::InvalidSignature,
+            Self::InvalidRecordLog => ErrorKind::InvalidRecordLog,
+            Self::UnsupportedNormalization(_) => ErrorKind::UnsupportedNormalization,
+            Self::Expired => ErrorKind::Expired,
+            Self::InvalidSharingParams { .. } => ErrorKind::InvalidSharingParams,
+            Self::InvalidShare => ErrorKind::InvalidShare,
+            Self::InsufficientShares { .. } => ErrorKind::InsufficientShares,
+            Self::InvalidPassphrase => ErrorKind::InvalidPassphrase,
+            Self::PlaintextTooLong => ErrorKind::PlaintextTooLong,
+        }
+    }
 }
 
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::InvalidLength => write!(f, "encrypted data is shorter than 164 bytes"),
+            Self::InvalidLength { len, min } => {
+                write!(
+                    f,
+                    "encrypted data is shorter than {min} bytes (got {len} bytes)"
+                )
+            }
             Self::InvalidMagicNumber => write!(f, "invalid magic number"),
             Self::UnsupportedVersion(version) => {
                 write!(f, "unsupported version number `{version}`")
             }
             Self::UnknownVersion(version) => write!(f, "unknown version number `{version}`"),
-            Self::InvalidArgon2Type(_) => write!(f, "invalid Argon2 type"),
+            Self::InvalidArgon2Type(ty) => write!(f, "invalid Argon2 type `{ty}`"),
             Self::InvalidArgon2Version(version) => {
                 write!(f, "invalid Argon2 version `{version:#x}`")
             }
             Self::InvalidArgon2Params(_) => write!(f, "invalid Argon2 parameters"),
             Self::InvalidArgon2Context(_) => write!(f, "invalid Argon2 context"),
-            Self::InvalidHeaderMac(_) => write!(f, "invalid header MAC"),
-            Self::InvalidMac(_) => write!(f, "invalid ciphertext MAC"),
+            Self::InvalidHeaderMac(_) => write!(
+                f,
+                "invalid header MAC (passphrase is incorrect or header is corrupted)"
+            ),
+            Self::InvalidMac { offset } => {
+                write!(
+                    f,
+                    "invalid ciphertext MAC (corrupted at byte offset {offset})"
+                )
+            }
+            Self::InvalidPadding => write!(f, "invalid padding"),
+            Self::InvalidArmor => write!(f, "invalid ASCII armor"),
+            Self::InvalidMetadata => write!(f, "invalid metadata"),
+            Self::InvalidExtension => write!(f, "invalid header extensions"),
+            Self::UnknownCriticalExtension(ty) => {
+                write!(f, "unknown critical extension `{ty:#06x}`")
+            }
+            Self::UnsupportedAead(id) => write!(f, "unsupported AEAD `{id}`"),
+            Self::InvalidScryptParams { log_n, r, p } => {
+                write!(f, "invalid scrypt parameters (logN={log_n}, r={r}, p={p})")
+            }
+            Self::InvalidPhcString => write!(f, "invalid PHC string"),
+            Self::InvalidArchive => write!(f, "invalid archive"),
+            Self::InvalidOutputLength { expected, actual } => write!(
+                f,
+                "output buffer has the wrong length (expected {expected} bytes, got {actual} bytes)"
+            ),
+            Self::UnsupportedKdf(id) => write!(f, "unsupported KDF `{id}`"),
+            Self::InvalidKeyCommitment => write!(f, "invalid key commitment"),
+            Self::InvalidSignature => write!(f, "invalid signature"),
+            Self::InvalidRecordLog => write!(f, "invalid record log"),
+            Self::UnsupportedNormalization(id) => {
+                write!(f, "unsupported passphrase normalization form `{id}`")
+            }
+            Self::Expired => write!(f, "encrypted data is outside its validity period"),
+            Self::InvalidSharingParams { threshold, count } => write!(
+                f,
+                "invalid key sharing parameters: {threshold} of {count} key shares"
+            ),
+            Self::InvalidShare => write!(f, "key share is invalid"),
+            Self::InsufficientShares { threshold, count } => write!(
+                f,
+                "at least {threshold} key shares are required, but {count} were given"
+            ),
+            Self::InvalidPassphrase => write!(
+                f,
+                "passphrase is not valid UTF-8 but is required to be normalized"
+            ),
+            Self::PlaintextTooLong => write!(f, "plaintext is too long to be encrypted"),
         }
     }
 }
@@ -71,12 +300,19 @@ impl std::error::Error for Error {
         match self {
             Self::InvalidArgon2Params(err) | Self::InvalidArgon2Context(err) => Some(err),
             Self::InvalidHeaderMac(err) => Some(err),
-            Self::InvalidMac(err) => Some(err),
             _ => None,
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    #[inline]
+    fn from(err: Error) -> Self {
+        Self::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
 impl From<MacError> for Error {
     #[inline]
     fn from(err: MacError) -> Self {
@@ -84,10 +320,164 @@ impl From<MacError> for Error {
     }
 }
 
-impl From<chacha20poly1305::Error> for Error {
+/// A list specifying general categories of [`Error`].
+///
+/// This is the same as [`Error`] except that it does not have the details of
+/// the error, so it can be used to compare, store or transfer the kind of the
+/// error.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The encrypted data was shorter than expected.
+    InvalidLength,
+
+    /// The magic number (file signature) was invalid.
+    InvalidMagicNumber,
+
+    /// The version was the unsupported abcrypt version number.
+    UnsupportedVersion,
+
+    /// The version was the unrecognized abcrypt version number.
+    UnknownVersion,
+
+    /// The Argon2 type were invalid.
+    InvalidArgon2Type,
+
+    /// The Argon2 version were invalid.
+    InvalidArgon2Version,
+
+    /// The Argon2 parameters were invalid.
+    InvalidArgon2Params,
+
+    /// The Argon2 context was invalid.
+    InvalidArgon2Context,
+
+    /// The MAC (authentication tag) of the header was invalid.
+    InvalidHeaderMac,
+
+    /// The MAC (authentication tag) of the ciphertext was invalid.
+    InvalidMac,
+
+    /// The padding of the plaintext was invalid.
+    InvalidPadding,
+
+    /// The ASCII armor was invalid.
+    InvalidArmor,
+
+    /// The metadata of the plaintext was invalid.
+    InvalidMetadata,
+
+    /// The extensions of the header were invalid.
+    InvalidExtension,
+
+    /// The header contained the unrecognized critical extension.
+    UnknownCriticalExtension,
+
+    /// The AEAD was unrecognized or not enabled by the feature flags.
+    UnsupportedAead,
+
+    /// The scrypt parameters were invalid.
+    InvalidScryptParams,
+
+    /// The PHC string was invalid.
+    InvalidPhcString,
+
+    /// The archive was invalid.
+    InvalidArchive,
+
+    /// The output buffer had a different length from the expected one.
+    InvalidOutputLength,
+
+    /// The key derivation function was unrecognized or not enabled by the
+    /// feature flags.
+    UnsupportedKdf,
+
+    /// The key commitment of the header was invalid.
+    InvalidKeyCommitment,
+
+    /// The signature of the encrypted data was missing or invalid.
+    InvalidSignature,
+
+    /// The record log was invalid.
+    InvalidRecordLog,
+
+    /// The passphrase normalization form was unrecognized or not enabled by
+    /// the feature flags.
+    UnsupportedNormalization,
+
+    /// The encrypted data was used outside its validity period.
+    Expired,
+
+    /// The parameters of the key sharing were invalid.
+    InvalidSharingParams,
+
+    /// The key share was invalid or did not match the other key shares.
+    InvalidShare,
+
+    /// There were fewer distinct key shares than required.
+    InsufficientShares,
+
+    /// The passphrase was not valid UTF-8 although the header requested
+    /// normalizing it.
+    InvalidPassphrase,
+
+    /// The plaintext was too long to be encrypted with the AEAD.
+    PlaintextTooLong,
+}
+
+impl ErrorKind {
+    /// Returns the name of this kind of the error.
+    ///
+    /// The name is the same as the name of the variant, so this can be used as
+    /// a stable identifier of the kind of the error, for example, in the
+    /// bindings to other languages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::ErrorKind;
+    /// #
+    /// assert_eq!(ErrorKind::InvalidHeaderMac.as_str(), "InvalidHeaderMac");
+    /// assert_eq!(ErrorKind::Expired.as_str(), "Expired");
+    /// ```
+    #[must_use]
     #[inline]
-    fn from(err: chacha20poly1305::Error) -> Self {
-        Self::InvalidMac(err)
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidLength => "InvalidLength",
+            Self::InvalidMagicNumber => "InvalidMagicNumber",
+            Self::UnsupportedVersion => "UnsupportedVersion",
+            Self::UnknownVersion => "UnknownVersion",
+            Self::InvalidArgon2Type => "InvalidArgon2Type",
+            Self::InvalidArgon2Version => "InvalidArgon2Version",
+            Self::InvalidArgon2Params => "InvalidArgon2Params",
+            Self::InvalidArgon2Context => "InvalidArgon2Context",
+            Self::InvalidHeaderMac => "InvalidHeaderMac",
+            Self::InvalidMac => "InvalidMac",
+            Self::InvalidPadding => "InvalidPadding",
+            Self::InvalidArmor => "InvalidArmor",
+            Self::InvalidMetadata => "InvalidMetadata",
+            Self::InvalidExtension => "InvalidExtension",
+            Self::UnknownCriticalExtension => "UnknownCriticalExtension",
+            Self::UnsupportedAead => "UnsupportedAead",
+            Self::InvalidScryptParams => "InvalidScryptParams",
+            Self::InvalidPhcString => "InvalidPhcString",
+            Self::InvalidArchive => "InvalidArchive",
+            Self::InvalidOutputLength => "InvalidOutputLength",
+            Self::UnsupportedKdf => "UnsupportedKdf",
+            Self::InvalidKeyCommitment => "InvalidKeyCommitment",
+            Self::InvalidSignature => "InvalidSignature",
+            Self::InvalidRecordLog => "InvalidRecordLog",
+            Self::UnsupportedNormalization => "UnsupportedNormalization",
+            Self::Expired => "Expired",
+            Self::InvalidSharingParams => "InvalidSharingParams",
+            Self::InvalidShare => "InvalidShare",
+            Self::InsufficientShares => "InsufficientShares",
+            Self::InvalidPassphrase => "InvalidPassphrase",
+            Self::PlaintextTooLong => "PlaintextTooLong",
+        }
     }
 }
 
@@ -128,8 +518,12 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn clone() {
-        assert_eq!(Error::InvalidLength.clone(), Error::InvalidLength);
+        assert_eq!(
+            Error::InvalidLength { len: 163, min: 164 }.clone(),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
         assert_eq!(Error::InvalidMagicNumber.clone(), Error::InvalidMagicNumber);
         assert_eq!(
             Error::UnsupportedVersion(u8::MIN).clone(),
@@ -160,15 +554,88 @@ mod tests {
             Error::InvalidHeaderMac(MacError)
         );
         assert_eq!(
-            Error::InvalidMac(chacha20poly1305::Error).clone(),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InvalidMac { offset: 148 }.clone(),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_eq!(Error::InvalidPadding.clone(), Error::InvalidPadding);
+        assert_eq!(Error::InvalidArmor.clone(), Error::InvalidArmor);
+        assert_eq!(Error::InvalidMetadata.clone(), Error::InvalidMetadata);
+        assert_eq!(Error::InvalidExtension.clone(), Error::InvalidExtension);
+        assert_eq!(
+            Error::UnknownCriticalExtension(0xc000).clone(),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_eq!(Error::UnsupportedAead(1).clone(), Error::UnsupportedAead(1));
+        assert_eq!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+            .clone(),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_eq!(Error::InvalidPhcString.clone(), Error::InvalidPhcString);
+        assert_eq!(Error::InvalidArchive.clone(), Error::InvalidArchive);
+        assert_eq!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+            .clone(),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_eq!(Error::UnsupportedKdf(4).clone(), Error::UnsupportedKdf(4));
+        assert_eq!(
+            Error::InvalidKeyCommitment.clone(),
+            Error::InvalidKeyCommitment
+        );
+        assert_eq!(Error::InvalidSignature.clone(), Error::InvalidSignature);
+        assert_eq!(Error::InvalidRecordLog.clone(), Error::InvalidRecordLog);
+        assert_eq!(
+            Error::UnsupportedNormalization(2).clone(),
+            Error::UnsupportedNormalization(2)
         );
+        assert_eq!(Error::Expired.clone(), Error::Expired);
+        assert_eq!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+            .clone(),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_eq!(Error::InvalidShare.clone(), Error::InvalidShare);
+        assert_eq!(
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+            .clone(),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_eq!(Error::InvalidPassphrase.clone(), Error::InvalidPassphrase);
+        assert_eq!(Error::PlaintextTooLong.clone(), Error::PlaintextTooLong);
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn copy() {
         {
-            let a = Error::InvalidLength;
+            let a = Error::InvalidLength { len: 163, min: 164 };
             let b = a;
             assert_eq!(a, b);
         }
@@ -222,7 +689,146 @@ mod tests {
         }
 
         {
-            let a = Error::InvalidMac(chacha20poly1305::Error);
+            let a = Error::InvalidMac { offset: 148 };
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidPadding;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidArmor;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidMetadata;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidExtension;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::UnknownCriticalExtension(0xc000);
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::UnsupportedAead(1);
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1,
+            };
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidPhcString;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidArchive;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177,
+            };
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::UnsupportedKdf(4);
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidKeyCommitment;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidSignature;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidRecordLog;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::UnsupportedNormalization(2);
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::Expired;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5,
+            };
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidShare;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InsufficientShares {
+                threshold: 3,
+                count: 2,
+            };
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::InvalidPassphrase;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = Error::PlaintextTooLong;
             let b = a;
             assert_eq!(a, b);
         }
@@ -230,8 +836,12 @@ mod tests {
 
     #[cfg(feature = "alloc")]
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn debug() {
-        assert_eq!(format!("{:?}", Error::InvalidLength), "InvalidLength");
+        assert_eq!(
+            format!("{:?}", Error::InvalidLength { len: 163, min: 164 }),
+            "InvalidLength { len: 163, min: 164 }"
+        );
         assert_eq!(
             format!("{:?}", Error::InvalidMagicNumber),
             "InvalidMagicNumber"
@@ -268,44 +878,239 @@ mod tests {
             "InvalidHeaderMac(MacError)"
         );
         assert_eq!(
-            format!("{:?}", Error::InvalidMac(chacha20poly1305::Error)),
-            "InvalidMac(Error)"
+            format!("{:?}", Error::InvalidMac { offset: 148 }),
+            "InvalidMac { offset: 148 }"
         );
-    }
-
-    #[test]
-    fn equality() {
-        assert_eq!(Error::InvalidLength, Error::InvalidLength);
-        assert_ne!(Error::InvalidLength, Error::InvalidMagicNumber);
-        assert_ne!(Error::InvalidLength, Error::UnsupportedVersion(u8::MIN));
-        assert_ne!(Error::InvalidLength, Error::UnknownVersion(u8::MAX));
-        assert_ne!(Error::InvalidLength, Error::InvalidArgon2Type(u32::MAX));
-        assert_ne!(Error::InvalidLength, Error::InvalidArgon2Version(u32::MAX));
-        assert_ne!(
-            Error::InvalidLength,
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        assert_eq!(format!("{:?}", Error::InvalidPadding), "InvalidPadding");
+        assert_eq!(format!("{:?}", Error::InvalidArmor), "InvalidArmor");
+        assert_eq!(format!("{:?}", Error::InvalidMetadata), "InvalidMetadata");
+        assert_eq!(format!("{:?}", Error::InvalidExtension), "InvalidExtension");
+        assert_eq!(
+            format!("{:?}", Error::UnknownCriticalExtension(0xc000)),
+            "UnknownCriticalExtension(49152)"
         );
-        assert_ne!(
-            Error::InvalidLength,
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        assert_eq!(
+            format!("{:?}", Error::UnsupportedAead(1)),
+            "UnsupportedAead(1)"
         );
-        assert_ne!(Error::InvalidLength, Error::InvalidHeaderMac(MacError));
-        assert_ne!(
-            Error::InvalidLength,
-            Error::InvalidMac(chacha20poly1305::Error)
+        assert_eq!(
+            format!(
+                "{:?}",
+                Error::InvalidScryptParams {
+                    log_n: 64,
+                    r: 8,
+                    p: 1
+                }
+            ),
+            "InvalidScryptParams { log_n: 64, r: 8, p: 1 }"
         );
-        assert_ne!(Error::InvalidMagicNumber, Error::InvalidLength);
-        assert_eq!(Error::InvalidMagicNumber, Error::InvalidMagicNumber);
-        assert_ne!(
-            Error::InvalidMagicNumber,
-            Error::UnsupportedVersion(u8::MIN)
+        assert_eq!(format!("{:?}", Error::InvalidPhcString), "InvalidPhcString");
+        assert_eq!(format!("{:?}", Error::InvalidArchive), "InvalidArchive");
+        assert_eq!(
+            format!(
+                "{:?}",
+                Error::InvalidOutputLength {
+                    expected: 178,
+                    actual: 177
+                }
+            ),
+            "InvalidOutputLength { expected: 178, actual: 177 }"
         );
-        assert_ne!(Error::InvalidMagicNumber, Error::UnknownVersion(u8::MAX));
-        assert_ne!(
-            Error::InvalidMagicNumber,
-            Error::InvalidArgon2Type(u32::MAX)
+        assert_eq!(
+            format!("{:?}", Error::UnsupportedKdf(4)),
+            "UnsupportedKdf(4)"
         );
-        assert_ne!(
+        assert_eq!(
+            format!("{:?}", Error::InvalidKeyCommitment),
+            "InvalidKeyCommitment"
+        );
+        assert_eq!(format!("{:?}", Error::InvalidSignature), "InvalidSignature");
+        assert_eq!(format!("{:?}", Error::InvalidRecordLog), "InvalidRecordLog");
+        assert_eq!(
+            format!("{:?}", Error::UnsupportedNormalization(2)),
+            "UnsupportedNormalization(2)"
+        );
+        assert_eq!(format!("{:?}", Error::Expired), "Expired");
+        assert_eq!(
+            format!(
+                "{:?}",
+                Error::InvalidSharingParams {
+                    threshold: 6,
+                    count: 5
+                }
+            ),
+            "InvalidSharingParams { threshold: 6, count: 5 }"
+        );
+        assert_eq!(format!("{:?}", Error::InvalidShare), "InvalidShare");
+        assert_eq!(
+            format!(
+                "{:?}",
+                Error::InsufficientShares {
+                    threshold: 3,
+                    count: 2
+                }
+            ),
+            "InsufficientShares { threshold: 3, count: 2 }"
+        );
+        assert_eq!(
+            format!("{:?}", Error::InvalidPassphrase),
+            "InvalidPassphrase"
+        );
+        assert_eq!(format!("{:?}", Error::PlaintextTooLong), "PlaintextTooLong");
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn equality() {
+        assert_eq!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidLength { len: 163, min: 164 }, Error::Expired);
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidShare
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidLength { len: 163, min: 164 },
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_eq!(Error::InvalidMagicNumber, Error::InvalidMagicNumber);
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::UnknownVersion(u8::MAX));
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
             Error::InvalidMagicNumber,
             Error::InvalidArgon2Version(u32::MAX)
         );
@@ -318,11 +1123,63 @@ mod tests {
             Error::InvalidArgon2Context(argon2::Error::AdTooLong)
         );
         assert_ne!(Error::InvalidMagicNumber, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidPadding);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidArmor);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidSignature);
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidRecordLog);
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::Expired);
         assert_ne!(
             Error::InvalidMagicNumber,
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidMagicNumber,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidMagicNumber, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidMagicNumber, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InvalidLength { len: 163, min: 164 }
         );
-        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidLength);
         assert_ne!(
             Error::UnsupportedVersion(u8::MIN),
             Error::InvalidMagicNumber
@@ -357,9 +1214,70 @@ mod tests {
         );
         assert_ne!(
             Error::UnsupportedVersion(u8::MIN),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidPadding);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidArmor);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidMetadata);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidExtension);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidPhcString);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidArchive);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::UnsupportedKdf(4));
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidSignature);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidRecordLog);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::Expired);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidShare);
+        assert_ne!(
+            Error::UnsupportedVersion(u8::MIN),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::InvalidPassphrase);
+        assert_ne!(Error::UnsupportedVersion(u8::MIN), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidLength { len: 163, min: 164 }
         );
-        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidLength);
         assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidMagicNumber);
         assert_ne!(
             Error::UnknownVersion(u8::MAX),
@@ -378,257 +1296,2999 @@ mod tests {
             Error::InvalidArgon2Version(u32::MAX)
         );
         assert_ne!(
-            Error::UnknownVersion(u8::MAX),
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidPadding);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidArmor);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidMetadata);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidExtension);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidPhcString);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidArchive);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::UnsupportedKdf(4));
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidKeyCommitment);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidSignature);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidRecordLog);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::Expired);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidShare);
+        assert_ne!(
+            Error::UnknownVersion(u8::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::InvalidPassphrase);
+        assert_ne!(Error::UnknownVersion(u8::MAX), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_eq!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidPadding);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidArmor);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidMetadata);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidPhcString);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::UnsupportedKdf(4));
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidSignature);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidRecordLog);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::Expired);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidArgon2Type(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_eq!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::InvalidPadding);
+        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::InvalidArmor);
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidPhcString
+        );
+        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::Expired);
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidArgon2Version(u32::MAX),
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_eq!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::Expired
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidShare
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_eq!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::Expired
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidShare
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidMagicNumber);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_eq!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidPadding);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidArmor);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidMetadata);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidPhcString);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::UnsupportedKdf(4));
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidSignature);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidRecordLog);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::Expired);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidHeaderMac(MacError),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidHeaderMac(MacError), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidMagicNumber);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_eq!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidPadding);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidArmor);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::UnsupportedKdf(4));
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidSignature);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidRecordLog);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::Expired);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidMac { offset: 148 },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidMac { offset: 148 }, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidPadding, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidPadding, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidPadding, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidPadding, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(Error::InvalidPadding, Error::InvalidArgon2Version(u32::MAX));
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidPadding, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidPadding, Error::InvalidMac { offset: 148 });
+        assert_eq!(Error::InvalidPadding, Error::InvalidPadding);
+        assert_ne!(Error::InvalidPadding, Error::InvalidArmor);
+        assert_ne!(Error::InvalidPadding, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidPadding, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidPadding, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidPadding, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidPadding, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidPadding, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidPadding, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidPadding, Error::InvalidSignature);
+        assert_ne!(Error::InvalidPadding, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidPadding, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidPadding, Error::Expired);
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidPadding, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidPadding,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidPadding, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidPadding, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidArmor, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidArmor, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidArmor, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidArmor, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(Error::InvalidArmor, Error::InvalidArgon2Version(u32::MAX));
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidArmor, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidArmor, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidArmor, Error::InvalidPadding);
+        assert_eq!(Error::InvalidArmor, Error::InvalidArmor);
+        assert_ne!(Error::InvalidArmor, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidArmor, Error::InvalidExtension);
+        assert_ne!(Error::InvalidArmor, Error::UnknownCriticalExtension(0xc000));
+        assert_ne!(Error::InvalidArmor, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidArmor, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidArmor, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidArmor, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidArmor, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidArmor, Error::InvalidSignature);
+        assert_ne!(Error::InvalidArmor, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidArmor, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidArmor, Error::Expired);
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidArmor, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidArmor,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidArmor, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidArmor, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidMetadata, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidMetadata, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidMetadata, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidMetadata, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidMetadata, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidMetadata, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidMetadata, Error::InvalidPadding);
+        assert_ne!(Error::InvalidMetadata, Error::InvalidArmor);
+        assert_eq!(Error::InvalidMetadata, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidMetadata, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidMetadata, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidMetadata, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidMetadata, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidMetadata, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidMetadata, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidMetadata, Error::InvalidSignature);
+        assert_ne!(Error::InvalidMetadata, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidMetadata, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidMetadata, Error::Expired);
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidMetadata, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidMetadata,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidMetadata, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidMetadata, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidExtension, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidExtension, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidExtension, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidExtension, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidExtension, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidExtension, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidExtension, Error::InvalidPadding);
+        assert_ne!(Error::InvalidExtension, Error::InvalidArmor);
+        assert_ne!(Error::InvalidExtension, Error::InvalidMetadata);
+        assert_eq!(Error::InvalidExtension, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidExtension, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidExtension, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidExtension, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidExtension, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidExtension, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidExtension, Error::InvalidSignature);
+        assert_ne!(Error::InvalidExtension, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidExtension, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidExtension, Error::Expired);
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidExtension, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidExtension,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidExtension, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidExtension, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidPadding
+        );
+        assert_ne!(Error::UnknownCriticalExtension(0xc000), Error::InvalidArmor);
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidExtension
+        );
+        assert_eq!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::UnknownCriticalExtension(0xc000), Error::Expired);
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnknownCriticalExtension(0xc000), Error::InvalidShare);
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::UnknownCriticalExtension(0xc000),
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidMagicNumber);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::UnknownVersion(u8::MAX));
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidPadding);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidArmor);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidMetadata);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidExtension);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_eq!(Error::UnsupportedAead(1), Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidPhcString);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidArchive);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::UnsupportedKdf(4));
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidKeyCommitment);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidSignature);
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidRecordLog);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::Expired);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidShare);
+        assert_ne!(
+            Error::UnsupportedAead(1),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::UnsupportedAead(1), Error::InvalidPassphrase);
+        assert_ne!(Error::UnsupportedAead(1), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnsupportedAead(1)
+        );
+        assert_eq!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::Expired
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidShare
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            },
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidPhcString, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidPhcString, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidPhcString, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidPhcString, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidPhcString, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidPhcString, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidPhcString, Error::InvalidPadding);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidArmor);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidPhcString, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_eq!(Error::InvalidPhcString, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidPhcString, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidPhcString, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidSignature);
+        assert_ne!(Error::InvalidPhcString, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidPhcString, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidPhcString, Error::Expired);
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidPhcString, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidPhcString,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidPhcString, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidPhcString, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidArchive, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidArchive, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidArchive, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidArchive, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(Error::InvalidArchive, Error::InvalidArgon2Version(u32::MAX));
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidArchive, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidArchive, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidArchive, Error::InvalidPadding);
+        assert_ne!(Error::InvalidArchive, Error::InvalidArmor);
+        assert_ne!(Error::InvalidArchive, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidArchive, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidArchive, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidArchive, Error::InvalidPhcString);
+        assert_eq!(Error::InvalidArchive, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidArchive, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidArchive, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidArchive, Error::InvalidSignature);
+        assert_ne!(Error::InvalidArchive, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidArchive, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidArchive, Error::Expired);
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidArchive, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidArchive,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidArchive, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidArchive, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidArchive
+        );
+        assert_eq!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::Expired
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidShare
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::InvalidPassphrase
+        );
+        assert_ne!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            },
+            Error::PlaintextTooLong
+        );
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidMagicNumber);
+        assert_ne!(Error::UnsupportedKdf(4), Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::UnsupportedKdf(4), Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidPadding);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidArmor);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidMetadata);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidExtension);
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidPhcString);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidArchive);
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_eq!(Error::UnsupportedKdf(4), Error::UnsupportedKdf(4));
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidKeyCommitment);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidSignature);
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidRecordLog);
+        assert_ne!(Error::UnsupportedKdf(4), Error::UnsupportedNormalization(2));
+        assert_ne!(Error::UnsupportedKdf(4), Error::Expired);
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidShare);
+        assert_ne!(
+            Error::UnsupportedKdf(4),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::UnsupportedKdf(4), Error::InvalidPassphrase);
+        assert_ne!(Error::UnsupportedKdf(4), Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidMagicNumber);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::UnknownVersion(u8::MAX));
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidPadding);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidArmor);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::UnsupportedKdf(4));
+        assert_eq!(Error::InvalidKeyCommitment, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidSignature);
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidRecordLog);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::Expired);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidKeyCommitment,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidKeyCommitment, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidKeyCommitment, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidSignature, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidSignature, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidSignature, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidSignature, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidSignature, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidSignature, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidSignature, Error::InvalidPadding);
+        assert_ne!(Error::InvalidSignature, Error::InvalidArmor);
+        assert_ne!(Error::InvalidSignature, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidSignature, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidSignature, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidSignature, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidSignature, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidSignature, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidSignature, Error::InvalidKeyCommitment);
+        assert_eq!(Error::InvalidSignature, Error::InvalidSignature);
+        assert_ne!(Error::InvalidSignature, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidSignature, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidSignature, Error::Expired);
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidSignature, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidSignature,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidSignature, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidSignature, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidRecordLog, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidRecordLog, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidPadding);
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidArmor);
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidExtension);
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidArchive);
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidSignature);
+        assert_eq!(Error::InvalidRecordLog, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidRecordLog, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidRecordLog, Error::Expired);
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidShare);
+        assert_ne!(
+            Error::InvalidRecordLog,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::InvalidRecordLog, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidRecordLog, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidPadding);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidArmor);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidMetadata);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidExtension);
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidPhcString);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidArchive);
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::UnsupportedKdf(4));
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidSignature);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidRecordLog);
+        assert_eq!(
+            Error::UnsupportedNormalization(2),
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::Expired);
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidShare);
+        assert_ne!(
+            Error::UnsupportedNormalization(2),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::UnsupportedNormalization(2), Error::InvalidPassphrase);
+        assert_ne!(Error::UnsupportedNormalization(2), Error::PlaintextTooLong);
+        assert_ne!(Error::Expired, Error::InvalidLength { len: 163, min: 164 });
+        assert_ne!(Error::Expired, Error::InvalidMagicNumber);
+        assert_ne!(Error::Expired, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::Expired, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::Expired, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(Error::Expired, Error::InvalidArgon2Version(u32::MAX));
+        assert_ne!(
+            Error::Expired,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::Expired,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(Error::Expired, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::Expired, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::Expired, Error::InvalidPadding);
+        assert_ne!(Error::Expired, Error::InvalidArmor);
+        assert_ne!(Error::Expired, Error::InvalidMetadata);
+        assert_ne!(Error::Expired, Error::InvalidExtension);
+        assert_ne!(Error::Expired, Error::UnknownCriticalExtension(0xc000));
+        assert_ne!(Error::Expired, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::Expired,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::Expired, Error::InvalidPhcString);
+        assert_ne!(Error::Expired, Error::InvalidArchive);
+        assert_ne!(
+            Error::Expired,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::Expired, Error::UnsupportedKdf(4));
+        assert_ne!(Error::Expired, Error::InvalidKeyCommitment);
+        assert_ne!(Error::Expired, Error::InvalidSignature);
+        assert_ne!(Error::Expired, Error::InvalidRecordLog);
+        assert_ne!(Error::Expired, Error::UnsupportedNormalization(2));
+        assert_eq!(Error::Expired, Error::Expired);
+        assert_ne!(
+            Error::Expired,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::Expired, Error::InvalidShare);
+        assert_ne!(
+            Error::Expired,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_ne!(Error::Expired, Error::InvalidPassphrase);
+        assert_ne!(Error::Expired, Error::PlaintextTooLong);
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidLength { len: 163, min: 164 }
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidMagicNumber
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnsupportedVersion(u8::MIN)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnknownVersion(u8::MAX)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArgon2Type(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArgon2Version(u32::MAX)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidHeaderMac(MacError)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidMac { offset: 148 }
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidPadding
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArmor
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidMetadata
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidExtension
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnknownCriticalExtension(0xc000)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnsupportedAead(1)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidPhcString
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidArchive
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnsupportedKdf(4)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidKeyCommitment
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidSignature
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidRecordLog
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::UnsupportedNormalization(2)
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::Expired
+        );
+        assert_eq!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidShare
         );
         assert_ne!(
-            Error::UnknownVersion(u8::MAX),
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
         );
         assert_ne!(
-            Error::UnknownVersion(u8::MAX),
-            Error::InvalidHeaderMac(MacError)
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::InvalidPassphrase
         );
         assert_ne!(
-            Error::UnknownVersion(u8::MAX),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            },
+            Error::PlaintextTooLong
         );
-        assert_ne!(Error::InvalidArgon2Type(u32::MAX), Error::InvalidLength);
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidMagicNumber
+            Error::InvalidShare,
+            Error::InvalidLength { len: 163, min: 164 }
         );
+        assert_ne!(Error::InvalidShare, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidShare, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidShare, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidShare, Error::InvalidArgon2Type(u32::MAX));
+        assert_ne!(Error::InvalidShare, Error::InvalidArgon2Version(u32::MAX));
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::UnsupportedVersion(u8::MIN)
+            Error::InvalidShare,
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
         );
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::UnknownVersion(u8::MAX)
-        );
-        assert_eq!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidArgon2Type(u32::MAX)
+            Error::InvalidShare,
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
         );
+        assert_ne!(Error::InvalidShare, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidShare, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidShare, Error::InvalidPadding);
+        assert_ne!(Error::InvalidShare, Error::InvalidArmor);
+        assert_ne!(Error::InvalidShare, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidShare, Error::InvalidExtension);
+        assert_ne!(Error::InvalidShare, Error::UnknownCriticalExtension(0xc000));
+        assert_ne!(Error::InvalidShare, Error::UnsupportedAead(1));
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidArgon2Version(u32::MAX)
+            Error::InvalidShare,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
         );
+        assert_ne!(Error::InvalidShare, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidShare, Error::InvalidArchive);
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+            Error::InvalidShare,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
         );
+        assert_ne!(Error::InvalidShare, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidShare, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidShare, Error::InvalidSignature);
+        assert_ne!(Error::InvalidShare, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidShare, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidShare, Error::Expired);
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+            Error::InvalidShare,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
         );
+        assert_eq!(Error::InvalidShare, Error::InvalidShare);
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidHeaderMac(MacError)
+            Error::InvalidShare,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
         );
+        assert_ne!(Error::InvalidShare, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidShare, Error::PlaintextTooLong);
         assert_ne!(
-            Error::InvalidArgon2Type(u32::MAX),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidLength { len: 163, min: 164 }
         );
-        assert_ne!(Error::InvalidArgon2Version(u32::MAX), Error::InvalidLength);
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidMagicNumber
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::UnsupportedVersion(u8::MIN)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::UnknownVersion(u8::MAX)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidArgon2Type(u32::MAX)
         );
-        assert_eq!(
-            Error::InvalidArgon2Version(u32::MAX),
+        assert_ne!(
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidArgon2Version(u32::MAX)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidArgon2Params(argon2::Error::AdTooLong)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidArgon2Context(argon2::Error::AdTooLong)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
             Error::InvalidHeaderMac(MacError)
         );
         assert_ne!(
-            Error::InvalidArgon2Version(u32::MAX),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidMac { offset: 148 }
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidLength
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidPadding
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidMagicNumber
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidArmor
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::UnsupportedVersion(u8::MIN)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidMetadata
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::UnknownVersion(u8::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidExtension
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Type(u32::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::UnknownCriticalExtension(0xc000)
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Version(u32::MAX)
-        );
-        assert_eq!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::UnsupportedAead(1)
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidHeaderMac(MacError)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidPhcString
         );
         assert_ne!(
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidArchive
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidLength
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidMagicNumber
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::UnsupportedKdf(4)
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::UnsupportedVersion(u8::MIN)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidKeyCommitment
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::UnknownVersion(u8::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidSignature
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Type(u32::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidRecordLog
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Version(u32::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::UnsupportedNormalization(2)
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Params(argon2::Error::AdTooLong)
-        );
-        assert_eq!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::Expired
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidHeaderMac(MacError)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
         );
         assert_ne!(
-            Error::InvalidArgon2Context(argon2::Error::AdTooLong),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidShare
+        );
+        assert_eq!(
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
         );
-        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidLength);
-        assert_ne!(Error::InvalidHeaderMac(MacError), Error::InvalidMagicNumber);
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
-            Error::UnsupportedVersion(u8::MIN)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::InvalidPassphrase
         );
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
-            Error::UnknownVersion(u8::MAX)
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            },
+            Error::PlaintextTooLong
         );
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
-            Error::InvalidArgon2Type(u32::MAX)
+            Error::InvalidPassphrase,
+            Error::InvalidLength { len: 163, min: 164 }
         );
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidMagicNumber);
+        assert_ne!(Error::InvalidPassphrase, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::InvalidPassphrase, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidArgon2Type(u32::MAX));
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
+            Error::InvalidPassphrase,
             Error::InvalidArgon2Version(u32::MAX)
         );
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
+            Error::InvalidPassphrase,
             Error::InvalidArgon2Params(argon2::Error::AdTooLong)
         );
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
+            Error::InvalidPassphrase,
             Error::InvalidArgon2Context(argon2::Error::AdTooLong)
         );
-        assert_eq!(
-            Error::InvalidHeaderMac(MacError),
-            Error::InvalidHeaderMac(MacError)
-        );
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidPadding);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidArmor);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidMetadata);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidExtension);
         assert_ne!(
-            Error::InvalidHeaderMac(MacError),
-            Error::InvalidMac(chacha20poly1305::Error)
+            Error::InvalidPassphrase,
+            Error::UnknownCriticalExtension(0xc000)
         );
+        assert_ne!(Error::InvalidPassphrase, Error::UnsupportedAead(1));
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::InvalidLength
+            Error::InvalidPassphrase,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
         );
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidPhcString);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidArchive);
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::InvalidMagicNumber
+            Error::InvalidPassphrase,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
         );
+        assert_ne!(Error::InvalidPassphrase, Error::UnsupportedKdf(4));
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidKeyCommitment);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidSignature);
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidRecordLog);
+        assert_ne!(Error::InvalidPassphrase, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::InvalidPassphrase, Error::Expired);
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::UnsupportedVersion(u8::MIN)
+            Error::InvalidPassphrase,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
         );
+        assert_ne!(Error::InvalidPassphrase, Error::InvalidShare);
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::UnknownVersion(u8::MAX)
+            Error::InvalidPassphrase,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
         );
+        assert_eq!(Error::InvalidPassphrase, Error::InvalidPassphrase);
+        assert_ne!(Error::InvalidPassphrase, Error::PlaintextTooLong);
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::InvalidArgon2Type(u32::MAX)
+            Error::PlaintextTooLong,
+            Error::InvalidLength { len: 163, min: 164 }
         );
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidMagicNumber);
+        assert_ne!(Error::PlaintextTooLong, Error::UnsupportedVersion(u8::MIN));
+        assert_ne!(Error::PlaintextTooLong, Error::UnknownVersion(u8::MAX));
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidArgon2Type(u32::MAX));
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
+            Error::PlaintextTooLong,
             Error::InvalidArgon2Version(u32::MAX)
         );
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
+            Error::PlaintextTooLong,
             Error::InvalidArgon2Params(argon2::Error::AdTooLong)
         );
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
+            Error::PlaintextTooLong,
             Error::InvalidArgon2Context(argon2::Error::AdTooLong)
         );
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidHeaderMac(MacError));
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidMac { offset: 148 });
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidPadding);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidArmor);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidMetadata);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidExtension);
         assert_ne!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::InvalidHeaderMac(MacError)
+            Error::PlaintextTooLong,
+            Error::UnknownCriticalExtension(0xc000)
         );
-        assert_eq!(
-            Error::InvalidMac(chacha20poly1305::Error),
-            Error::InvalidMac(chacha20poly1305::Error)
+        assert_ne!(Error::PlaintextTooLong, Error::UnsupportedAead(1));
+        assert_ne!(
+            Error::PlaintextTooLong,
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+        );
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidPhcString);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidArchive);
+        assert_ne!(
+            Error::PlaintextTooLong,
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+        );
+        assert_ne!(Error::PlaintextTooLong, Error::UnsupportedKdf(4));
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidKeyCommitment);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidSignature);
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidRecordLog);
+        assert_ne!(Error::PlaintextTooLong, Error::UnsupportedNormalization(2));
+        assert_ne!(Error::PlaintextTooLong, Error::Expired);
+        assert_ne!(
+            Error::PlaintextTooLong,
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidShare);
+        assert_ne!(
+            Error::PlaintextTooLong,
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
         );
+        assert_ne!(Error::PlaintextTooLong, Error::InvalidPassphrase);
+        assert_eq!(Error::PlaintextTooLong, Error::PlaintextTooLong);
     }
 
     #[cfg(feature = "alloc")]
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn display() {
         assert_eq!(
-            format!("{}", Error::InvalidLength),
-            "encrypted data is shorter than 164 bytes"
+            format!("{}", Error::InvalidLength { len: 163, min: 164 }),
+            "encrypted data is shorter than 164 bytes (got 163 bytes)"
         );
         assert_eq!(
             format!("{}", Error::InvalidMagicNumber),
@@ -644,7 +4304,7 @@ mod tests {
         );
         assert_eq!(
             format!("{}", Error::InvalidArgon2Type(u32::MAX)),
-            "invalid Argon2 type"
+            "invalid Argon2 type `4294967295`"
         );
         assert_eq!(
             format!("{}", Error::InvalidArgon2Version(u32::MAX)),
@@ -660,11 +4320,96 @@ mod tests {
         );
         assert_eq!(
             format!("{}", Error::InvalidHeaderMac(MacError)),
-            "invalid header MAC"
+            "invalid header MAC (passphrase is incorrect or header is corrupted)"
         );
         assert_eq!(
-            format!("{}", Error::InvalidMac(chacha20poly1305::Error)),
-            "invalid ciphertext MAC"
+            format!("{}", Error::InvalidMac { offset: 148 }),
+            "invalid ciphertext MAC (corrupted at byte offset 148)"
+        );
+        assert_eq!(format!("{}", Error::InvalidPadding), "invalid padding");
+        assert_eq!(format!("{}", Error::InvalidArmor), "invalid ASCII armor");
+        assert_eq!(format!("{}", Error::InvalidMetadata), "invalid metadata");
+        assert_eq!(
+            format!("{}", Error::InvalidExtension),
+            "invalid header extensions"
+        );
+        assert_eq!(
+            format!("{}", Error::UnknownCriticalExtension(0xc000)),
+            "unknown critical extension `0xc000`"
+        );
+        assert_eq!(
+            format!("{}", Error::UnsupportedAead(1)),
+            "unsupported AEAD `1`"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InvalidScryptParams {
+                    log_n: 64,
+                    r: 8,
+                    p: 1
+                }
+            ),
+            "invalid scrypt parameters (logN=64, r=8, p=1)"
+        );
+        assert_eq!(format!("{}", Error::InvalidPhcString), "invalid PHC string");
+        assert_eq!(format!("{}", Error::InvalidArchive), "invalid archive");
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InvalidOutputLength {
+                    expected: 178,
+                    actual: 177
+                }
+            ),
+            "output buffer has the wrong length (expected 178 bytes, got 177 bytes)"
+        );
+        assert_eq!(
+            format!("{}", Error::UnsupportedKdf(4)),
+            "unsupported KDF `4`"
+        );
+        assert_eq!(
+            format!("{}", Error::InvalidKeyCommitment),
+            "invalid key commitment"
+        );
+        assert_eq!(format!("{}", Error::InvalidSignature), "invalid signature");
+        assert_eq!(format!("{}", Error::InvalidRecordLog), "invalid record log");
+        assert_eq!(
+            format!("{}", Error::UnsupportedNormalization(2)),
+            "unsupported passphrase normalization form `2`"
+        );
+        assert_eq!(
+            format!("{}", Error::Expired),
+            "encrypted data is outside its validity period"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InvalidSharingParams {
+                    threshold: 6,
+                    count: 5
+                }
+            ),
+            "invalid key sharing parameters: 6 of 5 key shares"
+        );
+        assert_eq!(format!("{}", Error::InvalidShare), "key share is invalid");
+        assert_eq!(
+            format!(
+                "{}",
+                Error::InsufficientShares {
+                    threshold: 3,
+                    count: 2
+                }
+            ),
+            "at least 3 key shares are required, but 2 were given"
+        );
+        assert_eq!(
+            format!("{}", Error::InvalidPassphrase),
+            "passphrase is not valid UTF-8 but is required to be normalized"
+        );
+        assert_eq!(
+            format!("{}", Error::PlaintextTooLong),
+            "plaintext is too long to be encrypted"
         );
     }
 
@@ -673,7 +4418,11 @@ mod tests {
     fn source() {
         use std::error::Error as _;
 
-        assert!(Error::InvalidLength.source().is_none());
+        assert!(
+            Error::InvalidLength { len: 163, min: 164 }
+                .source()
+                .is_none()
+        );
         assert!(Error::InvalidMagicNumber.source().is_none());
         assert!(Error::UnsupportedVersion(u8::MIN).source().is_none());
         assert!(Error::UnknownVersion(u8::MAX).source().is_none());
@@ -697,25 +4446,73 @@ mod tests {
                 .unwrap()
                 .is::<MacError>()
         );
+        assert!(Error::InvalidMac { offset: 148 }.source().is_none());
+        assert!(Error::InvalidPadding.source().is_none());
+        assert!(Error::InvalidArmor.source().is_none());
+        assert!(Error::InvalidMetadata.source().is_none());
+        assert!(Error::InvalidExtension.source().is_none());
+        assert!(Error::UnknownCriticalExtension(0xc000).source().is_none());
+        assert!(Error::UnsupportedAead(1).source().is_none());
         assert!(
-            Error::InvalidMac(chacha20poly1305::Error)
-                .source()
-                .unwrap()
-                .is::<chacha20poly1305::Error>()
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+            .source()
+            .is_none()
+        );
+        assert!(Error::InvalidPhcString.source().is_none());
+        assert!(Error::InvalidArchive.source().is_none());
+        assert!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+            .source()
+            .is_none()
+        );
+        assert!(Error::UnsupportedKdf(4).source().is_none());
+        assert!(Error::InvalidKeyCommitment.source().is_none());
+        assert!(Error::InvalidSignature.source().is_none());
+        assert!(Error::InvalidRecordLog.source().is_none());
+        assert!(Error::UnsupportedNormalization(2).source().is_none());
+        assert!(Error::Expired.source().is_none());
+        assert!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+            .source()
+            .is_none()
+        );
+        assert!(Error::InvalidShare.source().is_none());
+        assert!(
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+            .source()
+            .is_none()
         );
+        assert!(Error::InvalidPassphrase.source().is_none());
+        assert!(Error::PlaintextTooLong.source().is_none());
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn from_mac_error_to_error() {
-        assert_eq!(Error::from(MacError), Error::InvalidHeaderMac(MacError));
+    fn from_error_to_io_error() {
+        let err = std::io::Error::from(Error::InvalidLength { len: 163, min: 164 });
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+            &Error::InvalidLength { len: 163, min: 164 }
+        );
     }
 
     #[test]
-    fn from_chacha20poly1305_error_to_error() {
-        assert_eq!(
-            Error::from(chacha20poly1305::Error),
-            Error::InvalidMac(chacha20poly1305::Error)
-        );
+    fn from_mac_error_to_error() {
+        assert_eq!(Error::from(MacError), Error::InvalidHeaderMac(MacError));
     }
 
     #[test]
@@ -729,4 +4526,194 @@ mod tests {
             any::type_name::<result::Result<u8, Error>>()
         );
     }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn kind() {
+        assert_eq!(
+            Error::InvalidLength { len: 163, min: 164 }.kind(),
+            ErrorKind::InvalidLength
+        );
+        assert_eq!(
+            Error::InvalidMagicNumber.kind(),
+            ErrorKind::InvalidMagicNumber
+        );
+        assert_eq!(
+            Error::UnsupportedVersion(u8::MIN).kind(),
+            ErrorKind::UnsupportedVersion
+        );
+        assert_eq!(
+            Error::UnknownVersion(u8::MAX).kind(),
+            ErrorKind::UnknownVersion
+        );
+        assert_eq!(
+            Error::InvalidArgon2Type(u32::MAX).kind(),
+            ErrorKind::InvalidArgon2Type
+        );
+        assert_eq!(
+            Error::InvalidArgon2Version(u32::MAX).kind(),
+            ErrorKind::InvalidArgon2Version
+        );
+        assert_eq!(
+            Error::InvalidArgon2Params(argon2::Error::AdTooLong).kind(),
+            ErrorKind::InvalidArgon2Params
+        );
+        assert_eq!(
+            Error::InvalidArgon2Context(argon2::Error::AdTooLong).kind(),
+            ErrorKind::InvalidArgon2Context
+        );
+        assert_eq!(
+            Error::InvalidHeaderMac(MacError).kind(),
+            ErrorKind::InvalidHeaderMac
+        );
+        assert_eq!(
+            Error::InvalidMac { offset: 148 }.kind(),
+            ErrorKind::InvalidMac
+        );
+        assert_eq!(Error::InvalidPadding.kind(), ErrorKind::InvalidPadding);
+        assert_eq!(Error::InvalidArmor.kind(), ErrorKind::InvalidArmor);
+        assert_eq!(Error::InvalidMetadata.kind(), ErrorKind::InvalidMetadata);
+        assert_eq!(Error::InvalidExtension.kind(), ErrorKind::InvalidExtension);
+        assert_eq!(
+            Error::UnknownCriticalExtension(0xc000).kind(),
+            ErrorKind::UnknownCriticalExtension
+        );
+        assert_eq!(Error::UnsupportedAead(1).kind(), ErrorKind::UnsupportedAead);
+        assert_eq!(
+            Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }
+            .kind(),
+            ErrorKind::InvalidScryptParams
+        );
+        assert_eq!(Error::InvalidPhcString.kind(), ErrorKind::InvalidPhcString);
+        assert_eq!(Error::InvalidArchive.kind(), ErrorKind::InvalidArchive);
+        assert_eq!(
+            Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }
+            .kind(),
+            ErrorKind::InvalidOutputLength
+        );
+        assert_eq!(Error::UnsupportedKdf(4).kind(), ErrorKind::UnsupportedKdf);
+        assert_eq!(
+            Error::InvalidKeyCommitment.kind(),
+            ErrorKind::InvalidKeyCommitment
+        );
+        assert_eq!(Error::InvalidSignature.kind(), ErrorKind::InvalidSignature);
+        assert_eq!(Error::InvalidRecordLog.kind(), ErrorKind::InvalidRecordLog);
+        assert_eq!(
+            Error::UnsupportedNormalization(2).kind(),
+            ErrorKind::UnsupportedNormalization
+        );
+        assert_eq!(Error::Expired.kind(), ErrorKind::Expired);
+        assert_eq!(
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+            .kind(),
+            ErrorKind::InvalidSharingParams
+        );
+        assert_eq!(Error::InvalidShare.kind(), ErrorKind::InvalidShare);
+        assert_eq!(
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+            .kind(),
+            ErrorKind::InsufficientShares
+        );
+        assert_eq!(
+            Error::InvalidPassphrase.kind(),
+            ErrorKind::InvalidPassphrase
+        );
+        assert_eq!(Error::PlaintextTooLong.kind(), ErrorKind::PlaintextTooLong);
+    }
+
+    #[test]
+    fn error_kind_as_str() {
+        assert_eq!(ErrorKind::InvalidLength.as_str(), "InvalidLength");
+        assert_eq!(ErrorKind::InvalidMagicNumber.as_str(), "InvalidMagicNumber");
+        assert_eq!(ErrorKind::UnsupportedVersion.as_str(), "UnsupportedVersion");
+        assert_eq!(ErrorKind::UnknownVersion.as_str(), "UnknownVersion");
+        assert_eq!(ErrorKind::InvalidArgon2Type.as_str(), "InvalidArgon2Type");
+        assert_eq!(
+            ErrorKind::InvalidArgon2Version.as_str(),
+            "InvalidArgon2Version"
+        );
+        assert_eq!(
+            ErrorKind::InvalidArgon2Params.as_str(),
+            "InvalidArgon2Params"
+        );
+        assert_eq!(
+            ErrorKind::InvalidArgon2Context.as_str(),
+            "InvalidArgon2Context"
+        );
+        assert_eq!(ErrorKind::InvalidHeaderMac.as_str(), "InvalidHeaderMac");
+        assert_eq!(ErrorKind::InvalidMac.as_str(), "InvalidMac");
+        assert_eq!(ErrorKind::InvalidPadding.as_str(), "InvalidPadding");
+        assert_eq!(ErrorKind::InvalidArmor.as_str(), "InvalidArmor");
+        assert_eq!(ErrorKind::InvalidMetadata.as_str(), "InvalidMetadata");
+        assert_eq!(ErrorKind::InvalidExtension.as_str(), "InvalidExtension");
+        assert_eq!(
+            ErrorKind::UnknownCriticalExtension.as_str(),
+            "UnknownCriticalExtension"
+        );
+        assert_eq!(ErrorKind::UnsupportedAead.as_str(), "UnsupportedAead");
+        assert_eq!(
+            ErrorKind::InvalidScryptParams.as_str(),
+            "InvalidScryptParams"
+        );
+        assert_eq!(ErrorKind::InvalidPhcString.as_str(), "InvalidPhcString");
+        assert_eq!(ErrorKind::InvalidArchive.as_str(), "InvalidArchive");
+        assert_eq!(
+            ErrorKind::InvalidOutputLength.as_str(),
+            "InvalidOutputLength"
+        );
+        assert_eq!(ErrorKind::UnsupportedKdf.as_str(), "UnsupportedKdf");
+        assert_eq!(
+            ErrorKind::InvalidKeyCommitment.as_str(),
+            "InvalidKeyCommitment"
+        );
+        assert_eq!(ErrorKind::InvalidSignature.as_str(), "InvalidSignature");
+        assert_eq!(ErrorKind::InvalidRecordLog.as_str(), "InvalidRecordLog");
+        assert_eq!(
+            ErrorKind::UnsupportedNormalization.as_str(),
+            "UnsupportedNormalization"
+        );
+        assert_eq!(ErrorKind::Expired.as_str(), "Expired");
+        assert_eq!(
+            ErrorKind::InvalidSharingParams.as_str(),
+            "InvalidSharingParams"
+        );
+        assert_eq!(ErrorKind::InvalidShare.as_str(), "InvalidShare");
+        assert_eq!(ErrorKind::InsufficientShares.as_str(), "InsufficientShares");
+        assert_eq!(ErrorKind::InvalidPassphrase.as_str(), "InvalidPassphrase");
+        assert_eq!(ErrorKind::PlaintextTooLong.as_str(), "PlaintextTooLong");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_error_kind() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &ErrorKind::InvalidLength,
+            &[Token::UnitVariant {
+                name: "ErrorKind",
+                variant: "invalidLength",
+            }],
+        );
+        assert_tokens(
+            &ErrorKind::InvalidHeaderMac,
+            &[Token::UnitVariant {
+                name: "ErrorKind",
+                variant: "invalidHeaderMac",
+            }],
+        );
+    }
 }
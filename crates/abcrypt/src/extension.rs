@@ -0,0 +1,423 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Extensions of the header.
+
+use core::iter::FusedIterator;
+
+use crate::{Error, Result};
+
+/// The number of bytes of the type and the length of a record.
+const RECORD_HEADER_SIZE: usize = 4;
+
+/// The extension type of the AEAD identifier.
+pub const AEAD: u16 = 0x0001;
+
+/// The extension type of the segment size of the segmented payload.
+pub const SEGMENT_SIZE: u16 = 0x0002;
+
+/// The extension type of the key commitment.
+pub const KEY_COMMITMENT: u16 = 0x0003;
+
+/// The extension type of the public key which verifies the signature.
+pub const SIGNATURE: u16 = 0x0004;
+
+/// The extension type which marks the ciphertext as a record log.
+pub const RECORD_LOG: u16 = 0x0005;
+
+/// The extension type of the Unicode normalization form of the passphrase.
+pub const NORMALIZATION: u16 = 0x0006;
+
+/// The extension type of the key identifier.
+pub const KEY_ID: u16 = 0x0007;
+
+/// The extension type of the validity period.
+pub const VALIDITY: u16 = 0x0008;
+
+/// The extension types defined by the abcrypt encrypted data format, without
+/// the critical bit.
+const KNOWN_TYPES: &[u16] = &[
+    AEAD,
+    SEGMENT_SIZE,
+    KEY_COMMITMENT,
+    SIGNATURE,
+    RECORD_LOG,
+    NORMALIZATION,
+    KEY_ID,
+    VALIDITY,
+];
+
+/// An extension record stored in the header.
+///
+/// An extension consists of a 16-bit type and a value of at most 65535 bytes.
+/// If the most significant bit of the type ([`Extension::CRITICAL`]) is set,
+/// the extension is critical, and the data containing it is rejected by the
+/// implementations which do not recognize the type. Otherwise, unknown
+/// extensions are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::Extension;
+/// #
+/// let extension = Extension::new(0x4000, b"Hello, world!");
+/// assert_eq!(extension.ty(), 0x4000);
+/// assert!(!extension.is_critical());
+/// assert_eq!(extension.value(), b"Hello, world!");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Extension<'a> {
+    ty: u16,
+    value: &'a [u8],
+}
+
+impl<'a> Extension<'a> {
+    /// The bit of the type which marks the extension as critical.
+    pub const CRITICAL: u16 = 0x8000;
+
+    /// The first type reserved for the applications.
+    ///
+    /// The types from `0x0000` to `0x3fff` are reserved for the abcrypt
+    /// encrypted data format, and the types from `0x4000` to `0x7fff` can be
+    /// used by the applications.
+    pub const PRIVATE_USE: u16 = 0x4000;
+
+    /// Creates a new `Extension`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is longer than 65535 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Extension;
+    /// #
+    /// let extension = Extension::new(Extension::CRITICAL | 0x4000, &[]);
+    /// assert!(extension.is_critical());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(ty: u16, value: &'a [u8]) -> Self {
+        assert!(
+            value.len() <= u16::MAX as usize,
+            "extension value is too long"
+        );
+        Self { ty, value }
+    }
+
+    /// Gets the type of this extension, including the critical bit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Extension;
+    /// #
+    /// let extension = Extension::new(Extension::CRITICAL | 0x4000, &[]);
+    /// assert_eq!(extension.ty(), 0xc000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn ty(&self) -> u16 {
+        self.ty
+    }
+
+    /// Returns `true` if this extension is critical.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Extension;
+    /// #
+    /// assert!(Extension::new(0xc000, &[]).is_critical());
+    /// assert!(!Extension::new(0x4000, &[]).is_critical());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_critical(&self) -> bool {
+        self.ty & Self::CRITICAL != 0
+    }
+
+    /// Gets the value of this extension.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Extension;
+    /// #
+    /// assert_eq!(Extension::new(0x4000, b"abc").value(), b"abc");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn value(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Returns the type of this extension without the critical bit.
+    const fn number(&self) -> u16 {
+        self.ty & !Self::CRITICAL
+    }
+
+    /// Returns `true` if the type of this extension is defined by the abcrypt
+    /// encrypted data format.
+    fn is_known(&self) -> bool {
+        KNOWN_TYPES.contains(&self.number())
+    }
+}
+
+/// An iterator over the [`Extension`]s stored in the header.
+///
+/// This is created by [`Decryptor::extensions`](crate::Decryptor::extensions).
+#[derive(Clone, Debug)]
+pub struct Extensions<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for Extensions<'a> {
+    type Item = Extension<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (record, rest) = split_record(self.data).ok()??;
+        self.data = rest;
+        Some(record)
+    }
+}
+
+impl FusedIterator for Extensions<'_> {}
+
+/// Splits the first record from `data`.
+///
+/// Returns [`None`] if `data` is empty.
+fn split_record(data: &[u8]) -> Result<Option<(Extension<'_>, &[u8])>> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+    let Some((record_header, rest)) = data.split_first_chunk::<RECORD_HEADER_SIZE>() else {
+        return Err(Error::InvalidExtension);
+    };
+    let ty = u16::from_le_bytes([record_header[0], record_header[1]]);
+    let len = u16::from_le_bytes([record_header[2], record_header[3]]).into();
+    if rest.len() < len {
+        return Err(Error::InvalidExtension);
+    }
+    let (value, rest) = rest.split_at(len);
+    Ok(Some((Extension::new(ty, value), rest)))
+}
+
+/// The extension area of the header.
+#[derive(Clone, Debug)]
+pub struct ExtensionArea {
+    buf: [u8; Self::MAX_SIZE],
+    len: usize,
+}
+
+impl ExtensionArea {
+    /// The maximum number of bytes of the extension area.
+    pub const MAX_SIZE: usize = 1024;
+
+    /// Creates a new empty `ExtensionArea`.
+    pub const fn new() -> Self {
+        Self {
+            buf: [u8::MIN; Self::MAX_SIZE],
+            len: usize::MIN,
+        }
+    }
+
+    /// Parses `data` into the extension area.
+    ///
+    /// Returns [`Err`] if the records are malformed, the same type appears more
+    /// than once, or there is a critical extension of the unknown type.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let mut area = Self::new();
+        if data.len() > Self::MAX_SIZE {
+            return Err(Error::InvalidExtension);
+        }
+        let mut rest = data;
+        while let Some((record, next)) = split_record(rest)? {
+            if record.is_critical() && !record.is_known() {
+                return Err(Error::UnknownCriticalExtension(record.ty()));
+            }
+            area.push(record)?;
+            rest = next;
+        }
+        Ok(area)
+    }
+
+    /// Appends `extension` to the extension area.
+    ///
+    /// Returns [`Err`] if the extension area does not have enough space or the
+    /// type of `extension` is already present.
+    pub fn push(&mut self, extension: Extension<'_>) -> Result<()> {
+        if self.get(extension.number()).is_some() {
+            return Err(Error::InvalidExtension);
+        }
+        let value = extension.value();
+        let end = self.len + RECORD_HEADER_SIZE + value.len();
+        let Some(record) = self.buf.get_mut(self.len..end) else {
+            return Err(Error::InvalidExtension);
+        };
+        let len =
+            u16::try_from(value.len()).expect("extension value should be at most 65535 bytes");
+        record[..2].copy_from_slice(&extension.ty().to_le_bytes());
+        record[2..4].copy_from_slice(&len.to_le_bytes());
+        record[RECORD_HEADER_SIZE..].copy_from_slice(value);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Removes the extension of the type `number` (without the critical bit).
+    pub fn remove(&mut self, number: u16) {
+        let mut offset = usize::MIN;
+        for extension in self.iter() {
+            let end = offset + RECORD_HEADER_SIZE + extension.value().len();
+            if extension.number() == number & !Extension::CRITICAL {
+                self.buf.copy_within(end..self.len, offset);
+                self.len -= end - offset;
+                return;
+            }
+            offset = end;
+        }
+    }
+
+    /// Returns the extension of the type `number` (without the critical bit).
+    pub fn get(&self, number: u16) -> Option<Extension<'_>> {
+        self.iter()
+            .find(|e| e.number() == number & !Extension::CRITICAL)
+    }
+
+    /// Returns an iterator over the extensions.
+    pub fn iter(&self) -> Extensions<'_> {
+        Extensions {
+            data: self.as_bytes(),
+        }
+    }
+
+    /// Returns the encoded extension area.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Returns the number of bytes of the encoded extension area.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn critical() {
+        assert_eq!(Extension::CRITICAL, 0x8000);
+        assert!(Extension::new(0x8001, &[]).is_critical());
+        assert!(!Extension::new(0x7fff, &[]).is_critical());
+    }
+
+    #[test]
+    #[should_panic(expected = "extension value is too long")]
+    fn new_with_too_long_value() {
+        static VALUE: [u8; 65536] = [u8::MIN; 65536];
+
+        let _ = Extension::new(0x4000, &VALUE);
+    }
+
+    #[test]
+    fn push() {
+        let mut area = ExtensionArea::new();
+        area.push(Extension::new(0x4000, b"ab")).unwrap();
+        area.push(Extension::new(0xc001, &[])).unwrap();
+        assert_eq!(
+            area.as_bytes(),
+            [0x00, 0x40, 0x02, 0x00, b'a', b'b', 0x01, 0xc0, 0x00, 0x00]
+        );
+        assert_eq!(area.len(), 10);
+    }
+
+    #[test]
+    fn push_duplicate_type() {
+        let mut area = ExtensionArea::new();
+        area.push(Extension::new(0x4000, &[])).unwrap();
+        assert_eq!(
+            area.push(Extension::new(0xc000, &[])).unwrap_err(),
+            Error::InvalidExtension
+        );
+    }
+
+    #[test]
+    fn push_too_large() {
+        let mut area = ExtensionArea::new();
+        let value = [u8::default(); ExtensionArea::MAX_SIZE - RECORD_HEADER_SIZE];
+        area.push(Extension::new(0x4000, &value)).unwrap();
+        assert_eq!(
+            area.push(Extension::new(0x4001, &[])).unwrap_err(),
+            Error::InvalidExtension
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let area = ExtensionArea::parse(&[0x00, 0x40, 0x02, 0x00, b'a', b'b']).unwrap();
+        let mut extensions = area.iter();
+        assert_eq!(extensions.next(), Some(Extension::new(0x4000, b"ab")));
+        assert_eq!(extensions.next(), None);
+
+        assert_eq!(ExtensionArea::parse(&[]).unwrap().iter().count(), 0);
+    }
+
+    #[test]
+    fn parse_with_unknown_critical_extension() {
+        assert_eq!(
+            ExtensionArea::parse(&[0x00, 0xc0, 0x00, 0x00]).unwrap_err(),
+            Error::UnknownCriticalExtension(0xc000)
+        );
+    }
+
+    #[test]
+    fn parse_with_invalid_extension() {
+        // Truncated record header.
+        assert_eq!(
+            ExtensionArea::parse(&[0x00, 0x40, 0x00]).unwrap_err(),
+            Error::InvalidExtension
+        );
+        // Truncated value.
+        assert_eq!(
+            ExtensionArea::parse(&[0x00, 0x40, 0x02, 0x00, b'a']).unwrap_err(),
+            Error::InvalidExtension
+        );
+        // Duplicate type.
+        assert_eq!(
+            ExtensionArea::parse(&[0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00]).unwrap_err(),
+            Error::InvalidExtension
+        );
+        // Too large.
+        assert_eq!(
+            ExtensionArea::parse(&[u8::default(); ExtensionArea::MAX_SIZE + 1]).unwrap_err(),
+            Error::InvalidExtension
+        );
+    }
+
+    #[test]
+    fn remove() {
+        let mut area = ExtensionArea::new();
+        area.push(Extension::new(0x4000, b"ab")).unwrap();
+        area.push(Extension::new(0xc001, b"c")).unwrap();
+        area.push(Extension::new(0x4002, &[])).unwrap();
+        area.remove(0x4001);
+        assert_eq!(
+            area.as_bytes(),
+            [0x00, 0x40, 0x02, 0x00, b'a', b'b', 0x02, 0x40, 0x00, 0x00]
+        );
+        area.remove(0x4003);
+        assert_eq!(area.len(), 10);
+    }
+
+    #[test]
+    fn get() {
+        let mut area = ExtensionArea::new();
+        area.push(Extension::new(0xc000, b"a")).unwrap();
+        assert_eq!(area.get(0x4000), Some(Extension::new(0xc000, b"a")));
+        assert_eq!(area.get(0x4001), None);
+    }
+}
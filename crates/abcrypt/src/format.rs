@@ -4,19 +4,24 @@
 
 //! Specifications of the abcrypt encrypted data format.
 
-use core::mem;
+use core::{mem, num::NonZeroU32};
 
-use argon2::Algorithm;
 use blake2::{
-    Blake2bMac512,
-    digest::{self, Mac, Output, OutputSizeUser, typenum::Unsigned},
+    Blake2bMac, Blake2bMac512,
+    digest::{self, Mac, Output, OutputSizeUser, consts::U32, typenum::Unsigned},
 };
 use chacha20poly1305::{
     AeadCore, Key as XChaCha20Poly1305Key, KeySizeUser, XChaCha20Poly1305, XNonce,
 };
 use rand::{Rng, SeedableRng, rngs::StdRng};
 
-use crate::{Error, Params, Result, argon2_context};
+#[cfg(feature = "scrypt")]
+use crate::ScryptParams;
+use crate::{
+    Aead, Argon2, Error, Extension, Kdf, KeyId, Normalization, Params, Result, Validity,
+    argon2_context,
+    extension::{self, ExtensionArea},
+};
 
 /// A type alias for magic number of the abcrypt encrypted data format.
 type MagicNumber = [u8; 7];
@@ -30,8 +35,21 @@ type Blake2bMac512Output = Output<Blake2bMac512>;
 /// A type alias for key of BLAKE2b-512-MAC.
 type Blake2bMac512Key = digest::Key<Blake2bMac512>;
 
+/// A type alias for BLAKE2b-MAC with the 256-bit output.
+type Blake2bMac256 = Blake2bMac<U32>;
+
+/// A type alias for the key commitment.
+type KeyCommitment = Output<Blake2bMac256>;
+
+/// A type alias for the public key which verifies the signature.
+type VerifyingKey = [u8; 32];
+
 /// The number of bytes of the header.
 ///
+/// This is the size of the header of version 1 of the abcrypt encrypted data
+/// format. The header of version 2 is longer because it contains the length of
+/// the extensions and the extensions.
+///
 /// # Examples
 ///
 /// ```
@@ -63,24 +81,31 @@ pub const HEADER_SIZE: usize = Header::SIZE;
 pub const TAG_SIZE: usize = <XChaCha20Poly1305 as AeadCore>::TagSize::USIZE;
 
 /// Version of the abcrypt encrypted data format.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-enum Version {
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum FormatVersion {
     /// Version 0.
     V0,
 
     /// Version 1.
     #[default]
     V1,
+
+    /// Version 2.
+    V2,
+
+    /// Version 3.
+    V3,
 }
 
-impl From<Version> for u8 {
+impl From<FormatVersion> for u8 {
     #[inline]
-    fn from(version: Version) -> Self {
+    fn from(version: FormatVersion) -> Self {
         version as Self
     }
 }
 
-impl TryFrom<u8> for Version {
+impl TryFrom<u8> for FormatVersion {
     type Error = Error;
 
     #[inline]
@@ -88,6 +113,8 @@ impl TryFrom<u8> for Version {
         match version {
             0 => Ok(Self::V0),
             1 => Ok(Self::V1),
+            2 => Ok(Self::V2),
+            3 => Ok(Self::V3),
             v => Err(Error::UnknownVersion(v)),
         }
     }
@@ -97,12 +124,19 @@ impl TryFrom<u8> for Version {
 #[derive(Clone, Debug)]
 pub struct Header {
     magic_number: MagicNumber,
-    version: Version,
-    argon2_type: argon2_context::Variant,
-    argon2_version: argon2_context::Version,
-    params: Params,
+    version: FormatVersion,
+    kdf: Kdf,
     salt: Salt,
     nonce: XNonce,
+    extensions: ExtensionArea,
+    aead: Aead,
+    segment_size: Option<NonZeroU32>,
+    key_commitment: Option<KeyCommitment>,
+    verifying_key: Option<VerifyingKey>,
+    record_log: bool,
+    normalization: Option<Normalization>,
+    key_id: Option<KeyId>,
+    validity: Validity,
     mac: Blake2bMac512Output,
 }
 
@@ -112,9 +146,12 @@ impl Header {
     /// This is the ASCII code for "abcrypt".
     const MAGIC_NUMBER: MagicNumber = *b"abcrypt";
 
-    /// The number of bytes of the header.
-    const SIZE: usize = mem::size_of::<MagicNumber>()
-        + mem::size_of::<Version>()
+    /// The data of the MAC used as the key commitment.
+    const KEY_COMMITMENT_DATA: &[u8] = b"abcrypt key commitment";
+
+    /// The number of bytes of the header of version 1.
+    pub const SIZE: usize = mem::size_of::<MagicNumber>()
+        + mem::size_of::<FormatVersion>()
         + mem::size_of::<argon2_context::Variant>()
         + mem::size_of::<argon2_context::Version>()
         + mem::size_of::<Params>()
@@ -122,57 +159,246 @@ impl Header {
         + <XChaCha20Poly1305 as AeadCore>::NonceSize::USIZE
         + <Blake2bMac512 as OutputSizeUser>::OutputSize::USIZE;
 
+    /// The number of bytes of the fields before the extensions.
+    pub const FIXED_SIZE: usize = Self::SIZE - Self::MAC_SIZE;
+
+    /// The number of bytes of the length of the extensions.
+    pub const EXTENSIONS_LEN_SIZE: usize = mem::size_of::<u32>();
+
+    /// The number of bytes of the MAC of the header.
+    pub const MAC_SIZE: usize = <Blake2bMac512 as OutputSizeUser>::OutputSize::USIZE;
+
+    /// The number of bytes of the signature appended to the ciphertext.
+    pub const SIGNATURE_SIZE: usize = 64;
+
+    /// The maximum number of bytes of the header.
+    pub const MAX_SIZE: usize = Self::SIZE + Self::EXTENSIONS_LEN_SIZE + ExtensionArea::MAX_SIZE;
+
     /// Creates a new `Header`.
-    pub fn new(
-        argon2_type: Algorithm,
-        argon2_version: argon2::Version,
-        params: argon2::Params,
-    ) -> Self {
+    ///
+    /// The key derivation function other than Argon2 requires version 3 of the
+    /// abcrypt encrypted data format.
+    pub fn new(kdf: Kdf) -> Self {
         let magic_number = Self::MAGIC_NUMBER;
-        let version = Version::default();
-        let argon2_type = argon2_type.into();
-        let argon2_version = argon2_version.into();
-        let params = params.into();
+        let version = if matches!(kdf, Kdf::Argon2(_)) {
+            FormatVersion::default()
+        } else {
+            FormatVersion::V3
+        };
         let salt = StdRng::from_entropy().r#gen();
         let nonce = XChaCha20Poly1305::generate_nonce(StdRng::from_entropy());
+        let extensions = ExtensionArea::new();
+        let aead = Aead::default();
+        let segment_size = None;
+        let key_commitment = None;
+        let verifying_key = None;
+        let record_log = false;
+        let normalization = None;
+        let key_id = None;
+        let validity = Validity::default();
         let mac = Blake2bMac512Output::default();
         Self {
             magic_number,
             version,
-            argon2_type,
-            argon2_version,
-            params,
+            kdf,
             salt,
             nonce,
+            extensions,
+            aead,
+            segment_size,
+            key_commitment,
+            verifying_key,
+            record_log,
+            normalization,
+            key_id,
+            validity,
             mac,
         }
     }
 
     /// Parses `data` into the header.
+    ///
+    /// `data` must be followed by at least the MAC of the ciphertext.
+    #[inline]
     pub fn parse(data: &[u8]) -> Result<Self> {
-        if data.len() < Self::SIZE + TAG_SIZE {
-            return Err(Error::InvalidLength);
+        Self::parse_with_trailing_len(data, TAG_SIZE)
+    }
+
+    /// Parses `data` into the header which is stored separately from the
+    /// ciphertext.
+    #[inline]
+    pub fn parse_detached(data: &[u8]) -> Result<Self> {
+        Self::parse_with_trailing_len(data, usize::MIN)
+    }
+
+    /// Parses `data` into the header which is followed by at least
+    /// `trailing_len` bytes.
+    fn parse_with_trailing_len(data: &[u8], trailing_len: usize) -> Result<Self> {
+        if data.len() < Self::SIZE + trailing_len {
+            return Err(Error::InvalidLength {
+                len: data.len(),
+                min: Self::SIZE + trailing_len,
+            });
         }
 
         let Some(magic_number) = Some(Self::MAGIC_NUMBER).filter(|mn| &data[..7] == mn) else {
             return Err(Error::InvalidMagicNumber);
         };
-        let version = Version::try_from(data[7])?;
-        if version != Version::V1 {
+        let version = Self::parse_version(data)?;
+        let kdf = Self::parse_kdf(data, version)?;
+        let salt = data[28..60]
+            .try_into()
+            .expect("size of salt should be 32 bytes");
+        let nonce = *XNonce::from_slice(&data[60..84]);
+        let extensions = if matches!(version, FormatVersion::V2 | FormatVersion::V3) {
+            Self::parse_extensions(data, trailing_len)?
+        } else {
+            ExtensionArea::new()
+        };
+        let aead = Self::parse_aead(&extensions)?;
+        let segment_size = Self::parse_segment_size(&extensions)?;
+        let key_commitment = Self::parse_key_commitment(&extensions)?;
+        let verifying_key = Self::parse_verifying_key(&extensions)?;
+        let record_log = Self::parse_record_log(&extensions)?;
+        let normalization = Self::parse_normalization(&extensions)?;
+        let key_id = Self::parse_key_id(&extensions)?;
+        let validity = Self::parse_validity(&extensions)?;
+        let mac = Blake2bMac512Output::default();
+        Ok(Self {
+            magic_number,
+            version,
+            kdf,
+            salt,
+            nonce,
+            extensions,
+            aead,
+            segment_size,
+            key_commitment,
+            verifying_key,
+            record_log,
+            normalization,
+            key_id,
+            validity,
+            mac,
+        })
+    }
+
+    /// Returns `true` if `data` starts with the magic number.
+    #[inline]
+    pub fn has_magic_number(data: &[u8]) -> bool {
+        data.starts_with(&Self::MAGIC_NUMBER)
+    }
+
+    /// Parses the version number in `data`.
+    ///
+    /// `data` must be at least 8 bytes long.
+    pub fn parse_version(data: &[u8]) -> Result<FormatVersion> {
+        let version = FormatVersion::try_from(data[7])?;
+        if version == FormatVersion::V0 {
             return Err(Error::UnsupportedVersion(version.into()));
         }
-        let argon2_type = u32::from_le_bytes(
+        Ok(version)
+    }
+
+    /// Parses the key derivation function in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    pub fn parse_kdf(data: &[u8], version: FormatVersion) -> Result<Kdf> {
+        if Self::is_argon2(data, version) {
+            let argon2_type = Self::parse_argon2_type(data)?;
+            let argon2_version = Self::parse_argon2_version(data)?;
+            let params = Self::parse_params(data)?;
+            return Ok(Argon2::from_parts(argon2_type, argon2_version, params).into());
+        }
+        match Self::parse_kdf_id(data) {
+            #[cfg(feature = "scrypt")]
+            Kdf::SCRYPT_ID => Self::parse_scrypt_params(data).map(Kdf::Scrypt),
+            #[cfg(feature = "sharing")]
+            Kdf::FILE_KEY_ID => Ok(Kdf::FileKey),
+            id => Err(Error::UnsupportedKdf(id)),
+        }
+    }
+
+    /// Returns `true` if the key derivation function in `data` is Argon2.
+    ///
+    /// Version 1 and 2 always use Argon2. In version 3, the identifiers of the
+    /// key derivation function from 0 to 2 are the Argon2 types.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    pub fn is_argon2(data: &[u8], version: FormatVersion) -> bool {
+        version != FormatVersion::V3
+            || argon2_context::Variant::try_from(Self::parse_kdf_id(data)).is_ok()
+    }
+
+    /// Parses the identifier of the key derivation function in `data`.
+    ///
+    /// This shares the field with the Argon2 type.
+    fn parse_kdf_id(data: &[u8]) -> u32 {
+        u32::from_le_bytes(
+            data[8..12]
+                .try_into()
+                .expect("size of the KDF identifier should be 4 bytes"),
+        )
+    }
+
+    /// Parses the scrypt parameters in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    #[cfg(feature = "scrypt")]
+    fn parse_scrypt_params(data: &[u8]) -> Result<ScryptParams> {
+        let log_n = u32::from_le_bytes(
+            data[12..16]
+                .try_into()
+                .expect("size of `logN` should be 4 bytes"),
+        );
+        let r = u32::from_le_bytes(
+            data[16..20]
+                .try_into()
+                .expect("size of `r` should be 4 bytes"),
+        );
+        let p = u32::from_le_bytes(
+            data[20..24]
+                .try_into()
+                .expect("size of `p` should be 4 bytes"),
+        );
+        let Ok(log_n) = u8::try_from(log_n) else {
+            return Err(Error::InvalidScryptParams {
+                log_n: u8::MAX,
+                r,
+                p,
+            });
+        };
+        ScryptParams::try_new(log_n, r, p)
+    }
+
+    /// Parses the Argon2 type in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    pub fn parse_argon2_type(data: &[u8]) -> Result<argon2_context::Variant> {
+        u32::from_le_bytes(
             data[8..12]
                 .try_into()
                 .expect("size of the Argon2 type should be 4 bytes"),
         )
-        .try_into()?;
-        let argon2_version = u32::from_le_bytes(
+        .try_into()
+    }
+
+    /// Parses the Argon2 version in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    pub fn parse_argon2_version(data: &[u8]) -> Result<argon2_context::Version> {
+        u32::from_le_bytes(
             data[12..16]
                 .try_into()
                 .expect("size of the Argon2 version should be 4 bytes"),
         )
-        .try_into()?;
+        .try_into()
+    }
+
+    /// Parses the Argon2 parameters in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long.
+    pub fn parse_params(data: &[u8]) -> Result<Params> {
         let memory_cost = u32::from_le_bytes(
             data[16..20]
                 .try_into()
@@ -188,75 +414,468 @@ impl Header {
                 .try_into()
                 .expect("size of `parallelism` should be 4 bytes"),
         );
-        let params = argon2::Params::new(memory_cost, time_cost, parallelism, None)
+        argon2::Params::new(memory_cost, time_cost, parallelism, None)
             .map(Params::from)
-            .map_err(Error::InvalidArgon2Params)?;
-        let salt = data[28..60]
-            .try_into()
-            .expect("size of salt should be 32 bytes");
-        let nonce = *XNonce::from_slice(&data[60..84]);
-        let mac = Blake2bMac512Output::default();
-        Ok(Self {
-            magic_number,
-            version,
-            argon2_type,
-            argon2_version,
-            params,
-            salt,
-            nonce,
-            mac,
-        })
+            .map_err(Error::InvalidArgon2Params)
+    }
+
+    /// Parses the extensions of version 2 in `data`.
+    ///
+    /// `data` must be at least [`Header::FIXED_SIZE`] bytes long, and the
+    /// header in `data` must be followed by at least `trailing_len` bytes.
+    pub fn parse_extensions(data: &[u8], trailing_len: usize) -> Result<ExtensionArea> {
+        let start = Self::FIXED_SIZE + Self::EXTENSIONS_LEN_SIZE;
+        let extensions_len = data[Self::FIXED_SIZE..]
+            .first_chunk::<{ Self::EXTENSIONS_LEN_SIZE }>()
+            .map(|len| u32::from_le_bytes(*len))
+            .and_then(|len| usize::try_from(len).ok())
+            .ok_or(Error::InvalidLength {
+                len: data.len(),
+                min: start + Self::MAC_SIZE + trailing_len,
+            })?;
+        if extensions_len > ExtensionArea::MAX_SIZE {
+            return Err(Error::InvalidExtension);
+        }
+        let min = start + extensions_len + Self::MAC_SIZE + trailing_len;
+        if data.len() < min {
+            return Err(Error::InvalidLength {
+                len: data.len(),
+                min,
+            });
+        }
+        ExtensionArea::parse(&data[start..(start + extensions_len)])
+    }
+
+    /// Parses the AEAD stored in `extensions`.
+    pub fn parse_aead(extensions: &ExtensionArea) -> Result<Aead> {
+        match extensions.get(extension::AEAD) {
+            Some(aead) if aead.is_critical() => match aead.value() {
+                &[id] => Aead::from_id(id),
+                _ => Err(Error::InvalidExtension),
+            },
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(Aead::default()),
+        }
+    }
+
+    /// Parses the number of bytes of each segment stored in `extensions`.
+    pub fn parse_segment_size(extensions: &ExtensionArea) -> Result<Option<NonZeroU32>> {
+        match extensions.get(extension::SEGMENT_SIZE) {
+            Some(segment_size) if segment_size.is_critical() => segment_size
+                .value()
+                .try_into()
+                .map(u32::from_le_bytes)
+                .ok()
+                .and_then(NonZeroU32::new)
+                .map(Some)
+                .ok_or(Error::InvalidExtension),
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the key commitment stored in `extensions`.
+    pub fn parse_key_commitment(extensions: &ExtensionArea) -> Result<Option<KeyCommitment>> {
+        match extensions.get(extension::KEY_COMMITMENT) {
+            Some(commitment) if commitment.is_critical() => {
+                <[u8; 32]>::try_from(commitment.value())
+                    .map(|c| Some(c.into()))
+                    .map_err(|_| Error::InvalidExtension)
+            }
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the public key which verifies the signature stored in
+    /// `extensions`.
+    pub fn parse_verifying_key(extensions: &ExtensionArea) -> Result<Option<VerifyingKey>> {
+        match extensions.get(extension::SIGNATURE) {
+            Some(key) if key.is_critical() => VerifyingKey::try_from(key.value())
+                .map(Some)
+                .map_err(|_| Error::InvalidExtension),
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses whether `extensions` marks the ciphertext as a record log.
+    pub fn parse_record_log(extensions: &ExtensionArea) -> Result<bool> {
+        match extensions.get(extension::RECORD_LOG) {
+            Some(log) if log.is_critical() && log.value().is_empty() => Ok(true),
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(false),
+        }
+    }
+
+    /// Parses the Unicode normalization form of the passphrase stored in
+    /// `extensions`.
+    pub fn parse_normalization(extensions: &ExtensionArea) -> Result<Option<Normalization>> {
+        match extensions.get(extension::NORMALIZATION) {
+            Some(normalization) if normalization.is_critical() => match normalization.value() {
+                &[id] => Normalization::try_from(id).map(Some),
+                _ => Err(Error::InvalidExtension),
+            },
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the key identifier stored in `extensions`.
+    pub fn parse_key_id(extensions: &ExtensionArea) -> Result<Option<KeyId>> {
+        match extensions.get(extension::KEY_ID) {
+            Some(key_id) if !key_id.is_critical() => <[u8; KeyId::SIZE]>::try_from(key_id.value())
+                .map(|id| Some(id.into()))
+                .map_err(|_| Error::InvalidExtension),
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(None),
+        }
+    }
+
+    /// Parses the validity period stored in `extensions`.
+    pub fn parse_validity(extensions: &ExtensionArea) -> Result<Validity> {
+        match extensions.get(extension::VALIDITY) {
+            Some(validity) if validity.is_critical() => validity
+                .value()
+                .try_into()
+                .map(Validity::from_bytes)
+                .map_err(|_| Error::InvalidExtension),
+            Some(_) => Err(Error::InvalidExtension),
+            None => Ok(Validity::default()),
+        }
     }
 
     /// Gets a BLAKE2b-512-MAC of this header.
     #[inline]
     pub fn compute_mac(&mut self, key: &Blake2bMac512Key) {
         let mut mac = Blake2bMac512::new(key);
-        mac.update(&self.as_bytes()[..84]);
+        self.update_mac(&mut mac);
         self.mac.copy_from_slice(&mac.finalize().into_bytes());
     }
 
     /// Verifies a BLAKE2b-512-MAC stored in this header.
     pub fn verify_mac(&mut self, key: &Blake2bMac512Key, tag: &Blake2bMac512Output) -> Result<()> {
         let mut mac = Blake2bMac512::new(key);
-        mac.update(&self.as_bytes()[..84]);
+        self.update_mac(&mut mac);
         mac.verify(tag)?;
         self.mac.copy_from_slice(tag);
         Ok(())
     }
 
-    /// Converts this header to a byte array.
-    pub fn as_bytes(&self) -> [u8; Self::SIZE] {
-        let mut header = [u8::default(); Self::SIZE];
+    /// Feeds the bytes of this header covered by the MAC into `mac`.
+    fn update_mac(&self, mac: &mut Blake2bMac512) {
+        let mut header = [u8::default(); Self::MAX_SIZE];
+        self.write(&mut header);
+        mac.update(&header[..self.mac_offset()]);
+    }
+
+    /// Returns the number of bytes of this header.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.mac_offset() + Self::MAC_SIZE
+    }
+
+    /// Returns the offset of the MAC in this header.
+    #[inline]
+    pub const fn mac_offset(&self) -> usize {
+        if self.has_extended_payload() {
+            Self::FIXED_SIZE + Self::EXTENSIONS_LEN_SIZE + self.extensions.len()
+        } else {
+            Self::FIXED_SIZE
+        }
+    }
+
+    /// Writes this header into the first [`Header::size`] bytes of `buf`.
+    pub fn write(&self, buf: &mut [u8]) {
+        let header = &mut buf[..self.size()];
         header[..7].copy_from_slice(&self.magic_number);
         header[7] = self.version.into();
-        header[8..12].copy_from_slice(&u32::from(self.argon2_type).to_le_bytes());
-        header[12..16].copy_from_slice(&u32::from(self.argon2_version).to_le_bytes());
-        header[16..20].copy_from_slice(&self.params.memory_cost().to_le_bytes());
-        header[20..24].copy_from_slice(&self.params.time_cost().to_le_bytes());
-        header[24..28].copy_from_slice(&self.params.parallelism().to_le_bytes());
+        header[8..12].copy_from_slice(&self.kdf.id().to_le_bytes());
+        match self.kdf {
+            Kdf::Argon2(argon2) => {
+                let argon2_version = argon2_context::Version::from(argon2.version());
+                let params = argon2.params();
+                header[12..16].copy_from_slice(&u32::from(argon2_version).to_le_bytes());
+                header[16..20].copy_from_slice(&params.memory_cost().to_le_bytes());
+                header[20..24].copy_from_slice(&params.time_cost().to_le_bytes());
+                header[24..28].copy_from_slice(&params.parallelism().to_le_bytes());
+            }
+            #[cfg(feature = "scrypt")]
+            Kdf::Scrypt(params) => {
+                header[12..16].copy_from_slice(&u32::from(params.log_n()).to_le_bytes());
+                header[16..20].copy_from_slice(&params.r().to_le_bytes());
+                header[20..24].copy_from_slice(&params.p().to_le_bytes());
+                header[24..28].fill(u8::default());
+            }
+            #[cfg(feature = "sharing")]
+            Kdf::FileKey => header[12..28].fill(u8::default()),
+        }
         header[28..60].copy_from_slice(&self.salt);
         header[60..84].copy_from_slice(&self.nonce);
-        header[84..].copy_from_slice(&self.mac);
-        header
+        if self.has_extended_payload() {
+            let extensions = self.extensions.as_bytes();
+            let extensions_len =
+                u32::try_from(extensions.len()).expect("extensions should be at most 1 KiB");
+            let start = Self::FIXED_SIZE + Self::EXTENSIONS_LEN_SIZE;
+            header[Self::FIXED_SIZE..start].copy_from_slice(&extensions_len.to_le_bytes());
+            header[start..self.mac_offset()].copy_from_slice(extensions);
+        }
+        header[self.mac_offset()..].copy_from_slice(&self.mac);
+    }
+
+    /// Returns `true` if the payload of this header contains the metadata and
+    /// the padding in addition to the plaintext.
+    ///
+    /// This is `true` if and only if this header is version 2 or later.
+    #[inline]
+    pub const fn has_extended_payload(&self) -> bool {
+        matches!(self.version, FormatVersion::V2 | FormatVersion::V3)
+    }
+
+    /// Marks the payload of this header as containing the metadata and the
+    /// padding in addition to the plaintext.
+    ///
+    /// The extended payload requires version 2 or later of the abcrypt
+    /// encrypted data format, so this also enables the extensions of this
+    /// header.
+    #[inline]
+    pub const fn set_extended_payload(&mut self) {
+        if matches!(self.version, FormatVersion::V1) {
+            self.version = FormatVersion::V2;
+        }
+    }
+
+    /// Returns the extensions stored in this header.
+    #[inline]
+    pub const fn extensions(&self) -> &ExtensionArea {
+        &self.extensions
+    }
+
+    /// Returns the mutable extensions stored in this header.
+    ///
+    /// The extensions are written only if this header is version 2 or later.
+    #[inline]
+    pub const fn extensions_mut(&mut self) -> &mut ExtensionArea {
+        &mut self.extensions
+    }
+
+    /// Returns the AEAD stored in this header.
+    #[inline]
+    pub const fn aead(&self) -> Aead {
+        self.aead
+    }
+
+    /// Sets the AEAD to `aead`.
+    ///
+    /// The AEAD other than the default is stored as the critical extension, so
+    /// this also sets this header to version 2.
+    pub fn set_aead(&mut self, aead: Aead) -> Result<()> {
+        self.extensions.remove(extension::AEAD);
+        if aead != Aead::default() {
+            self.extensions.push(Extension::new(
+                Extension::CRITICAL | extension::AEAD,
+                &[aead.id()],
+            ))?;
+            self.set_extended_payload();
+        }
+        self.aead = aead;
+        Ok(())
+    }
+
+    /// Returns the number of bytes of each segment of the payload if the
+    /// payload is segmented.
+    #[inline]
+    pub const fn segment_size(&self) -> Option<usize> {
+        match self.segment_size {
+            Some(segment_size) => Some(segment_size.get() as usize),
+            None => None,
+        }
+    }
+
+    /// Sets the number of bytes of each segment of the payload to
+    /// `segment_size`.
+    ///
+    /// The segment size is stored as the critical extension, so this also sets
+    /// this header to version 2.
+    pub fn set_segment_size(&mut self, segment_size: NonZeroU32) -> Result<()> {
+        self.extensions.remove(extension::SEGMENT_SIZE);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::SEGMENT_SIZE,
+            &segment_size.get().to_le_bytes(),
+        ))?;
+        self.set_extended_payload();
+        self.segment_size = Some(segment_size);
+        Ok(())
+    }
+
+    /// Returns `true` if this header contains the key commitment.
+    #[inline]
+    pub const fn has_key_commitment(&self) -> bool {
+        self.key_commitment.is_some()
+    }
+
+    /// Stores the key commitment of `key` in this header.
+    ///
+    /// The key commitment is stored as the critical extension, so this also
+    /// sets this header to version 2.
+    pub fn set_key_commitment(&mut self, key: &XChaCha20Poly1305Key) -> Result<()> {
+        let commitment = Self::key_commitment_mac(key).finalize().into_bytes();
+        self.extensions.remove(extension::KEY_COMMITMENT);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::KEY_COMMITMENT,
+            &commitment,
+        ))?;
+        self.set_extended_payload();
+        self.key_commitment = Some(commitment);
+        Ok(())
+    }
+
+    /// Verifies that the key commitment stored in this header commits to
+    /// `key`.
+    ///
+    /// This does nothing if this header does not contain the key commitment.
+    pub fn verify_key_commitment(&self, key: &XChaCha20Poly1305Key) -> Result<()> {
+        self.key_commitment.map_or(Ok(()), |commitment| {
+            Self::key_commitment_mac(key)
+                .verify(&commitment)
+                .map_err(|_| Error::InvalidKeyCommitment)
+        })
+    }
+
+    /// Returns BLAKE2b-256-MAC keyed with `key` which is fed with the data of
+    /// the key commitment.
+    fn key_commitment_mac(key: &XChaCha20Poly1305Key) -> Blake2bMac256 {
+        let mut mac = Blake2bMac256::new_with_salt_and_personal(key, &[], &[])
+            .expect("key size should be valid");
+        mac.update(Self::KEY_COMMITMENT_DATA);
+        mac
+    }
+
+    /// Returns the public key which verifies the signature if the ciphertext
+    /// is signed.
+    #[inline]
+    pub const fn verifying_key(&self) -> Option<&VerifyingKey> {
+        self.verifying_key.as_ref()
+    }
+
+    /// Stores `key` in this header as the public key which verifies the
+    /// signature.
+    ///
+    /// The public key is stored as the critical extension, so this also sets
+    /// this header to version 2.
+    #[cfg(feature = "ed25519")]
+    pub fn set_verifying_key(&mut self, key: VerifyingKey) -> Result<()> {
+        self.extensions.remove(extension::SIGNATURE);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::SIGNATURE,
+            &key,
+        ))?;
+        self.set_extended_payload();
+        self.verifying_key = Some(key);
+        Ok(())
+    }
+
+    /// Returns the number of bytes of the signature appended to the
+    /// ciphertext.
+    #[inline]
+    pub const fn signature_size(&self) -> usize {
+        if self.verifying_key.is_some() {
+            Self::SIGNATURE_SIZE
+        } else {
+            usize::MIN
+        }
+    }
+
+    /// Returns `true` if the ciphertext following this header is a record log.
+    #[inline]
+    pub const fn is_record_log(&self) -> bool {
+        self.record_log
+    }
+
+    /// Marks the ciphertext following this header as a record log.
+    ///
+    /// The mark is stored as the critical extension, so this also sets this
+    /// header to version 2.
+    #[cfg(feature = "log")]
+    pub fn set_record_log(&mut self) -> Result<()> {
+        self.extensions.remove(extension::RECORD_LOG);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::RECORD_LOG,
+            &[],
+        ))?;
+        self.set_extended_payload();
+        self.record_log = true;
+        Ok(())
+    }
+
+    /// Returns the Unicode normalization form applied to the passphrase if the
+    /// passphrase is normalized.
+    #[inline]
+    pub const fn normalization(&self) -> Option<Normalization> {
+        self.normalization
+    }
+
+    /// Sets the Unicode normalization form applied to the passphrase to
+    /// `normalization`.
+    ///
+    /// The normalization form is stored as the critical extension, so this
+    /// also sets this header to version 2.
+    #[cfg(feature = "normalization")]
+    pub fn set_normalization(&mut self, normalization: Normalization) -> Result<()> {
+        self.extensions.remove(extension::NORMALIZATION);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::NORMALIZATION,
+            &[normalization.id()],
+        ))?;
+        self.set_extended_payload();
+        self.normalization = Some(normalization);
+        Ok(())
     }
 
-    /// Returns the Argon2 type stored in this header.
+    /// Returns the key identifier stored in this header.
     #[inline]
-    pub const fn argon2_type(&self) -> argon2_context::Variant {
-        self.argon2_type
+    pub const fn key_id(&self) -> Option<KeyId> {
+        self.key_id
+    }
+
+    /// Stores `key_id` in this header as the key identifier.
+    ///
+    /// The key identifier is stored as the non-critical extension, but this
+    /// also sets this header to version 2.
+    pub fn set_key_id(&mut self, key_id: KeyId) -> Result<()> {
+        self.extensions.remove(extension::KEY_ID);
+        self.extensions
+            .push(Extension::new(extension::KEY_ID, key_id.as_bytes()))?;
+        self.set_extended_payload();
+        self.key_id = Some(key_id);
+        Ok(())
     }
 
-    /// Returns the Argon2 version stored in this header.
+    /// Returns the validity period stored in this header.
     #[inline]
-    pub const fn argon2_version(&self) -> argon2_context::Version {
-        self.argon2_version
+    pub const fn validity(&self) -> Validity {
+        self.validity
+    }
+
+    /// Stores `validity` in this header as the validity period.
+    ///
+    /// The validity period is stored as the critical extension, so this also
+    /// sets this header to version 2.
+    pub fn set_validity(&mut self, validity: Validity) -> Result<()> {
+        self.extensions.remove(extension::VALIDITY);
+        self.extensions.push(Extension::new(
+            Extension::CRITICAL | extension::VALIDITY,
+            &validity.to_bytes(),
+        ))?;
+        self.set_extended_payload();
+        self.validity = validity;
+        Ok(())
     }
 
-    /// Returns the Argon2 parameters stored in this header.
+    /// Returns the key derivation function stored in this header.
     #[inline]
-    pub const fn params(&self) -> Params {
-        self.params
+    pub const fn kdf(&self) -> Kdf {
+        self.kdf
     }
 
     /// Returns a salt stored in this header.
@@ -325,31 +944,47 @@ mod tests {
 
     #[test]
     fn version() {
-        assert_eq!(Version::V0 as u8, 0);
-        assert_eq!(Version::V1 as u8, 1);
+        assert_eq!(FormatVersion::V0 as u8, 0);
+        assert_eq!(FormatVersion::V1 as u8, 1);
+        assert_eq!(FormatVersion::V2 as u8, 2);
+        assert_eq!(FormatVersion::V3 as u8, 3);
     }
 
     #[test]
     fn size_of_version() {
-        assert_eq!(mem::size_of::<Version>(), mem::size_of::<u8>());
+        assert_eq!(mem::size_of::<FormatVersion>(), mem::size_of::<u8>());
     }
 
     #[test]
     fn clone_version() {
-        assert_eq!(Version::V0.clone(), Version::V0);
-        assert_eq!(Version::V1.clone(), Version::V1);
+        assert_eq!(FormatVersion::V0.clone(), FormatVersion::V0);
+        assert_eq!(FormatVersion::V1.clone(), FormatVersion::V1);
+        assert_eq!(FormatVersion::V2.clone(), FormatVersion::V2);
+        assert_eq!(FormatVersion::V3.clone(), FormatVersion::V3);
     }
 
     #[test]
     fn copy_version() {
         {
-            let a = Version::V0;
+            let a = FormatVersion::V0;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = FormatVersion::V1;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = FormatVersion::V2;
             let b = a;
             assert_eq!(a, b);
         }
 
         {
-            let a = Version::V1;
+            let a = FormatVersion::V3;
             let b = a;
             assert_eq!(a, b);
         }
@@ -358,40 +993,61 @@ mod tests {
     #[cfg(feature = "alloc")]
     #[test]
     fn debug_version() {
-        assert_eq!(format!("{:?}", Version::V0), "V0");
-        assert_eq!(format!("{:?}", Version::V1), "V1");
+        assert_eq!(format!("{:?}", FormatVersion::V0), "V0");
+        assert_eq!(format!("{:?}", FormatVersion::V1), "V1");
+        assert_eq!(format!("{:?}", FormatVersion::V2), "V2");
+        assert_eq!(format!("{:?}", FormatVersion::V3), "V3");
     }
 
     #[test]
     fn default_version() {
-        assert_eq!(Version::default(), Version::V1);
+        assert_eq!(FormatVersion::default(), FormatVersion::V1);
     }
 
     #[test]
     fn version_equality() {
-        assert_eq!(Version::V0, Version::V0);
-        assert_ne!(Version::V0, Version::V1);
-        assert_ne!(Version::V1, Version::V0);
-        assert_eq!(Version::V1, Version::V1);
+        assert_eq!(FormatVersion::V0, FormatVersion::V0);
+        assert_ne!(FormatVersion::V0, FormatVersion::V1);
+        assert_ne!(FormatVersion::V0, FormatVersion::V2);
+        assert_ne!(FormatVersion::V0, FormatVersion::V3);
+        assert_ne!(FormatVersion::V1, FormatVersion::V0);
+        assert_eq!(FormatVersion::V1, FormatVersion::V1);
+        assert_ne!(FormatVersion::V1, FormatVersion::V2);
+        assert_ne!(FormatVersion::V1, FormatVersion::V3);
+        assert_ne!(FormatVersion::V2, FormatVersion::V0);
+        assert_ne!(FormatVersion::V2, FormatVersion::V1);
+        assert_eq!(FormatVersion::V2, FormatVersion::V2);
+        assert_ne!(FormatVersion::V2, FormatVersion::V3);
+        assert_ne!(FormatVersion::V3, FormatVersion::V0);
+        assert_ne!(FormatVersion::V3, FormatVersion::V1);
+        assert_ne!(FormatVersion::V3, FormatVersion::V2);
+        assert_eq!(FormatVersion::V3, FormatVersion::V3);
     }
 
     #[test]
     fn from_version_to_u8() {
-        assert_eq!(u8::from(Version::V0), 0);
-        assert_eq!(u8::from(Version::V1), 1);
+        assert_eq!(u8::from(FormatVersion::V0), 0);
+        assert_eq!(u8::from(FormatVersion::V1), 1);
+        assert_eq!(u8::from(FormatVersion::V2), 2);
+        assert_eq!(u8::from(FormatVersion::V3), 3);
     }
 
     #[test]
     fn try_from_u8_to_version() {
-        assert_eq!(Version::try_from(0).unwrap(), Version::V0);
-        assert_eq!(Version::try_from(1).unwrap(), Version::V1);
+        assert_eq!(FormatVersion::try_from(0).unwrap(), FormatVersion::V0);
+        assert_eq!(FormatVersion::try_from(1).unwrap(), FormatVersion::V1);
+        assert_eq!(FormatVersion::try_from(2).unwrap(), FormatVersion::V2);
+        assert_eq!(FormatVersion::try_from(3).unwrap(), FormatVersion::V3);
     }
 
     #[test]
     fn try_from_u8_to_version_with_invalid_version() {
-        assert_eq!(Version::try_from(2).unwrap_err(), Error::UnknownVersion(2));
         assert_eq!(
-            Version::try_from(u8::MAX).unwrap_err(),
+            FormatVersion::try_from(4).unwrap_err(),
+            Error::UnknownVersion(4)
+        );
+        assert_eq!(
+            FormatVersion::try_from(u8::MAX).unwrap_err(),
             Error::UnknownVersion(u8::MAX)
         );
     }
@@ -405,4 +1061,186 @@ mod tests {
     fn derived_key_size() {
         assert_eq!(DerivedKey::SIZE, 96);
     }
+
+    /// An element of the field of Poly1305 (the integers modulo 2^130 - 5)
+    /// represented as 26-bit limbs.
+    #[derive(Clone, Copy)]
+    struct FieldElement([u64; 5]);
+
+    impl FieldElement {
+        const MASK: u64 = (1 << 26) - 1;
+
+        /// Creates a new element from `lo + hi * 2^128`.
+        fn new(lo: u128, hi: u64) -> Self {
+            let limb = |i: u32| u64::try_from((lo >> (26 * i)) & u128::from(Self::MASK)).unwrap();
+            let last = u64::try_from(lo >> 104).unwrap() | (hi << 24);
+            Self([limb(0), limb(1), limb(2), limb(3), last])
+        }
+
+        fn carry(self) -> Self {
+            let mut l = self.0;
+            for _ in 0..2 {
+                for i in 0..4 {
+                    l[i + 1] += l[i] >> 26;
+                    l[i] &= Self::MASK;
+                }
+                l[0] += (l[4] >> 26) * 5;
+                l[4] &= Self::MASK;
+            }
+            Self(l)
+        }
+
+        fn add(self, rhs: Self) -> Self {
+            Self(core::array::from_fn(|i| self.0[i] + rhs.0[i])).carry()
+        }
+
+        fn sub(self, rhs: Self) -> Self {
+            // Adds 4 * (2^130 - 5) so that no limb underflows.
+            let p4 = [
+                (Self::MASK - 4) << 2,
+                Self::MASK << 2,
+                Self::MASK << 2,
+                Self::MASK << 2,
+                Self::MASK << 2,
+            ];
+            Self(core::array::from_fn(|i| self.0[i] + p4[i] - rhs.0[i])).carry()
+        }
+
+        fn mul(self, rhs: Self) -> Self {
+            let mut d = [u64::default(); 5];
+            for i in 0..5 {
+                for j in 0..5 {
+                    let product = self.0[i] * rhs.0[j];
+                    if i + j < 5 {
+                        d[i + j] += product;
+                    } else {
+                        d[i + j - 5] += product * 5;
+                    }
+                }
+            }
+            Self(d).carry()
+        }
+
+        fn invert(self) -> Self {
+            // Computes `self^(p - 2)`, where `p - 2 = 2^130 - 7`.
+            let mut result = Self::new(1, 0);
+            for bit in (0..130).rev() {
+                result = result.mul(result);
+                if bit != 1 && bit != 2 {
+                    result = result.mul(self);
+                }
+            }
+            result
+        }
+
+        /// Returns the canonical value as `(lo, hi)`, where the value is
+        /// `lo + hi * 2^128`.
+        fn value(self) -> (u128, u64) {
+            let mut l = self.carry().0;
+            for i in 0..4 {
+                l[i + 1] += l[i] >> 26;
+                l[i] &= Self::MASK;
+            }
+            let lo = (0..5).fold(u128::MIN, |lo, i| lo | (u128::from(l[i]) << (26 * i)));
+            let hi = l[4] >> 24;
+            // Subtracts `p` if the value is at least `p`.
+            if hi == 3 && lo >= u128::MAX - 4 {
+                (lo.wrapping_add(5), u64::MIN)
+            } else {
+                (lo, hi)
+            }
+        }
+    }
+
+    /// Returns the Poly1305 key `(r, s)` used by XChaCha20-Poly1305.
+    fn poly1305_key(key: &XChaCha20Poly1305Key, nonce: &XNonce) -> (FieldElement, u128) {
+        use chacha20::{
+            XChaCha20,
+            cipher::{KeyIvInit, StreamCipher},
+        };
+
+        let mut block = [u8::default(); 32];
+        XChaCha20::new(key, nonce).apply_keystream(&mut block);
+        let (r, s) = block.split_at(16);
+        let r =
+            u128::from_le_bytes(r.try_into().unwrap()) & 0x0fff_fffc_0fff_fffc_0fff_fffc_0fff_ffff;
+        let s = u128::from_le_bytes(s.try_into().unwrap());
+        (FieldElement::new(r, 0), s)
+    }
+
+    /// Forges a 16-byte ciphertext and its tag which may be valid under both
+    /// `k1` and `k2`.
+    fn forge(
+        k1: &XChaCha20Poly1305Key,
+        k2: &XChaCha20Poly1305Key,
+        nonce: &XNonce,
+    ) -> Option<([u8; 16], chacha20poly1305::Tag)> {
+        let ((r1, s1), (r2, s2)) = (poly1305_key(k1, nonce), poly1305_key(k2, nonce));
+        // The last block is the lengths of the associated data and the ciphertext.
+        let len_block = FieldElement::new(16 << 64, 1);
+        // Solves `c * (r1^2 - r2^2) + len_block * (r1 - r2) = s2 - s1` for `c`.
+        let diff = FieldElement::new(s2.wrapping_sub(s1), 0);
+        let c = diff
+            .sub(len_block.mul(r1.sub(r2)))
+            .mul(r1.mul(r1).sub(r2.mul(r2)).invert());
+        let (c, hi) = c.value();
+        // `c` must be a 16-byte block with the high bit appended.
+        if hi != 1 {
+            return None;
+        }
+        let (h1, _) = FieldElement::new(c, 1)
+            .mul(r1)
+            .add(len_block)
+            .mul(r1)
+            .value();
+        let tag = h1.wrapping_add(s1).to_le_bytes();
+        Some((c.to_le_bytes(), tag.into()))
+    }
+
+    #[test]
+    fn key_commitment_with_forged_multi_key_ciphertext() {
+        let nonce = XNonce::default();
+        let k1 = XChaCha20Poly1305Key::from([1; 32]);
+        let (k2, ciphertext, tag) = (2..=u8::MAX)
+            .map(|i| XChaCha20Poly1305Key::from([i; 32]))
+            .find_map(|k2| {
+                let (ciphertext, tag) = forge(&k1, &k2, &nonce)?;
+                let mut buf = ciphertext;
+                Aead::XChaCha20Poly1305
+                    .decrypt_in_place_detached(&k2, &nonce, &mut buf, &tag, 0)
+                    .ok()?;
+                Some((k2, ciphertext, tag))
+            })
+            .unwrap();
+
+        // The payload alone is decrypted with both keys.
+        for key in [k1, k2] {
+            let mut buf = ciphertext;
+            assert!(
+                Aead::XChaCha20Poly1305
+                    .decrypt_in_place_detached(&key, &nonce, &mut buf, &tag, 0)
+                    .is_ok()
+            );
+        }
+
+        let argon2 = Argon2::from_parts(
+            argon2::Algorithm::default().into(),
+            argon2::Version::default().into(),
+            argon2::Params::default().into(),
+        );
+        let mut header = Header::new(argon2.into());
+        assert!(!header.has_key_commitment());
+        assert!(header.verify_key_commitment(&k2).is_ok());
+
+        header.set_key_commitment(&k1).unwrap();
+        let mut data = [u8::default(); Header::MAX_SIZE + TAG_SIZE];
+        header.write(&mut data);
+        let header = Header::parse(&data[..(header.size() + TAG_SIZE)]).unwrap();
+        assert!(header.has_key_commitment());
+        assert!(header.verify_key_commitment(&k1).is_ok());
+        assert_eq!(
+            header.verify_key_commitment(&k2).unwrap_err(),
+            Error::InvalidKeyCommitment
+        );
+    }
 }
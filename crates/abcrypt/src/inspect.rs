@@ -0,0 +1,287 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Format sniffing and structural validation of the encrypted data.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::{Error, TAG_SIZE, segment};
+use crate::{
+    armor,
+    format::{FormatVersion, Header},
+};
+
+/// Detects the version of the abcrypt encrypted data format of `data`.
+///
+/// `data` can be the [armored](crate::armor) data.
+///
+/// Returns [`None`] if `data` does not start with the magic number, or the
+/// version number is unrecognized. This only looks at the first 8 bytes of
+/// `data`, so the rest of `data` may still be invalid. Use [`inspect`] to
+/// validate the structure of `data`.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::FormatVersion;
+/// #
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+/// assert_eq!(abcrypt::detect(ciphertext), Some(FormatVersion::V1));
+///
+/// let plaintext = include_bytes!("../tests/data/data.txt");
+/// assert_eq!(abcrypt::detect(plaintext), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn detect(data: impl AsRef<[u8]>) -> Option<FormatVersion> {
+    let inner = |data: &[u8]| -> Option<FormatVersion> {
+        let mut buf = [u8::default(); 8];
+        let data = armor::dearmor_header(data, &mut buf).ok()?;
+        if !Header::has_magic_number(data) {
+            return None;
+        }
+        data.get(7)
+            .and_then(|&version| FormatVersion::try_from(version).ok())
+    };
+    inner(data.as_ref())
+}
+
+/// Inspects the structure of `data` as the abcrypt encrypted data format.
+///
+/// `data` can be the [armored](crate::armor) data.
+///
+/// Unlike [`Decryptor::new`](crate::Decryptor::new), this does not stop at the
+/// first problem, and reports every structural problem it finds in the
+/// returned [`Report`]. This does not require the passphrase, so the MACs
+/// (authentication tags) are not verified. If `data` does not start with the
+/// magic number or is version 0, the rest of `data` is not inspected. If the
+/// version number is unrecognized, the rest of `data` is inspected as version
+/// 1.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::{Error, FormatVersion};
+/// #
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+/// let report = abcrypt::inspect(ciphertext);
+/// assert_eq!(report.version(), Some(FormatVersion::V1));
+/// assert!(report.is_valid());
+///
+/// let mut ciphertext = *ciphertext;
+/// ciphertext[8] = 3;
+/// ciphertext[16..20].copy_from_slice(&u32::MIN.to_le_bytes());
+/// let report = abcrypt::inspect(ciphertext);
+/// assert_eq!(
+///     report.problems(),
+///     [
+///         Error::InvalidArgon2Type(3),
+///         Error::InvalidArgon2Params(abcrypt::argon2::Error::MemoryTooLittle)
+///     ]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[allow(clippy::too_many_lines)]
+#[must_use]
+pub fn inspect(data: impl AsRef<[u8]>) -> Report {
+    let inner = |data: &[u8]| -> Report {
+        let mut report = Report::default();
+        let decoded;
+        let data = if armor::is_armored(data) {
+            match armor::decode(data) {
+                Ok(data) => {
+                    decoded = data;
+                    &decoded
+                }
+                Err(err) => {
+                    report.push(err);
+                    return report;
+                }
+            }
+        } else {
+            data
+        };
+
+        if data.len() < Header::SIZE + TAG_SIZE {
+            report.push(Error::InvalidLength {
+                len: data.len(),
+                min: Header::SIZE + TAG_SIZE,
+            });
+        }
+        if !Header::has_magic_number(data) {
+            report.push(Error::InvalidMagicNumber);
+            return report;
+        }
+        let Some(&version) = data.get(7) else {
+            return report;
+        };
+        report.version = FormatVersion::try_from(version).ok();
+        if let Err(err) = Header::parse_version(data) {
+            report.push(err);
+        }
+        // The fields of version 0 are not compatible with the later versions.
+        if report.version == Some(FormatVersion::V0) || data.len() < Header::FIXED_SIZE {
+            return report;
+        }
+        let version = report.version.unwrap_or_default();
+        if Header::is_argon2(data, version) {
+            if let Err(err) = Header::parse_argon2_type(data) {
+                report.push(err);
+            }
+            if let Err(err) = Header::parse_argon2_version(data) {
+                report.push(err);
+            }
+            if let Err(err) = Header::parse_params(data) {
+                report.push(err);
+            }
+        } else if let Err(err) = Header::parse_kdf(data, version) {
+            report.push(err);
+        }
+        if !matches!(version, FormatVersion::V2 | FormatVersion::V3) {
+            return report;
+        }
+        let extensions = match Header::parse_extensions(data, TAG_SIZE) {
+            Ok(extensions) => extensions,
+            Err(err) => {
+                report.push(err);
+                return report;
+            }
+        };
+        if let Err(err) = Header::parse_aead(&extensions) {
+            report.push(err);
+        }
+        let signature_size = match Header::parse_verifying_key(&extensions) {
+            Ok(Some(_)) => Header::SIGNATURE_SIZE,
+            Ok(None) => usize::MIN,
+            Err(err) => {
+                report.push(err);
+                usize::MIN
+            }
+        };
+        // The records of a record log have their own lengths.
+        let record_log = Header::parse_record_log(&extensions).unwrap_or_else(|err| {
+            report.push(err);
+            false
+        });
+        let header_size =
+            Header::FIXED_SIZE + Header::EXTENSIONS_LEN_SIZE + extensions.len() + Header::MAC_SIZE;
+        let ciphertext_len = (data.len() - header_size).saturating_sub(signature_size);
+        match Header::parse_segment_size(&extensions) {
+            Ok(_) if record_log => {}
+            Ok(Some(segment_size)) => {
+                let segment_size = segment_size.get() as usize;
+                if segment::payload_len(ciphertext_len, segment_size).is_none() {
+                    report.push(Error::InvalidLength {
+                        len: data.len(),
+                        min: header_size
+                            + segment::min_len(ciphertext_len, segment_size)
+                            + signature_size,
+                    });
+                }
+            }
+            Ok(None) if ciphertext_len < TAG_SIZE => report.push(Error::InvalidLength {
+                len: data.len(),
+                min: header_size + TAG_SIZE + signature_size,
+            }),
+            Ok(None) => {}
+            Err(err) => report.push(err),
+        }
+        if let Err(err) = Header::parse_key_commitment(&extensions) {
+            report.push(err);
+        }
+        if let Err(err) = Header::parse_normalization(&extensions) {
+            report.push(err);
+        }
+        report
+    };
+    inner(data.as_ref())
+}
+
+/// The result of inspecting the structure of the encrypted data.
+///
+/// This is returned by [`inspect`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    version: Option<FormatVersion>,
+    problems: Vec<Error>,
+}
+
+#[cfg(feature = "alloc")]
+impl Report {
+    /// Returns the version of the abcrypt encrypted data format.
+    ///
+    /// Returns [`None`] if the data does not start with the magic number, or
+    /// the version number is unrecognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::FormatVersion;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    ///
+    /// let report = abcrypt::inspect(ciphertext);
+    /// assert_eq!(report.version(), Some(FormatVersion::V1));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn version(&self) -> Option<FormatVersion> {
+        self.version
+    }
+
+    /// Returns the structural problems found in the data, in the order they
+    /// were found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Error;
+    /// #
+    /// let plaintext = include_bytes!("../tests/data/data.txt");
+    ///
+    /// let report = abcrypt::inspect(plaintext);
+    /// assert_eq!(
+    ///     report.problems(),
+    ///     [
+    ///         Error::InvalidLength { len: 14, min: 164 },
+    ///         Error::InvalidMagicNumber
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn problems(&self) -> &[Error] {
+        &self.problems
+    }
+
+    /// Returns `true` if no structural problems were found in the data.
+    ///
+    /// Note that this does not mean the data can be decrypted, because the
+    /// MACs (authentication tags) are not verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    /// assert!(abcrypt::inspect(ciphertext).is_valid());
+    ///
+    /// let plaintext = include_bytes!("../tests/data/data.txt");
+    /// assert!(!abcrypt::inspect(plaintext).is_valid());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Adds `problem` to this report unless it has already been added.
+    fn push(&mut self, problem: Error) {
+        if !self.problems.contains(&problem) {
+            self.problems.push(problem);
+        }
+    }
+}
@@ -0,0 +1,355 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Key derivation functions for deriving the key from the passphrase.
+
+#[cfg(feature = "scrypt")]
+use core::fmt;
+
+use crate::{
+    Argon2, Error, Result, TAG_SIZE, armor,
+    format::{DerivedKey, Header},
+};
+
+/// The key derivation function used for the encrypted data.
+///
+/// The key derivation function other than Argon2 is stored in the header as
+/// version 3 of the abcrypt encrypted data format.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum Kdf {
+    /// Argon2.
+    Argon2(Argon2),
+
+    /// scrypt.
+    #[cfg(feature = "scrypt")]
+    Scrypt(ScryptParams),
+
+    /// The [`FileKey`](crate::sharing::FileKey), which is used directly instead
+    /// of deriving the key from the passphrase.
+    #[cfg(feature = "sharing")]
+    FileKey,
+}
+
+impl Kdf {
+    /// The identifier of scrypt stored in the header.
+    ///
+    /// The identifiers from 0 to 2 are the Argon2 types.
+    #[cfg(feature = "scrypt")]
+    pub(crate) const SCRYPT_ID: u32 = 3;
+
+    /// The identifier of the file key stored in the header.
+    #[cfg(feature = "sharing")]
+    pub(crate) const FILE_KEY_ID: u32 = 4;
+
+    /// Creates a new instance of the key derivation function from
+    /// `ciphertext`.
+    ///
+    /// `ciphertext` can be the [armored](crate::armor) data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `ciphertext` is armored and the ASCII armor is invalid.
+    /// - `ciphertext` is shorter than 164 bytes.
+    /// - The magic number is invalid.
+    /// - The version number is the unsupported abcrypt version number.
+    /// - The version number is the unrecognized abcrypt version number.
+    /// - The key derivation function is unrecognized or not enabled.
+    /// - The parameters of the key derivation function are invalid.
+    /// - The extensions of the header are invalid.
+    /// - The header contains the unrecognized critical extension.
+    /// - The AEAD is unrecognized or not enabled.
+    /// - The passphrase normalization form is unrecognized or not enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Kdf, argon2::Algorithm};
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    ///
+    /// let kdf = Kdf::new(ciphertext).unwrap();
+    /// assert!(matches!(kdf, Kdf::Argon2(argon2) if argon2.variant() == Algorithm::Argon2id));
+    /// ```
+    #[inline]
+    pub fn new(ciphertext: impl AsRef<[u8]>) -> Result<Self> {
+        let inner = |ciphertext: &[u8]| -> Result<Self> {
+            let mut buf = [u8::default(); Header::MAX_SIZE + TAG_SIZE];
+            let ciphertext = armor::dearmor_header(ciphertext, &mut buf)?;
+            Header::parse(ciphertext).map(|h| h.kdf())
+        };
+        inner(ciphertext.as_ref())
+    }
+
+    /// Returns the identifier of this key derivation function stored in the
+    /// header.
+    pub(crate) fn id(self) -> u32 {
+        match self {
+            Self::Argon2(argon2) => crate::argon2_context::Variant::from(argon2.variant()).into(),
+            #[cfg(feature = "scrypt")]
+            Self::Scrypt(_) => Self::SCRYPT_ID,
+            #[cfg(feature = "sharing")]
+            Self::FileKey => Self::FILE_KEY_ID,
+        }
+    }
+
+    /// Derives the key from `passphrase` and `salt`.
+    ///
+    /// The file key is not derived from the passphrase, so this returns
+    /// [`Error::UnsupportedKdf`] for the file key.
+    pub(crate) fn derive_key(self, passphrase: &[u8], salt: &[u8]) -> Result<DerivedKey> {
+        // The derived key size is 96 bytes. The first 256 bits are for
+        // XChaCha20-Poly1305 key, and the last 512 bits are for BLAKE2b-512-MAC key.
+        let mut dk = [u8::default(); DerivedKey::SIZE];
+        match self {
+            Self::Argon2(argon2) => {
+                let argon2 =
+                    argon2::Argon2::new(argon2.variant(), argon2.version(), argon2.params().into());
+                #[cfg(feature = "alloc")]
+                argon2
+                    .hash_password_into(passphrase, salt, &mut dk)
+                    .map_err(Error::InvalidArgon2Context)?;
+                #[cfg(not(feature = "alloc"))]
+                {
+                    let mut memory_blocks = crate::MEMORY_BLOCKS;
+                    argon2
+                        .hash_password_into_with_memory(
+                            passphrase,
+                            salt,
+                            &mut dk,
+                            &mut memory_blocks,
+                        )
+                        .map_err(Error::InvalidArgon2Context)?;
+                }
+            }
+            #[cfg(feature = "scrypt")]
+            Self::Scrypt(params) => {
+                scrypt::scrypt(passphrase, salt, &params.into(), &mut dk)
+                    .expect("derived key size should be valid");
+            }
+            #[cfg(feature = "sharing")]
+            Self::FileKey => return Err(Error::UnsupportedKdf(Self::FILE_KEY_ID)),
+        }
+        Ok(DerivedKey::new(dk))
+    }
+}
+
+impl From<Argon2> for Kdf {
+    #[inline]
+    fn from(argon2: Argon2) -> Self {
+        Self::Argon2(argon2)
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl From<ScryptParams> for Kdf {
+    #[inline]
+    fn from(params: ScryptParams) -> Self {
+        Self::Scrypt(params)
+    }
+}
+
+/// The scrypt parameters used for the encrypted data.
+#[cfg(feature = "scrypt")]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", try_from = "RawScryptParams")
+)]
+pub struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+#[cfg(feature = "scrypt")]
+impl ScryptParams {
+    /// Creates a new instance of the scrypt parameters from `ciphertext`.
+    ///
+    /// `ciphertext` can be the [armored](crate::armor) data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by [`Kdf::new`] occurs,
+    /// or the key derivation function is not scrypt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, ScryptParams};
+    /// #
+    /// let data = b"Hello, world!\n";
+    /// let passphrase = "passphrase";
+    ///
+    /// let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    ///     .map(|c| c.encrypt_to_vec())
+    ///     .unwrap();
+    ///
+    /// assert!(ScryptParams::new(ciphertext).is_ok());
+    /// ```
+    #[inline]
+    pub fn new(ciphertext: impl AsRef<[u8]>) -> Result<Self> {
+        match Kdf::new(ciphertext)? {
+            Kdf::Scrypt(params) => Ok(params),
+            kdf => Err(Error::UnsupportedKdf(kdf.id())),
+        }
+    }
+
+    /// Creates a new instance of the scrypt parameters after validating them.
+    pub(crate) fn try_new(log_n: u8, r: u32, p: u32) -> Result<Self> {
+        scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+            .map(Self::from)
+            .map_err(|_| Error::InvalidScryptParams { log_n, r, p })
+    }
+
+    /// Gets log2 of the scrypt parameter `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, ScryptParams};
+    /// #
+    /// # let data = b"Hello, world!\n";
+    /// # let passphrase = "passphrase";
+    /// #
+    /// # let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// # let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    /// #     .map(|c| c.encrypt_to_vec())
+    /// #     .unwrap();
+    /// #
+    /// let params = ScryptParams::new(ciphertext).unwrap();
+    /// assert_eq!(params.log_n(), 10);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn log_n(&self) -> u8 {
+        self.log_n
+    }
+
+    /// Gets the scrypt parameter `r`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, ScryptParams};
+    /// #
+    /// # let data = b"Hello, world!\n";
+    /// # let passphrase = "passphrase";
+    /// #
+    /// # let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// # let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    /// #     .map(|c| c.encrypt_to_vec())
+    /// #     .unwrap();
+    /// #
+    /// let params = ScryptParams::new(ciphertext).unwrap();
+    /// assert_eq!(params.r(), 8);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn r(&self) -> u32 {
+        self.r
+    }
+
+    /// Gets the scrypt parameter `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, ScryptParams};
+    /// #
+    /// # let data = b"Hello, world!\n";
+    /// # let passphrase = "passphrase";
+    /// #
+    /// # let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// # let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    /// #     .map(|c| c.encrypt_to_vec())
+    /// #     .unwrap();
+    /// #
+    /// let params = ScryptParams::new(ciphertext).unwrap();
+    /// assert_eq!(params.p(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn p(&self) -> u32 {
+        self.p
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl fmt::Display for ScryptParams {
+    /// Formats the scrypt parameters as the parameters of the [PHC string
+    /// format].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{Encryptor, ScryptParams};
+    /// #
+    /// # let data = b"Hello, world!\n";
+    /// # let passphrase = "passphrase";
+    /// #
+    /// # let params = abcrypt::scrypt::Params::new(10, 8, 1, 32).unwrap();
+    /// # let ciphertext = Encryptor::with_scrypt(data, passphrase, params)
+    /// #     .map(|c| c.encrypt_to_vec())
+    /// #     .unwrap();
+    /// #
+    /// let params = ScryptParams::new(ciphertext).unwrap();
+    /// assert_eq!(format!("{params}"), "ln=10,r=8,p=1");
+    /// ```
+    ///
+    /// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ln={},r={},p={}", self.log_n(), self.r(), self.p())
+    }
+}
+
+/// The unvalidated scrypt parameters used for deserializing [`ScryptParams`].
+#[cfg(all(feature = "scrypt", feature = "serde"))]
+#[derive(serde::Deserialize)]
+#[serde(rename = "ScryptParams", rename_all = "camelCase")]
+struct RawScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+#[cfg(all(feature = "scrypt", feature = "serde"))]
+impl TryFrom<RawScryptParams> for ScryptParams {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(params: RawScryptParams) -> Result<Self> {
+        Self::try_new(params.log_n, params.r, params.p)
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl From<ScryptParams> for scrypt::Params {
+    #[inline]
+    fn from(params: ScryptParams) -> Self {
+        Self::new(
+            params.log_n(),
+            params.r(),
+            params.p(),
+            Self::RECOMMENDED_LEN,
+        )
+        .expect("`ScryptParams` should be valid as `scrypt::Params`")
+    }
+}
+
+#[cfg(feature = "scrypt")]
+impl From<scrypt::Params> for ScryptParams {
+    #[inline]
+    fn from(params: scrypt::Params) -> Self {
+        let (log_n, r, p) = (params.log_n(), params.r(), params.p());
+        Self { log_n, r, p }
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Key identifiers to select the passphrase without deriving the key.
+
+use blake2::{Blake2b, Digest, digest::consts::U8};
+
+/// The data hashed before the key ID secret.
+const KEY_ID_DATA: &[u8] = b"abcrypt key identifier";
+
+/// A short identifier of the passphrase stored in the header.
+///
+/// The key identifier is the BLAKE2b-64 hash of a key ID secret, which is
+/// an arbitrary label chosen independently of the passphrase (e.g., the name
+/// of the passphrase in a rotating set), so it reveals nothing about the
+/// passphrase. It allows [`decrypt_with_any`](crate::decrypt_with_any) to skip
+/// the candidate passphrases which cannot match before deriving the key.
+///
+/// Note that the key identifier is not authenticated until the MAC of the
+/// header is verified, so it is only a hint to select the passphrase.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeyId([u8; Self::SIZE]);
+
+impl KeyId {
+    /// The number of bytes of the key identifier.
+    pub const SIZE: usize = 8;
+
+    /// Creates a new `KeyId` from the key ID secret `secret`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::KeyId;
+    /// #
+    /// let key_id = KeyId::new("2026-q1");
+    /// assert_eq!(key_id, KeyId::new(b"2026-q1"));
+    /// assert_ne!(key_id, KeyId::new("2026-q2"));
+    /// ```
+    #[must_use]
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        let hash = Blake2b::<U8>::new()
+            .chain_update(KEY_ID_DATA)
+            .chain_update(secret)
+            .finalize();
+        Self(hash.into())
+    }
+
+    /// Returns the bytes of this key identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::KeyId;
+    /// #
+    /// let key_id = KeyId::from([0x01; 8]);
+    /// assert_eq!(key_id.as_bytes(), &[0x01; 8]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; Self::SIZE] {
+        &self.0
+    }
+}
+
+impl From<[u8; Self::SIZE]> for KeyId {
+    #[inline]
+    fn from(bytes: [u8; Self::SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<KeyId> for [u8; KeyId::SIZE] {
+    #[inline]
+    fn from(key_id: KeyId) -> Self {
+        key_id.0
+    }
+}
+
+/// A candidate passphrase for [`decrypt_with_any`](crate::decrypt_with_any).
+#[derive(Clone, Copy, Debug)]
+pub struct Candidate<'p> {
+    passphrase: &'p [u8],
+    key_id: Option<KeyId>,
+}
+
+impl<'p> Candidate<'p> {
+    /// Creates a new `Candidate` without the key identifier.
+    ///
+    /// The candidate without the key identifier is tried regardless of the key
+    /// identifier stored in the header.
+    #[must_use]
+    #[inline]
+    pub fn new(passphrase: &'p (impl AsRef<[u8]> + ?Sized)) -> Self {
+        let passphrase = passphrase.as_ref();
+        let key_id = None;
+        Self { passphrase, key_id }
+    }
+
+    /// Sets the key identifier of the passphrase.
+    ///
+    /// If the header stores a key identifier, this candidate is skipped unless
+    /// `key_id` matches it.
+    #[must_use]
+    #[inline]
+    pub const fn with_key_id(mut self, key_id: KeyId) -> Self {
+        self.key_id = Some(key_id);
+        self
+    }
+
+    /// Returns the passphrase of this candidate.
+    #[must_use]
+    #[inline]
+    pub const fn passphrase(&self) -> &'p [u8] {
+        self.passphrase
+    }
+
+    /// Returns the key identifier of this candidate.
+    #[must_use]
+    #[inline]
+    pub const fn key_id(&self) -> Option<KeyId> {
+        self.key_id
+    }
+
+    /// Returns `true` if this candidate can match the key identifier `key_id`
+    /// stored in the header.
+    pub(crate) fn matches(&self, key_id: Option<KeyId>) -> bool {
+        match (self.key_id, key_id) {
+            (Some(expected), Some(actual)) => expected == actual,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(KeyId::SIZE, 8);
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(KeyId::new("secret"), KeyId::new("secret"));
+        assert_ne!(KeyId::new("secret"), KeyId::new("secreT"));
+        assert_ne!(KeyId::new(""), KeyId::from([u8::MIN; KeyId::SIZE]));
+    }
+
+    #[test]
+    fn from_bytes() {
+        let key_id = KeyId::from([0x42; KeyId::SIZE]);
+        assert_eq!(key_id.as_bytes(), &[0x42; KeyId::SIZE]);
+        assert_eq!(<[u8; KeyId::SIZE]>::from(key_id), [0x42; KeyId::SIZE]);
+    }
+
+    #[test]
+    fn candidate() {
+        let candidate = Candidate::new("passphrase");
+        assert_eq!(candidate.passphrase(), b"passphrase");
+        assert_eq!(candidate.key_id(), None);
+
+        let candidate = candidate.with_key_id(KeyId::new("secret"));
+        assert_eq!(candidate.key_id(), Some(KeyId::new("secret")));
+    }
+
+    #[test]
+    fn candidate_matches() {
+        let key_id = KeyId::new("secret");
+        assert!(Candidate::new("passphrase").matches(None));
+        assert!(Candidate::new("passphrase").matches(Some(key_id)));
+        assert!(
+            Candidate::new("passphrase")
+                .with_key_id(key_id)
+                .matches(None)
+        );
+        assert!(
+            Candidate::new("passphrase")
+                .with_key_id(key_id)
+                .matches(Some(key_id))
+        );
+        assert!(
+            !Candidate::new("passphrase")
+                .with_key_id(KeyId::new("other"))
+                .matches(Some(key_id))
+        );
+    }
+}
@@ -5,7 +5,9 @@
 //! The `abcrypt` crate is an implementation of the [abcrypt encrypted data
 //! format].
 //!
-//! This crate supports version 1 of the abcrypt format.
+//! This crate supports version 1, version 2 and version 3 of the abcrypt
+//! format. Version 3 is used only when the key derivation function other than
+//! Argon2 is selected.
 //!
 //! # Examples
 //!
@@ -73,6 +75,86 @@
 //! assert_eq!(buf, *data);
 //! ```
 //!
+//! ## Hiding the length of the plaintext
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use abcrypt::{Decryptor, Encryptor, Padding, argon2::Params};
+//!
+//! let data = b"Hello, world!\n";
+//! let passphrase = "passphrase";
+//!
+//! // Encrypt `data` with the padding using `passphrase`.
+//! let params = Params::new(32, 3, 4, None).unwrap();
+//! let ciphertext = Encryptor::with_params(data, passphrase, params)
+//!     .map(|c| c.with_padding(Padding::Padme).encrypt_to_vec())
+//!     .unwrap();
+//! assert_ne!(
+//!     ciphertext.len(),
+//!     abcrypt::HEADER_SIZE + data.len() + abcrypt::TAG_SIZE
+//! );
+//!
+//! // And decrypt it back. The padding is removed.
+//! let plaintext = Decryptor::new(&ciphertext, passphrase)
+//!     .and_then(|c| c.decrypt_to_vec())
+//!     .unwrap();
+//! assert_eq!(plaintext, data);
+//! # }
+//! ```
+//!
+//! ## Storing the metadata of the plaintext
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use abcrypt::{Decryptor, Encryptor, Metadata, argon2::Params};
+//!
+//! let data = b"Hello, world!\n";
+//! let passphrase = "passphrase";
+//!
+//! // Encrypt `data` with its metadata using `passphrase`.
+//! let metadata = Metadata::new()
+//!     .with_file_name("hello.txt")
+//!     .with_content_type("text/plain");
+//! let params = Params::new(32, 3, 4, None).unwrap();
+//! let ciphertext = Encryptor::with_params(data, passphrase, params)
+//!     .map(|c| c.with_metadata(&metadata).encrypt_to_vec())
+//!     .unwrap();
+//!
+//! // And decrypt it back with the metadata.
+//! let (plaintext, decrypted_metadata) = Decryptor::new(&ciphertext, passphrase)
+//!     .and_then(|c| c.decrypt_to_vec_with_metadata())
+//!     .unwrap();
+//! assert_eq!(plaintext, data);
+//! assert_eq!(decrypted_metadata, metadata);
+//! # }
+//! ```
+//!
+//! ## Encoding the encrypted data as text
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use abcrypt::{Encryptor, argon2::Params, armor};
+//!
+//! let data = b"Hello, world!\n";
+//! let passphrase = "passphrase";
+//!
+//! // Encrypt `data` using `passphrase`, and encode it with the ASCII armor.
+//! let params = Params::new(32, 3, 4, None).unwrap();
+//! let ciphertext = Encryptor::with_params(data, passphrase, params)
+//!     .map(|c| c.encrypt_to_vec())
+//!     .unwrap();
+//! let armored = armor::encode_with_checksum(ciphertext);
+//! assert!(armored.starts_with("-----BEGIN ABCRYPT ENCRYPTED DATA-----"));
+//!
+//! // And decrypt it back. The armored data is detected automatically.
+//! let plaintext = abcrypt::decrypt(armored, passphrase).unwrap();
+//! assert_eq!(plaintext, data);
+//! # }
+//! ```
+//!
 //! ## Extracting the Argon2 parameters in the encrypted data
 //!
 //! ```
@@ -104,6 +186,8 @@
 // Lint levels of rustc.
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
+// Lint levels of Clippy.
+#![allow(clippy::multiple_crate_versions)]
 
 #[cfg(feature = "alloc")]
 #[macro_use]
@@ -111,29 +195,78 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod aead;
+#[cfg(feature = "archive")]
+pub mod archive;
 mod argon2_context;
+pub mod armor;
 mod decrypt;
 mod encrypt;
 mod error;
+mod extension;
 mod format;
+mod inspect;
+mod kdf;
+mod key_id;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "alloc")]
+mod metadata;
+mod normalization;
+mod padding;
 mod params;
+mod payload;
+#[cfg(feature = "scrypt-compat")]
+pub mod scrypt_compat;
+#[cfg(feature = "std")]
+mod seek;
+mod segment;
+#[cfg(feature = "sharing")]
+pub mod sharing;
+#[cfg(feature = "ed25519")]
+mod signature;
+mod validity;
 
+#[cfg(feature = "aes-gcm-siv")]
+pub use aes_gcm_siv;
 pub use argon2;
 pub use blake2;
+#[cfg(feature = "bytes")]
+pub use bytes;
 pub use chacha20poly1305;
+#[cfg(feature = "ed25519")]
+pub use ed25519_dalek;
+#[cfg(any(feature = "parallel", feature = "rayon"))]
+pub use rayon;
+#[cfg(feature = "scrypt")]
+pub use scrypt;
 
+#[cfg(feature = "scrypt")]
+pub use crate::kdf::ScryptParams;
+#[cfg(feature = "std")]
+pub use crate::seek::SeekableDecryptor;
 pub use crate::{
+    aead::Aead,
     argon2_context::Argon2,
     decrypt::Decryptor,
     encrypt::Encryptor,
-    error::{Error, Result},
-    format::{HEADER_SIZE, TAG_SIZE},
+    error::{Error, ErrorKind, Result},
+    extension::{Extension, Extensions},
+    format::{FormatVersion, HEADER_SIZE, TAG_SIZE},
+    inspect::detect,
+    kdf::Kdf,
+    key_id::{Candidate, KeyId},
+    normalization::Normalization,
+    padding::Padding,
     params::Params,
+    validity::Validity,
 };
 #[cfg(feature = "alloc")]
 pub use crate::{
-    decrypt::decrypt,
+    decrypt::{decrypt, decrypt_with_any},
     encrypt::{encrypt, encrypt_with_context, encrypt_with_params},
+    inspect::{Report, inspect},
+    metadata::Metadata,
 };
 
 #[cfg(not(feature = "alloc"))]
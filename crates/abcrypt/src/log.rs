@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Append-only encrypted record logs.
+//!
+//! A record log is an abcrypt encrypted data whose ciphertext is a sequence of
+//! records instead of a single payload. The key is derived from the passphrase
+//! only once when the log is opened, and each record is encrypted and
+//! authenticated independently when it is appended, so appending a record
+//! costs neither the key derivation nor another header.
+//!
+//! The nonce of each record is derived from its sequence number in the same
+//! way as the segments of the segmented payload, so reordered, duplicated or
+//! dropped records are detected. [`LogWriter::finish`] appends the final
+//! record, after which truncating the log at a record boundary is also
+//! detected.
+//!
+//! # Examples
+//!
+//! ```
+//! # use abcrypt::{
+//! #     argon2::Params,
+//! #     log::{LogReader, LogWriter},
+//! # };
+//! #
+//! let passphrase = "passphrase";
+//!
+//! let params = Params::new(32, 3, 4, None).unwrap();
+//! let mut writer = LogWriter::new(Vec::new(), passphrase, params).unwrap();
+//! writer.append(b"first").unwrap();
+//! writer.append(b"second").unwrap();
+//! let log = writer.finish().unwrap();
+//!
+//! let mut reader = LogReader::new(log.as_slice(), passphrase).unwrap();
+//! assert_eq!(reader.read_record().unwrap().unwrap(), b"first");
+//! assert_eq!(reader.read_record().unwrap().unwrap(), b"second");
+//! assert!(reader.read_record().unwrap().is_none());
+//! assert!(reader.is_finished());
+//! ```
+
+use std::{
+    io::{self, Read, Write},
+    vec::Vec,
+};
+
+use argon2::{Algorithm, Params, Version};
+
+use crate::{
+    Argon2, Error, FormatVersion, Kdf, TAG_SIZE, decrypt,
+    extension::ExtensionArea,
+    format::{DerivedKey, Header},
+};
+
+/// The number of bytes of the length of a record.
+const LEN_SIZE: usize = 4;
+
+/// Writer for a record log.
+///
+/// Each call to [`LogWriter::append`] writes one record to the underlying
+/// writer, so the records should be written to a file or a buffered writer as
+/// they arrive.
+///
+/// The errors of the abcrypt encrypted data format are returned as
+/// [`io::Error`] with the kind [`io::ErrorKind::InvalidData`], and the
+/// original [`Error`] can be retrieved by [`io::Error::get_ref`].
+#[derive(Debug)]
+pub struct LogWriter<W> {
+    inner: W,
+    header: Header,
+    dk: DerivedKey,
+    seq: u32,
+}
+
+impl<W: Write> LogWriter<W> {
+    /// Creates a new record log with the specified Argon2 [`Params`], and
+    /// writes the header to `inner`.
+    ///
+    /// This uses the Argon2 type created by [`Algorithm::default`] and the
+    /// Argon2 version created by [`Version::default`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the Argon2 context is invalid or writing to `inner`
+    /// fails.
+    pub fn new(mut inner: W, passphrase: impl AsRef<[u8]>, params: Params) -> io::Result<Self> {
+        let kdf = Kdf::from(Argon2::from_parts(
+            Algorithm::default().into(),
+            Version::default().into(),
+            params.into(),
+        ));
+        let mut header = Header::new(kdf);
+        header.set_record_log()?;
+        let dk = kdf.derive_key(passphrase.as_ref(), &header.salt())?;
+        header.compute_mac(&dk.mac());
+
+        let mut buf = [u8::default(); Header::MAX_SIZE];
+        header.write(&mut buf);
+        inner.write_all(&buf[..header.size()])?;
+        Ok(Self {
+            inner,
+            header,
+            dk,
+            seq: u32::MIN,
+        })
+    }
+
+    /// Encrypts `record` and appends it to the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `record` is larger than 4 GiB, the log already has
+    /// the maximum number of records, or writing to the underlying writer
+    /// fails.
+    pub fn append(&mut self, record: impl AsRef<[u8]>) -> io::Result<()> {
+        if self.seq == u32::MAX {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "too many records in record log",
+            ));
+        }
+        self.write_record(record.as_ref(), false)
+    }
+
+    /// Appends the final record, and returns the underlying writer.
+    ///
+    /// No records can be appended to the log after this, and the readers can
+    /// tell that the log was not truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if writing to or flushing the underlying writer fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_record(&[], true)?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+
+    /// Encrypts `record` with the current sequence number and writes it.
+    fn write_record(&mut self, record: &[u8], last: bool) -> io::Result<()> {
+        let len = u32::try_from(record.len()).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record should be at most 4 GiB",
+            )
+        })?;
+        let mut buf = Vec::with_capacity(LEN_SIZE + record.len() + TAG_SIZE);
+        buf.extend_from_slice(&len.to_le_bytes());
+        buf.extend_from_slice(record);
+
+        let aead = self.header.aead();
+        let nonce = aead.segment_nonce(&self.header.nonce(), self.seq, last);
+        let tag =
+            aead.encrypt_in_place_detached(&self.dk.encrypt(), &nonce, &mut buf[LEN_SIZE..])?;
+        buf.extend_from_slice(&tag);
+        self.inner.write_all(&buf)?;
+        self.seq += 1;
+        Ok(())
+    }
+
+    /// Unwraps this `LogWriter` without finishing the log, returning the
+    /// underlying writer.
+    ///
+    /// The log can be reopened by [`LogWriter::open`] to append more records.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Read + Write> LogWriter<W> {
+    /// Opens the existing record log in `inner` to append more records.
+    ///
+    /// This reads and authenticates all records in `inner`, so the records
+    /// are appended after the last one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the errors returned by [`LogReader::new`] or
+    /// [`LogReader::read_record`] occurs, or the log is already finished.
+    pub fn open(inner: W, passphrase: impl AsRef<[u8]>) -> io::Result<Self> {
+        let mut reader = LogReader::new(inner, passphrase)?;
+        while reader.read_record()?.is_some() {}
+        if reader.is_finished() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record log is already finished",
+            ));
+        }
+        Ok(Self {
+            inner: reader.inner,
+            header: reader.header,
+            dk: reader.dk,
+            seq: reader.seq,
+        })
+    }
+}
+
+/// Reader for a record log.
+///
+/// This also implements [`Iterator`] over the records.
+///
+/// The errors of the abcrypt encrypted data format are returned as
+/// [`io::Error`] with the kind [`io::ErrorKind::InvalidData`], and the
+/// original [`Error`] can be retrieved by [`io::Error::get_ref`].
+#[derive(Debug)]
+pub struct LogReader<R> {
+    inner: R,
+    header: Header,
+    dk: DerivedKey,
+    seq: u32,
+    offset: u64,
+    finished: bool,
+}
+
+impl<R: Read> LogReader<R> {
+    /// Creates a new `LogReader`.
+    ///
+    /// This reads the header from `inner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if reading from `inner` fails, any of the errors
+    /// returned by [`Decryptor::new`](crate::Decryptor::new) about the header
+    /// occurs, the encrypted data is not a record log, or the system time is
+    /// outside the validity period of the encrypted data.
+    pub fn new(mut inner: R, passphrase: impl AsRef<[u8]>) -> io::Result<Self> {
+        let data = read_header(&mut inner)?;
+        let header = Header::parse_detached(&data)?;
+        if !header.is_record_log()
+            || header.segment_size().is_some()
+            || header.verifying_key().is_some()
+        {
+            return Err(Error::InvalidRecordLog.into());
+        }
+        let (header, dk) = decrypt::unlock(header, &data, passphrase.as_ref())?;
+        header.validity().check_at(None)?;
+        Ok(Self {
+            inner,
+            offset: header.size() as u64,
+            header,
+            dk,
+            seq: u32::MIN,
+            finished: bool::default(),
+        })
+    }
+
+    /// Reads and decrypts the next record.
+    ///
+    /// Returns [`None`] at the end of the log. Use [`LogReader::is_finished`]
+    /// to check whether the log ended with the final record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - Reading from the underlying reader fails.
+    /// - The log ends in the middle of a record.
+    /// - The MAC (authentication tag) of the record is invalid.
+    /// - The log has data after the final record.
+    pub fn read_record(&mut self) -> io::Result<Option<Vec<u8>>> {
+        if self.finished {
+            return Ok(None);
+        }
+        let mut len = [u8::default(); LEN_SIZE];
+        let n = read_full(&mut self.inner, &mut len)?;
+        if n == usize::MIN {
+            return Ok(None);
+        }
+        let invalid_length = |len: u64, min: u64| Error::InvalidLength {
+            len: usize::try_from(self.offset + len).unwrap_or(usize::MAX),
+            min: usize::try_from(self.offset + min).unwrap_or(usize::MAX),
+        };
+        if n < LEN_SIZE {
+            return Err(invalid_length(n as u64, LEN_SIZE as u64).into());
+        }
+        let len = u64::from(u32::from_le_bytes(len));
+        let mut buf = Vec::new();
+        (&mut self.inner)
+            .take(len + TAG_SIZE as u64)
+            .read_to_end(&mut buf)?;
+        let record_size = (LEN_SIZE + TAG_SIZE) as u64 + len;
+        if buf.len() as u64 != len + TAG_SIZE as u64 {
+            let read = (LEN_SIZE + buf.len()) as u64;
+            return Err(invalid_length(read, record_size).into());
+        }
+        let tag = buf.split_off(buf.len() - TAG_SIZE);
+
+        // Only the final record is empty and marked as the last one.
+        let (aead, key, nonce) = (self.header.aead(), self.dk.encrypt(), self.header.nonce());
+        let decrypt = |buf: &mut [u8], last| {
+            let nonce = aead.segment_nonce(&nonce, self.seq, last);
+            aead.decrypt_in_place_detached(&key, &nonce, buf, tag.as_slice().into(), self.offset)
+        };
+        if buf.is_empty() && decrypt(&mut buf, true).is_ok() {
+            if read_full(&mut self.inner, &mut [u8::default()])? != usize::MIN {
+                return Err(Error::InvalidRecordLog.into());
+            }
+            self.finished = true;
+        } else {
+            decrypt(&mut buf, false)?;
+        }
+        if !self.finished {
+            self.seq = self.seq.checked_add(1).ok_or(Error::InvalidRecordLog)?;
+        }
+        self.offset += record_size;
+        Ok((!self.finished).then_some(buf))
+    }
+
+    /// Returns `true` if the final record has been read.
+    ///
+    /// If [`LogReader::read_record`] returns [`None`] while this is `false`,
+    /// the log was either not finished or truncated.
+    #[must_use]
+    #[inline]
+    pub const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Unwraps this `LogReader`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Iterator for LogReader<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// Reads the header from `reader`.
+///
+/// The header is not parsed, but the length of the extensions is read to
+/// determine the size of the header.
+fn read_header(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let start = Header::FIXED_SIZE + Header::EXTENSIONS_LEN_SIZE;
+    let mut data = Vec::with_capacity(Header::MAX_SIZE);
+    reader.take(start as u64).read_to_end(&mut data)?;
+    let version = data.get(7).map(|&v| FormatVersion::try_from(v));
+    let rest = match version {
+        Some(Ok(FormatVersion::V2 | FormatVersion::V3)) if data.len() == start => {
+            let len = u32::from_le_bytes([data[84], data[85], data[86], data[87]]);
+            // The extensions longer than the maximum are rejected when parsing.
+            (len as usize).min(ExtensionArea::MAX_SIZE + 1) + Header::MAC_SIZE
+        }
+        _ => Header::SIZE.saturating_sub(data.len()),
+    };
+    reader.take(rest as u64).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Reads from `reader` until `buf` is filled or the end of `reader` is
+/// reached, and returns the number of bytes read.
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut n = usize::MIN;
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(m) => n += m,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(n)
+}
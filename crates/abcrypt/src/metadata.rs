@@ -0,0 +1,397 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The metadata of the plaintext.
+
+use alloc::{string::String, vec::Vec};
+use core::{str, time::Duration};
+
+use crate::{Error, Result};
+
+/// The type of the entry of the original file name.
+const FILE_NAME: u16 = 0x0001;
+
+/// The type of the entry of the modification time.
+const MODIFIED: u16 = 0x0002;
+
+/// The type of the entry of the file mode.
+const MODE: u16 = 0x0003;
+
+/// The type of the entry of the content type.
+const CONTENT_TYPE: u16 = 0x0004;
+
+/// The number of bytes of the type and the length of an entry.
+const ENTRY_HEADER_SIZE: usize = 6;
+
+/// The metadata of the plaintext.
+///
+/// The metadata is encrypted and authenticated together with the plaintext
+/// using version 2 of the abcrypt encrypted data format.
+///
+/// # Examples
+///
+/// ```
+/// # use core::time::Duration;
+/// #
+/// # use abcrypt::Metadata;
+/// #
+/// let metadata = Metadata::new()
+///     .with_file_name("data.txt")
+///     .with_modified(Duration::from_secs(1_767_225_600))
+///     .with_mode(0o644)
+///     .with_content_type("text/plain");
+/// assert_eq!(metadata.file_name(), Some("data.txt"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct Metadata {
+    file_name: Option<String>,
+    modified: Option<Duration>,
+    mode: Option<u32>,
+    content_type: Option<String>,
+}
+
+impl Metadata {
+    /// Creates a new empty `Metadata`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// let metadata = Metadata::new();
+    /// assert!(metadata.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            file_name: None,
+            modified: None,
+            mode: None,
+            content_type: None,
+        }
+    }
+
+    /// Sets the original file name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// let metadata = Metadata::new().with_file_name("data.txt");
+    /// assert_eq!(metadata.file_name(), Some("data.txt"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Sets the modification time as the duration since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::time::Duration;
+    /// #
+    /// # use abcrypt::Metadata;
+    /// #
+    /// let metadata = Metadata::new().with_modified(Duration::from_secs(1_767_225_600));
+    /// assert_eq!(
+    ///     metadata.modified(),
+    ///     Some(Duration::from_secs(1_767_225_600))
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_modified(mut self, modified: Duration) -> Self {
+        self.modified = Some(modified);
+        self
+    }
+
+    /// Sets the file mode (the Unix permission bits).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// let metadata = Metadata::new().with_mode(0o644);
+    /// assert_eq!(metadata.mode(), Some(0o644));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Sets the content type (the media type) of the plaintext.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// let metadata = Metadata::new().with_content_type("text/plain");
+    /// assert_eq!(metadata.content_type(), Some("text/plain"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// Gets the original file name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// assert_eq!(Metadata::new().file_name(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// Gets the modification time as the duration since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// assert_eq!(Metadata::new().modified(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn modified(&self) -> Option<Duration> {
+        self.modified
+    }
+
+    /// Gets the file mode (the Unix permission bits).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// assert_eq!(Metadata::new().mode(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn mode(&self) -> Option<u32> {
+        self.mode
+    }
+
+    /// Gets the content type (the media type) of the plaintext.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// assert_eq!(Metadata::new().content_type(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Returns `true` if no entries are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Metadata;
+    /// #
+    /// assert!(Metadata::new().is_empty());
+    /// assert!(!Metadata::new().with_mode(0o644).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.file_name.is_none()
+            && self.modified.is_none()
+            && self.mode.is_none()
+            && self.content_type.is_none()
+    }
+
+    /// Encodes the metadata as the sequence of the entries.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        fn push_entry(buf: &mut Vec<u8>, ty: u16, value: &[u8]) {
+            let len = u32::try_from(value.len()).expect("metadata entry should be at most 4 GiB");
+            buf.extend_from_slice(&ty.to_le_bytes());
+            buf.extend_from_slice(&len.to_le_bytes());
+            buf.extend_from_slice(value);
+        }
+
+        let mut buf = Vec::new();
+        if let Some(file_name) = &self.file_name {
+            push_entry(&mut buf, FILE_NAME, file_name.as_bytes());
+        }
+        if let Some(modified) = self.modified {
+            let mut value = [u8::default(); 12];
+            value[..8].copy_from_slice(&modified.as_secs().to_le_bytes());
+            value[8..].copy_from_slice(&modified.subsec_nanos().to_le_bytes());
+            push_entry(&mut buf, MODIFIED, &value);
+        }
+        if let Some(mode) = self.mode {
+            push_entry(&mut buf, MODE, &mode.to_le_bytes());
+        }
+        if let Some(content_type) = &self.content_type {
+            push_entry(&mut buf, CONTENT_TYPE, content_type.as_bytes());
+        }
+        buf
+    }
+
+    /// Decodes the metadata from the sequence of the entries.
+    ///
+    /// The entries of the unknown types are ignored.
+    pub(crate) fn decode(mut data: &[u8]) -> Result<Self> {
+        fn set<T>(field: &mut Option<T>, value: Option<T>) -> Result<()> {
+            if field.is_some() {
+                return Err(Error::InvalidMetadata);
+            }
+            *field = Some(value.ok_or(Error::InvalidMetadata)?);
+            Ok(())
+        }
+
+        let mut metadata = Self::new();
+        while !data.is_empty() {
+            if data.len() < ENTRY_HEADER_SIZE {
+                return Err(Error::InvalidMetadata);
+            }
+            let ty = u16::from_le_bytes([data[0], data[1]]);
+            let len = u32::from_le_bytes([data[2], data[3], data[4], data[5]]);
+            let value = usize::try_from(len)
+                .ok()
+                .and_then(|len| data[ENTRY_HEADER_SIZE..].get(..len))
+                .ok_or(Error::InvalidMetadata)?;
+            data = &data[(ENTRY_HEADER_SIZE + value.len())..];
+
+            match ty {
+                FILE_NAME => set(
+                    &mut metadata.file_name,
+                    str::from_utf8(value).ok().map(String::from),
+                )?,
+                MODIFIED => set(
+                    &mut metadata.modified,
+                    <[u8; 12]>::try_from(value).ok().and_then(|value| {
+                        let secs = u64::from_le_bytes(value[..8].try_into().ok()?);
+                        let nanos = u32::from_le_bytes(value[8..].try_into().ok()?);
+                        (nanos < 1_000_000_000).then(|| Duration::new(secs, nanos))
+                    }),
+                )?,
+                MODE => set(
+                    &mut metadata.mode,
+                    <[u8; 4]>::try_from(value).ok().map(u32::from_le_bytes),
+                )?,
+                CONTENT_TYPE => set(
+                    &mut metadata.content_type,
+                    str::from_utf8(value).ok().map(String::from),
+                )?,
+                _ => {}
+            }
+        }
+        Ok(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode() {
+        assert!(Metadata::new().encode().is_empty());
+
+        let metadata = Metadata::new()
+            .with_file_name("a")
+            .with_modified(Duration::new(1, 2))
+            .with_mode(0o644)
+            .with_content_type("b");
+        assert_eq!(
+            metadata.encode(),
+            [
+                [0x01, 0x00, 0x01, 0x00, 0x00, 0x00].as_slice(),
+                b"a",
+                &[0x02, 0x00, 0x0c, 0x00, 0x00, 0x00],
+                &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+                &[0x02, 0x00, 0x00, 0x00],
+                &[0x03, 0x00, 0x04, 0x00, 0x00, 0x00],
+                &[0xa4, 0x01, 0x00, 0x00],
+                &[0x04, 0x00, 0x01, 0x00, 0x00, 0x00],
+                b"b",
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(Metadata::decode(&[]).unwrap(), Metadata::new());
+
+        let metadata = Metadata::new()
+            .with_file_name("data.txt")
+            .with_modified(Duration::new(1_767_225_600, 999_999_999))
+            .with_mode(0o755)
+            .with_content_type("text/plain");
+        assert_eq!(Metadata::decode(&metadata.encode()).unwrap(), metadata);
+    }
+
+    #[test]
+    fn decode_with_unknown_entry() {
+        let data = [
+            [0xff, 0x7f, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00].as_slice(),
+            &[0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0xa4, 0x01, 0x00, 0x00],
+        ]
+        .concat();
+        assert_eq!(
+            Metadata::decode(&data).unwrap(),
+            Metadata::new().with_mode(0o644)
+        );
+    }
+
+    #[test]
+    fn decode_with_invalid_entry() {
+        // Truncated entry header.
+        assert_eq!(
+            Metadata::decode(&[0x01, 0x00, 0x00]).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        // Truncated entry value.
+        assert_eq!(
+            Metadata::decode(&[0x01, 0x00, 0x02, 0x00, 0x00, 0x00, b'a']).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        // Invalid UTF-8 file name.
+        assert_eq!(
+            Metadata::decode(&[0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0xff]).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        // Invalid length of the modification time.
+        assert_eq!(
+            Metadata::decode(&[0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00]).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        // Nanoseconds out of range.
+        let mut data = vec![0x02, 0x00, 0x0c, 0x00, 0x00, 0x00];
+        data.extend_from_slice(&[u8::default(); 8]);
+        data.extend_from_slice(&1_000_000_000_u32.to_le_bytes());
+        assert_eq!(Metadata::decode(&data).unwrap_err(), Error::InvalidMetadata);
+        // Duplicate entry.
+        let data = [0x03, 0x00, 0x04, 0x00, 0x00, 0x00, 0xa4, 0x01, 0x00, 0x00].repeat(2);
+        assert_eq!(Metadata::decode(&data).unwrap_err(), Error::InvalidMetadata);
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unicode normalization of the passphrase.
+
+#[cfg(feature = "normalization")]
+use alloc::string::String;
+
+#[cfg(feature = "normalization")]
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Error, Result};
+
+/// The Unicode normalization form applied to the passphrase before deriving
+/// the key.
+///
+/// The same text can be represented by different sequences of code points
+/// depending on the input method (e.g., "é" is composed on most systems but
+/// decomposed on macOS), which results in different keys. If the passphrase is
+/// normalized when encrypting, the normalization form is stored in the header,
+/// and the passphrase is normalized in the same way when decrypting.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Normalization {
+    /// Normalization Form C (canonical composition).
+    Nfc,
+
+    /// Normalization Form KC (compatibility composition).
+    ///
+    /// This also unifies the characters which are visually similar, such as
+    /// full-width and half-width forms.
+    Nfkc,
+}
+
+impl Normalization {
+    /// Returns the identifier of this normalization form stored in the header.
+    #[cfg(feature = "normalization")]
+    pub(crate) const fn id(self) -> u8 {
+        match self {
+            Self::Nfc => 0,
+            Self::Nfkc => 1,
+        }
+    }
+
+    /// Normalizes `passphrase` to this normalization form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Normalization;
+    /// #
+    /// assert_eq!(Normalization::Nfc.normalize("e\u{301}"), "\u{e9}");
+    /// assert_eq!(Normalization::Nfc.normalize("\u{ff21}"), "\u{ff21}");
+    /// assert_eq!(Normalization::Nfkc.normalize("\u{ff21}"), "A");
+    /// ```
+    #[cfg(feature = "normalization")]
+    #[must_use]
+    pub fn normalize(self, passphrase: &str) -> String {
+        match self {
+            Self::Nfc => passphrase.nfc().collect(),
+            Self::Nfkc => passphrase.nfkc().collect(),
+        }
+    }
+}
+
+impl TryFrom<u8> for Normalization {
+    type Error = Error;
+
+    /// Returns the normalization form of the identifier `id` stored in the
+    /// header.
+    ///
+    /// The identifier of NFC is 0, and the identifier of NFKC is 1. The
+    /// normalization forms are only supported if the `normalization` feature
+    /// is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `id` is unrecognized or the `normalization` feature
+    /// is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "normalization")]
+    /// # {
+    /// use abcrypt::{Error, Normalization};
+    ///
+    /// assert_eq!(Normalization::try_from(0), Ok(Normalization::Nfc));
+    /// assert_eq!(Normalization::try_from(1), Ok(Normalization::Nfkc));
+    /// assert_eq!(
+    ///     Normalization::try_from(2),
+    ///     Err(Error::UnsupportedNormalization(2))
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    fn try_from(id: u8) -> Result<Self> {
+        match id {
+            #[cfg(feature = "normalization")]
+            0 => Ok(Self::Nfc),
+            #[cfg(feature = "normalization")]
+            1 => Ok(Self::Nfkc),
+            id => Err(Error::UnsupportedNormalization(id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    fn id() {
+        assert_eq!(Normalization::Nfc.id(), 0);
+        assert_eq!(Normalization::Nfkc.id(), 1);
+    }
+
+    #[test]
+    fn try_from() {
+        #[cfg(feature = "normalization")]
+        {
+            assert_eq!(Normalization::try_from(0).unwrap(), Normalization::Nfc);
+            assert_eq!(Normalization::try_from(1).unwrap(), Normalization::Nfkc);
+        }
+        #[cfg(not(feature = "normalization"))]
+        {
+            assert_eq!(
+                Normalization::try_from(0).unwrap_err(),
+                Error::UnsupportedNormalization(0)
+            );
+            assert_eq!(
+                Normalization::try_from(1).unwrap_err(),
+                Error::UnsupportedNormalization(1)
+            );
+        }
+        assert_eq!(
+            Normalization::try_from(2).unwrap_err(),
+            Error::UnsupportedNormalization(2)
+        );
+    }
+
+    #[cfg(feature = "normalization")]
+    #[test]
+    fn normalize() {
+        let composed = "\u{e9}";
+        let decomposed = "e\u{301}";
+        assert_eq!(Normalization::Nfc.normalize(composed), composed);
+        assert_eq!(Normalization::Nfc.normalize(decomposed), composed);
+        assert_eq!(Normalization::Nfkc.normalize(decomposed), composed);
+
+        assert_eq!(Normalization::Nfc.normalize("\u{fb01}"), "\u{fb01}");
+        assert_eq!(Normalization::Nfkc.normalize("\u{fb01}"), "fi");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Normalization::Nfc,
+            &[Token::UnitVariant {
+                name: "Normalization",
+                variant: "nfc",
+            }],
+        );
+        assert_tokens(
+            &Normalization::Nfkc,
+            &[Token::UnitVariant {
+                name: "Normalization",
+                variant: "nfkc",
+            }],
+        );
+    }
+}
@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Length-hiding padding for the plaintext.
+
+use core::num::NonZeroUsize;
+
+use crate::{Error, Result};
+
+/// The byte that marks the end of the plaintext in the padded payload.
+const PADDING_DELIMITER: u8 = 0x80;
+
+/// The padding policy used to hide the length of the plaintext.
+///
+/// The padding is appended to the plaintext before encryption using the
+/// ISO/IEC 7816-4 scheme (the byte `0x80` followed by zero or more `0x00`
+/// bytes), so the padded payload is always at least one byte longer than the
+/// plaintext.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum Padding {
+    /// Pads the payload using the [PADMÉ] algorithm.
+    ///
+    /// This limits the leaked information of the length to `O(log log n)` bits
+    /// with at most 12% overhead.
+    ///
+    /// [PADMÉ]: https://petsymposium.org/popets/2019/popets-2019-0056.pdf
+    Padme,
+
+    /// Pads the payload to a multiple of the specified number of bytes.
+    Multiple(NonZeroUsize),
+}
+
+impl Padding {
+    /// Returns the number of bytes of the payload after padding `len` bytes of
+    /// the plaintext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the padded length overflows [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::num::NonZeroUsize;
+    /// #
+    /// # use abcrypt::Padding;
+    /// #
+    /// assert_eq!(Padding::Padme.padded_len(14), 16);
+    /// assert_eq!(Padding::Padme.padded_len(1000), 1024);
+    ///
+    /// let padding = Padding::Multiple(NonZeroUsize::new(512).unwrap());
+    /// assert_eq!(padding.padded_len(14), 512);
+    /// assert_eq!(padding.padded_len(511), 512);
+    /// assert_eq!(padding.padded_len(512), 1024);
+    /// ```
+    #[must_use]
+    pub const fn padded_len(self, len: usize) -> usize {
+        assert!(len < usize::MAX, "plaintext is too long to pad");
+        let len = len + 1;
+        match self {
+            Self::Padme => {
+                if len < 2 {
+                    return len;
+                }
+                let e = len.ilog2();
+                let s = e.ilog2() + 1;
+                let mask = (1 << (e - s)) - 1;
+                assert!(len <= usize::MAX - mask, "padded length overflows");
+                (len + mask) & !mask
+            }
+            Self::Multiple(n) => {
+                let n = n.get();
+                let Some(padded_len) = len.div_ceil(n).checked_mul(n) else {
+                    panic!("padded length overflows");
+                };
+                padded_len
+            }
+        }
+    }
+
+    /// Writes the padding into `buf` after the first `len` bytes.
+    pub(crate) fn pad(buf: &mut [u8], len: usize) {
+        let (delimiter, zeros) = buf[len..].split_first_mut().expect("buffer is too short");
+        *delimiter = PADDING_DELIMITER;
+        zeros.fill(u8::default());
+    }
+
+    /// Returns the number of bytes of the plaintext in the padded payload
+    /// `buf`.
+    pub(crate) fn unpadded_len(buf: &[u8]) -> Result<usize> {
+        match buf.iter().rposition(|&b| b != u8::default()) {
+            Some(pos) if buf[pos] == PADDING_DELIMITER => Ok(pos),
+            _ => Err(Error::InvalidPadding),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padded_len_padme() {
+        assert_eq!(Padding::Padme.padded_len(0), 1);
+        assert_eq!(Padding::Padme.padded_len(1), 2);
+        assert_eq!(Padding::Padme.padded_len(7), 8);
+        assert_eq!(Padding::Padme.padded_len(8), 10);
+        assert_eq!(Padding::Padme.padded_len(9), 10);
+        assert_eq!(Padding::Padme.padded_len(14), 16);
+        assert_eq!(Padding::Padme.padded_len(1000), 1024);
+        assert_eq!(Padding::Padme.padded_len(1023), 1024);
+        assert_eq!(Padding::Padme.padded_len(1024), 1088);
+        assert_eq!(Padding::Padme.padded_len(usize::pow(10, 6)), 1_015_808);
+    }
+
+    #[test]
+    fn padme_overhead() {
+        for len in (0..usize::pow(2, 20)).step_by(997) {
+            let padded_len = Padding::Padme.padded_len(len);
+            assert!(padded_len > len);
+            assert!((padded_len - (len + 1)) * 100 <= (len + 1) * 12);
+        }
+    }
+
+    #[test]
+    fn padded_len_multiple() {
+        let padding = Padding::Multiple(NonZeroUsize::MIN);
+        assert_eq!(padding.padded_len(0), 1);
+        assert_eq!(padding.padded_len(14), 15);
+
+        let padding = Padding::Multiple(NonZeroUsize::new(16).unwrap());
+        assert_eq!(padding.padded_len(0), 16);
+        assert_eq!(padding.padded_len(14), 16);
+        assert_eq!(padding.padded_len(15), 16);
+        assert_eq!(padding.padded_len(16), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "padded length overflows")]
+    fn padded_len_multiple_overflow() {
+        let _ = Padding::Multiple(NonZeroUsize::new(usize::pow(2, 16)).unwrap())
+            .padded_len(usize::MAX - 1);
+    }
+
+    #[test]
+    fn pad() {
+        let mut buf = [u8::MAX; 8];
+        Padding::pad(&mut buf, 3);
+        assert_eq!(buf, [u8::MAX, u8::MAX, u8::MAX, 0x80, 0, 0, 0, 0]);
+
+        let mut buf = [u8::MAX; 4];
+        Padding::pad(&mut buf, 3);
+        assert_eq!(buf, [u8::MAX, u8::MAX, u8::MAX, 0x80]);
+    }
+
+    #[test]
+    fn unpadded_len() {
+        assert_eq!(Padding::unpadded_len(&[0x80]).unwrap(), 0);
+        assert_eq!(Padding::unpadded_len(&[0x80, 0x00, 0x00]).unwrap(), 0);
+        assert_eq!(Padding::unpadded_len(&[0x00, 0x80, 0x00]).unwrap(), 1);
+        assert_eq!(Padding::unpadded_len(&[0x80, 0x80, 0x00]).unwrap(), 1);
+    }
+
+    #[test]
+    fn unpadded_len_with_invalid_padding() {
+        assert_eq!(
+            Padding::unpadded_len(&[]).unwrap_err(),
+            Error::InvalidPadding
+        );
+        assert_eq!(
+            Padding::unpadded_len(&[0x00, 0x00]).unwrap_err(),
+            Error::InvalidPadding
+        );
+        assert_eq!(
+            Padding::unpadded_len(&[0x80, 0x01]).unwrap_err(),
+            Error::InvalidPadding
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        use serde_test::{Token, assert_tokens};
+
+        assert_tokens(
+            &Padding::Padme,
+            &[Token::UnitVariant {
+                name: "Padding",
+                variant: "padme",
+            }],
+        );
+        assert_tokens(
+            &Padding::Multiple(NonZeroUsize::new(512).unwrap()),
+            &[
+                Token::NewtypeVariant {
+                    name: "Padding",
+                    variant: "multiple",
+                },
+                Token::U64(512),
+            ],
+        );
+    }
+}
@@ -4,12 +4,17 @@
 
 //! The Argon2 parameters.
 
-use crate::{Result, format::Header};
+use core::{fmt, str::FromStr};
+
+use crate::{Argon2, Error, Result};
 
 /// The Argon2 parameters used for the encrypted data.
 #[derive(Clone, Copy, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", try_from = "RawParams")
+)]
 pub struct Params {
     memory_cost: u32,
     time_cost: u32,
@@ -19,10 +24,13 @@ pub struct Params {
 impl Params {
     /// Creates a new instance of the Argon2 parameters from `ciphertext`.
     ///
+    /// `ciphertext` can be the [armored](crate::armor) data.
+    ///
     /// # Errors
     ///
     /// Returns [`Err`] if any of the following are true:
     ///
+    /// - `ciphertext` is armored and the ASCII armor is invalid.
     /// - `ciphertext` is shorter than 164 bytes.
     /// - The magic number is invalid.
     /// - The version number is the unsupported abcrypt version number.
@@ -30,6 +38,11 @@ impl Params {
     /// - The Argon2 type is invalid.
     /// - The Argon2 version is invalid.
     /// - The Argon2 parameters are invalid.
+    /// - The extensions of the header are invalid.
+    /// - The header contains the unrecognized critical extension.
+    /// - The AEAD is unrecognized or not enabled.
+    /// - The passphrase normalization form is unrecognized or not enabled.
+    /// - The key derivation function is not Argon2.
     ///
     /// # Examples
     ///
@@ -42,11 +55,7 @@ impl Params {
     /// ```
     #[inline]
     pub fn new(ciphertext: impl AsRef<[u8]>) -> Result<Self> {
-        let inner = |ciphertext: &[u8]| -> Result<Self> {
-            let params = Header::parse(ciphertext).map(|h| h.params())?;
-            Ok(params)
-        };
-        inner(ciphertext.as_ref())
+        Argon2::new(ciphertext).map(|a| a.params())
     }
 
     /// Gets memory size in KiB.
@@ -87,6 +96,10 @@ impl Params {
 
     /// Gets degree of parallelism.
     ///
+    /// This is the number of lanes of the Argon2 memory. The lanes are filled
+    /// concurrently on the current Rayon thread pool if the `parallel` feature
+    /// is enabled, and one after another on the current thread otherwise.
+    ///
     /// # Examples
     ///
     /// ```
@@ -104,6 +117,110 @@ impl Params {
     }
 }
 
+impl fmt::Display for Params {
+    /// Formats the Argon2 parameters as the parameters of the [PHC string
+    /// format].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Params;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+    ///
+    /// let params = Params::new(ciphertext).unwrap();
+    /// assert_eq!(format!("{params}"), "m=32,t=3,p=4");
+    /// ```
+    ///
+    /// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m={},t={},p={}",
+            self.memory_cost(),
+            self.time_cost(),
+            self.parallelism()
+        )
+    }
+}
+
+impl FromStr for Params {
+    type Err = Error;
+
+    /// Parses the parameters of the [PHC string format] into the Argon2
+    /// parameters.
+    ///
+    /// The parameters must be `m`, `t` and `p` in this order, such as
+    /// `m=19456,t=2,p=1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - `s` is not the valid parameters of the PHC string format.
+    /// - The Argon2 parameters are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::Params;
+    /// #
+    /// let params: Params = "m=19456,t=2,p=1".parse().unwrap();
+    /// assert_eq!(params.memory_cost(), 19456);
+    /// assert_eq!(params.time_cost(), 2);
+    /// assert_eq!(params.parallelism(), 1);
+    /// ```
+    ///
+    /// [PHC string format]: https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md
+    #[inline]
+    fn from_str(s: &str) -> Result<Self> {
+        let mut params = s.split(',');
+        let mut next = |name| {
+            params
+                .next()
+                .and_then(|param| param.strip_prefix(name))
+                .and_then(|value| value.strip_prefix('='))
+                .and_then(parse_decimal)
+                .ok_or(Error::InvalidPhcString)
+        };
+        let (memory_cost, time_cost, parallelism) = (next("m")?, next("t")?, next("p")?);
+        if params.next().is_some() {
+            return Err(Error::InvalidPhcString);
+        }
+        argon2::Params::new(memory_cost, time_cost, parallelism, None)
+            .map(Self::from)
+            .map_err(Error::InvalidArgon2Params)
+    }
+}
+
+/// The unvalidated Argon2 parameters used for deserializing [`Params`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Params", rename_all = "camelCase")]
+struct RawParams {
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawParams> for Params {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(params: RawParams) -> Result<Self> {
+        argon2::Params::new(
+            params.memory_cost,
+            params.time_cost,
+            params.parallelism,
+            None,
+        )
+        .map(Self::from)
+        .map_err(Error::InvalidArgon2Params)
+    }
+}
+
 impl From<Params> for argon2::Params {
     #[inline]
     fn from(params: Params) -> Self {
@@ -129,3 +246,12 @@ impl From<argon2::Params> for Params {
         }
     }
 }
+
+/// Parses the decimal value of the PHC string format.
+///
+/// The value must not have a sign or leading zeros.
+pub fn parse_decimal(value: &str) -> Option<u32> {
+    let is_canonical =
+        value.bytes().all(|b| b.is_ascii_digit()) && (value == "0" || !value.starts_with('0'));
+    is_canonical.then(|| value.parse().ok()).flatten()
+}
@@ -0,0 +1,145 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The extended payload of version 2 of the abcrypt encrypted data format.
+//!
+//! The extended payload consists of the length of the metadata (32-bit
+//! little-endian), the metadata, the plaintext and the padding.
+
+use core::ops::Range;
+
+use crate::{Error, Padding, Result};
+
+/// The number of bytes of the length of the metadata.
+pub const METADATA_LEN_SIZE: usize = 4;
+
+/// Returns the number of bytes of the extended payload.
+pub const fn len(metadata_len: usize, plaintext_len: usize, padding: Option<Padding>) -> usize {
+    assert!(metadata_len <= u32::MAX as usize, "metadata is too long");
+    assert!(
+        plaintext_len < usize::MAX - METADATA_LEN_SIZE - metadata_len,
+        "plaintext is too long"
+    );
+    let len = METADATA_LEN_SIZE + metadata_len + plaintext_len;
+    match padding {
+        Some(padding) => padding.padded_len(len),
+        None => len + 1,
+    }
+}
+
+/// Writes the extended payload into `buf`.
+///
+/// `buf` must be [`len`] bytes long.
+pub fn write(buf: &mut [u8], metadata: &[u8], plaintext: &[u8]) {
+    let (metadata_len, rest) = buf.split_at_mut(METADATA_LEN_SIZE);
+    let len = u32::try_from(metadata.len()).expect("metadata is too long");
+    metadata_len.copy_from_slice(&len.to_le_bytes());
+    let (metadata_buf, rest) = rest.split_at_mut(metadata.len());
+    metadata_buf.copy_from_slice(metadata);
+    rest[..plaintext.len()].copy_from_slice(plaintext);
+    Padding::pad(rest, plaintext.len());
+}
+
+/// Returns the ranges of the metadata and the plaintext in the extended
+/// payload `buf`.
+pub fn parse(buf: &[u8]) -> Result<(Range<usize>, Range<usize>)> {
+    let len = Padding::unpadded_len(buf)?;
+    let metadata_len = buf[..len]
+        .first_chunk::<METADATA_LEN_SIZE>()
+        .ok_or(Error::InvalidMetadata)?;
+    let plaintext = plaintext_range(*metadata_len, len)?;
+    Ok((METADATA_LEN_SIZE..plaintext.start, plaintext))
+}
+
+/// Returns the range of the plaintext in the extended payload which is `len`
+/// bytes long without the padding and begins with `metadata_len`.
+pub fn plaintext_range(metadata_len: [u8; METADATA_LEN_SIZE], len: usize) -> Result<Range<usize>> {
+    let start = usize::try_from(u32::from_le_bytes(metadata_len))
+        .ok()
+        .and_then(|l| METADATA_LEN_SIZE.checked_add(l))
+        .filter(|&start| start <= len)
+        .ok_or(Error::InvalidMetadata)?;
+    Ok(start..len)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroUsize;
+
+    use super::*;
+
+    #[test]
+    fn len() {
+        assert_eq!(super::len(0, 0, None), 5);
+        assert_eq!(super::len(3, 14, None), 22);
+        assert_eq!(super::len(0, 14, Some(Padding::Padme)), 20);
+        assert_eq!(
+            super::len(
+                0,
+                14,
+                Some(Padding::Multiple(NonZeroUsize::new(64).unwrap()))
+            ),
+            64
+        );
+    }
+
+    #[test]
+    fn write() {
+        let mut buf = [u8::MAX; 9];
+        super::write(&mut buf, b"ab", b"c");
+        assert_eq!(buf, [0x02, 0x00, 0x00, 0x00, b'a', b'b', b'c', 0x80, 0x00]);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            super::parse(&[0x02, 0x00, 0x00, 0x00, b'a', b'b', b'c', 0x80, 0x00]).unwrap(),
+            (4..6, 6..7)
+        );
+        assert_eq!(
+            super::parse(&[0x00, 0x00, 0x00, 0x00, 0x80]).unwrap(),
+            (4..4, 4..4)
+        );
+    }
+
+    #[test]
+    fn parse_with_invalid_metadata() {
+        assert_eq!(
+            super::parse(&[0x00, 0x00, 0x00, 0x80]).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        assert_eq!(
+            super::parse(&[0x03, 0x00, 0x00, 0x00, b'a', b'b', 0x80]).unwrap_err(),
+            Error::InvalidMetadata
+        );
+    }
+
+    #[test]
+    fn plaintext_range() {
+        assert_eq!(
+            super::plaintext_range([0x02, 0x00, 0x00, 0x00], 7).unwrap(),
+            6..7
+        );
+        assert_eq!(
+            super::plaintext_range([0x00, 0x00, 0x00, 0x00], 4).unwrap(),
+            4..4
+        );
+        assert_eq!(
+            super::plaintext_range([0x03, 0x00, 0x00, 0x00], 6).unwrap_err(),
+            Error::InvalidMetadata
+        );
+        assert_eq!(
+            super::plaintext_range([0xff, 0xff, 0xff, 0xff], 8).unwrap_err(),
+            Error::InvalidMetadata
+        );
+    }
+
+    #[test]
+    fn parse_with_invalid_padding() {
+        assert_eq!(
+            super::parse(&[0x00, 0x00, 0x00, 0x00]).unwrap_err(),
+            Error::InvalidPadding
+        );
+    }
+}
@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compatibility with the scrypt encrypted data format.
+//!
+//! The abcrypt encrypted data format was modeled on the [scrypt encrypted data
+//! format] used by the [`scrypt enc`] command of Tarsnap. This module decrypts
+//! the data in the scrypt encrypted data format, which uses scrypt for the key
+//! derivation, AES-256-CTR for the encryption and HMAC-SHA-256 for the
+//! authentication, so that it can be re-encrypted into the abcrypt encrypted
+//! data format.
+//!
+//! [scrypt encrypted data format]: https://github.com/Tarsnap/scrypt/blob/1.3.3/FORMAT
+//! [`scrypt enc`]: https://www.tarsnap.com/scrypt.html
+
+use aes::{
+    Aes256,
+    cipher::{KeyIvInit, StreamCipher},
+};
+use ctr::Ctr128BE;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::{Error, Result};
+
+/// The number of bytes of the header of the scrypt encrypted data format.
+pub const HEADER_SIZE: usize = 96;
+
+/// The number of bytes of the MAC (authentication tag) of the scrypt encrypted
+/// data format.
+pub const TAG_SIZE: usize = 32;
+
+/// The magic number (file signature) of the scrypt encrypted data format.
+const MAGIC_NUMBER: &[u8] = b"scrypt";
+
+/// The version number of the scrypt encrypted data format.
+const VERSION: u8 = 0;
+
+/// The number of bytes of the derived key.
+const DERIVED_KEY_SIZE: usize = 64;
+
+/// Returns `true` if `data` starts with the magic number of the scrypt
+/// encrypted data format.
+///
+/// # Examples
+///
+/// ```
+/// let ciphertext = include_bytes!("../tests/data/scrypt/data.txt.scrypt");
+/// assert!(abcrypt::scrypt_compat::is_scrypt(ciphertext));
+///
+/// let ciphertext = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
+/// assert!(!abcrypt::scrypt_compat::is_scrypt(ciphertext));
+/// ```
+#[must_use]
+#[inline]
+pub fn is_scrypt(data: impl AsRef<[u8]>) -> bool {
+    data.as_ref().starts_with(MAGIC_NUMBER)
+}
+
+/// Decrypts `ciphertext` in the scrypt encrypted data format and into a newly
+/// allocated [`Vec`](alloc::vec::Vec).
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `ciphertext` is shorter than 128 bytes.
+/// - The magic number is invalid.
+/// - The version number is the unrecognized scrypt version number.
+/// - The scrypt parameters are invalid.
+/// - The checksum of the header is invalid.
+/// - The MAC (authentication tag) of the header is invalid.
+/// - The MAC (authentication tag) of the ciphertext is invalid.
+///
+/// # Examples
+///
+/// ```
+/// let data = b"Hello, world!\n";
+/// let ciphertext = include_bytes!("../tests/data/scrypt/data.txt.scrypt");
+/// let passphrase = "passphrase";
+///
+/// let plaintext = abcrypt::scrypt_compat::decrypt(ciphertext, passphrase).unwrap();
+/// assert_eq!(plaintext, data);
+/// ```
+#[inline]
+pub fn decrypt(
+    ciphertext: impl AsRef<[u8]>,
+    passphrase: impl AsRef<[u8]>,
+) -> Result<alloc::vec::Vec<u8>> {
+    decrypt_inner(ciphertext.as_ref(), passphrase.as_ref())
+}
+
+/// Decrypts `ciphertext` in the scrypt encrypted data format.
+fn decrypt_inner(ciphertext: &[u8], passphrase: &[u8]) -> Result<alloc::vec::Vec<u8>> {
+    if ciphertext.len() < HEADER_SIZE + TAG_SIZE {
+        return Err(Error::InvalidLength {
+            len: ciphertext.len(),
+            min: HEADER_SIZE + TAG_SIZE,
+        });
+    }
+    if !is_scrypt(ciphertext) {
+        return Err(Error::InvalidMagicNumber);
+    }
+    if ciphertext[6] != VERSION {
+        return Err(Error::UnknownVersion(ciphertext[6]));
+    }
+    let log_n = ciphertext[7];
+    let r = u32::from_be_bytes(
+        ciphertext[8..12]
+            .try_into()
+            .expect("size of `r` should be 4 bytes"),
+    );
+    let p = u32::from_be_bytes(
+        ciphertext[12..16]
+            .try_into()
+            .expect("size of `p` should be 4 bytes"),
+    );
+    let params = scrypt::Params::new(log_n, r, p, DERIVED_KEY_SIZE)
+        .map_err(|_| Error::InvalidScryptParams { log_n, r, p })?;
+    let salt = &ciphertext[16..48];
+    if Sha256::digest(&ciphertext[..48])[..16] != ciphertext[48..64] {
+        return Err(Error::InvalidHeaderMac(hmac::digest::MacError));
+    }
+
+    let mut dk = [u8::default(); DERIVED_KEY_SIZE];
+    scrypt::scrypt(passphrase, salt, &params, &mut dk).expect("derived key size should be valid");
+    let (encryption_key, mac_key) = dk.split_at(32);
+
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(mac_key)
+        .expect("HMAC-SHA-256 should accept any key length");
+    mac.update(&ciphertext[..64]);
+    mac.verify_slice(&ciphertext[64..HEADER_SIZE])?;
+
+    let (data, tag) = ciphertext.split_at(ciphertext.len() - TAG_SIZE);
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(mac_key)
+        .expect("HMAC-SHA-256 should accept any key length");
+    mac.update(data);
+    mac.verify_slice(tag).map_err(|_| Error::InvalidMac {
+        offset: HEADER_SIZE as u64,
+    })?;
+
+    let mut plaintext = data[HEADER_SIZE..].to_vec();
+    let mut cipher = Ctr128BE::<Aes256>::new(encryption_key.into(), &[u8::default(); 16].into());
+    cipher.apply_keystream(&mut plaintext);
+    Ok(plaintext)
+}
+
+/// Converts `ciphertext` in the scrypt encrypted data format into the abcrypt
+/// encrypted data format.
+///
+/// This decrypts `ciphertext` with `passphrase`, and encrypts the plaintext
+/// again with the same passphrase and the specified Argon2 parameters. The
+/// result is version 1 of the abcrypt encrypted data format.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the errors returned by [`decrypt`] occurs, or the
+/// Argon2 context is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::argon2::Params;
+/// #
+/// let data = b"Hello, world!\n";
+/// let ciphertext = include_bytes!("../tests/data/scrypt/data.txt.scrypt");
+/// let passphrase = "passphrase";
+///
+/// let params = Params::new(32, 3, 4, None).unwrap();
+/// let ciphertext = abcrypt::scrypt_compat::convert(ciphertext, passphrase, params).unwrap();
+/// # assert_eq!(abcrypt::decrypt(ciphertext, passphrase).unwrap(), data);
+/// ```
+pub fn convert(
+    ciphertext: impl AsRef<[u8]>,
+    passphrase: impl AsRef<[u8]>,
+    params: argon2::Params,
+) -> Result<alloc::vec::Vec<u8>> {
+    let plaintext = decrypt(ciphertext, passphrase.as_ref())?;
+    crate::encrypt_with_params(plaintext, passphrase, params)
+}
@@ -0,0 +1,310 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Decrypts from the abcrypt encrypted data format with random access.
+
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    ops::Range,
+    time::Duration,
+    vec::Vec,
+};
+
+use chacha20poly1305::Tag;
+
+use crate::{
+    Error, Padding, TAG_SIZE, decrypt,
+    format::{DerivedKey, Header},
+    payload::{self, METADATA_LEN_SIZE},
+    segment,
+};
+
+/// Decryptor for the abcrypt encrypted data format which supports random
+/// access.
+///
+/// This implements [`Read`] and [`Seek`] over the plaintext. Only the segments
+/// which contain the requested bytes are read from the underlying reader and
+/// authenticated, so a byte range of the plaintext can be read without
+/// decrypting from the start.
+///
+/// The payload should be segmented by [`Encryptor::with_segment_size`]. If the
+/// payload is not segmented, the whole payload is treated as a single segment,
+/// so it is decrypted at the first read.
+///
+/// The errors of the abcrypt encrypted data format are returned as
+/// [`io::Error`] with the kind [`io::ErrorKind::InvalidData`], and the
+/// original [`Error`] can be retrieved by [`io::Error::get_ref`].
+///
+/// [`Encryptor::with_segment_size`]: crate::Encryptor::with_segment_size
+#[derive(Debug)]
+pub struct SeekableDecryptor<R> {
+    inner: R,
+    header: Header,
+    dk: DerivedKey,
+    ciphertext_offset: u64,
+    ciphertext_len: usize,
+    segment_size: usize,
+    plaintext: Range<usize>,
+    pos: u64,
+    segment: Option<(usize, Vec<u8>)>,
+    now: Option<Duration>,
+}
+
+impl<R: Read + Seek> SeekableDecryptor<R> {
+    /// Creates a new `SeekableDecryptor`.
+    ///
+    /// This reads the header from the start of `inner`, and decrypts the
+    /// segments which contain the metadata and the padding if they exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if reading from `inner` fails or any of the errors
+    /// returned by [`Decryptor::new`](crate::Decryptor::new) occurs. If the
+    /// plaintext was padded or the metadata was encrypted together, this also
+    /// returns [`Err`] if the segments which contain them can not be
+    /// decrypted.
+    ///
+    /// The validity period of the encrypted data is not checked here, but when
+    /// reading the plaintext. See [`SeekableDecryptor::with_time`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{Cursor, Read, Seek, SeekFrom};
+    /// #
+    /// # use abcrypt::SeekableDecryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/segmented/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let mut cipher = SeekableDecryptor::new(Cursor::new(ciphertext), passphrase).unwrap();
+    /// cipher.seek(SeekFrom::Start(7)).unwrap();
+    /// let mut buf = [u8::default(); 5];
+    /// cipher.read_exact(&mut buf).unwrap();
+    /// assert_eq!(&buf, b"world");
+    /// ```
+    pub fn new(mut inner: R, passphrase: impl AsRef<[u8]>) -> io::Result<Self> {
+        let len = inner.seek(SeekFrom::End(0))?;
+        inner.seek(SeekFrom::Start(0))?;
+        let mut buf = vec![u8::default(); (Header::MAX_SIZE + TAG_SIZE).min(to_usize(len)?)];
+        inner.read_exact(&mut buf)?;
+        let (header, dk) = decrypt::open(&buf, passphrase.as_ref())?;
+        if header.is_record_log() {
+            return Err(Error::InvalidRecordLog.into());
+        }
+
+        // The signature is not a part of the ciphertext.
+        let (len, signature_size) = (to_usize(len)?, header.signature_size());
+        let ciphertext_len = (len - header.size()).saturating_sub(signature_size);
+        let invalid_length = |min| Error::InvalidLength {
+            len,
+            min: header.size() + min + signature_size,
+        };
+        let (segment_size, payload_len) = match header.segment_size() {
+            Some(segment_size) => (
+                segment_size,
+                segment::payload_len(ciphertext_len, segment_size).ok_or_else(|| {
+                    invalid_length(segment::min_len(ciphertext_len, segment_size))
+                })?,
+            ),
+            None if ciphertext_len < TAG_SIZE => return Err(invalid_length(TAG_SIZE).into()),
+            None => (ciphertext_len - TAG_SIZE, ciphertext_len - TAG_SIZE),
+        };
+        let mut decryptor = Self {
+            inner,
+            ciphertext_offset: header.size() as u64,
+            header,
+            dk,
+            ciphertext_len,
+            segment_size,
+            plaintext: 0..payload_len,
+            pos: u64::default(),
+            segment: None,
+            now: None,
+        };
+        if decryptor.header.has_extended_payload() {
+            decryptor.plaintext = decryptor.plaintext_range(payload_len)?;
+        }
+        Ok(decryptor)
+    }
+
+    /// Returns the number of bytes of the plaintext.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// #
+    /// # use abcrypt::SeekableDecryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/segmented/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = SeekableDecryptor::new(Cursor::new(ciphertext), passphrase).unwrap();
+    /// assert_eq!(cipher.len(), 14);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        (self.plaintext.end - self.plaintext.start) as u64
+    }
+
+    /// Returns `true` if the plaintext is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// #
+    /// # use abcrypt::SeekableDecryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/segmented/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = SeekableDecryptor::new(Cursor::new(ciphertext), passphrase).unwrap();
+    /// assert!(!cipher.is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sets the current time used to check the [`Validity`] period of the
+    /// encrypted data.
+    ///
+    /// `now` is the duration since the Unix epoch. If this is not set, the
+    /// system time is used. The validity period is checked every time the
+    /// plaintext is read, and reading fails if `now` is outside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::{io::Cursor, time::Duration};
+    /// #
+    /// # use abcrypt::SeekableDecryptor;
+    /// #
+    /// let ciphertext = include_bytes!("../tests/data/v2/segmented/data.txt.abcrypt");
+    /// let passphrase = "passphrase";
+    ///
+    /// let cipher = SeekableDecryptor::new(Cursor::new(ciphertext), passphrase)
+    ///     .unwrap()
+    ///     .with_time(Duration::from_secs(1_800_000_000));
+    /// ```
+    ///
+    /// [`Validity`]: crate::Validity
+    #[must_use]
+    #[inline]
+    pub const fn with_time(mut self, now: Duration) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Unwraps this `SeekableDecryptor`, returning the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns the range of the plaintext in the extended payload of
+    /// `payload_len` bytes.
+    fn plaintext_range(&mut self, payload_len: usize) -> io::Result<Range<usize>> {
+        let segment_size = self.segment_size;
+        let mut end = None;
+        for index in (0..self.segment_count()).rev() {
+            let segment = self.segment(index)?;
+            if segment.iter().any(|&b| b != u8::default()) {
+                end = Some(index * segment_size + Padding::unpadded_len(segment)?);
+                break;
+            }
+        }
+        let end = end.ok_or(Error::InvalidPadding)?;
+        debug_assert!(end < payload_len);
+
+        let mut metadata_len = [u8::default(); METADATA_LEN_SIZE];
+        if end < METADATA_LEN_SIZE {
+            return Err(Error::InvalidMetadata.into());
+        }
+        for (i, b) in metadata_len.iter_mut().enumerate() {
+            *b = self.segment(i / segment_size)?[i % segment_size];
+        }
+        Ok(payload::plaintext_range(metadata_len, end)?)
+    }
+
+    /// Returns the number of segments.
+    const fn segment_count(&self) -> usize {
+        self.ciphertext_len
+            .div_ceil(self.segment_size.saturating_add(TAG_SIZE))
+    }
+
+    /// Reads and decrypts the segment `index`.
+    fn segment(&mut self, index: usize) -> io::Result<&[u8]> {
+        if self.segment.as_ref().is_none_or(|(i, _)| *i != index) {
+            let encrypted_segment_size = self.segment_size.saturating_add(TAG_SIZE);
+            let start = index * encrypted_segment_size;
+            let len = encrypted_segment_size.min(self.ciphertext_len - start);
+            let mut buf = vec![u8::default(); len];
+            let offset = self.ciphertext_offset + start as u64;
+            self.inner.seek(SeekFrom::Start(offset))?;
+            self.inner.read_exact(&mut buf)?;
+
+            let tag = *Tag::from_slice(&buf[(len - TAG_SIZE)..]);
+            buf.truncate(len - TAG_SIZE);
+            let (aead, key, nonce) = (self.header.aead(), self.dk.encrypt(), self.header.nonce());
+            if self.header.segment_size().is_some() {
+                let last = index == self.segment_count() - 1;
+                segment::decrypt_segment(aead, &key, &nonce, index, last, &mut buf, &tag, offset)?;
+            } else {
+                aead.decrypt_in_place_detached(&key, &nonce, &mut buf, &tag, offset)?;
+            }
+            self.segment = Some((index, buf));
+        }
+        Ok(self
+            .segment
+            .as_ref()
+            .map(|(_, segment)| segment.as_slice())
+            .expect("segment should be decrypted"))
+    }
+}
+
+impl<R: Read + Seek> Read for SeekableDecryptor<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.header.validity().check_at(self.now)?;
+        if self.pos >= self.len() || buf.is_empty() {
+            return Ok(usize::default());
+        }
+        let offset = self.plaintext.start + to_usize(self.pos)?;
+        let (index, start) = (offset / self.segment_size, offset % self.segment_size);
+        let remaining = self.plaintext.end - offset;
+        let segment = self.segment(index)?;
+        let len = buf.len().min(segment.len() - start).min(remaining);
+        buf[..len].copy_from_slice(&segment[start..(start + len)]);
+        self.pos += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Read + Seek> Seek for SeekableDecryptor<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        };
+        let Some(pos) = pos else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ));
+        };
+        self.pos = pos;
+        Ok(pos)
+    }
+}
+
+/// Converts `n` to [`usize`].
+fn to_usize(n: u64) -> io::Result<usize> {
+    usize::try_from(n).map_err(|_| io::Error::from(io::ErrorKind::FileTooLarge))
+}
@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The segmented payload of version 2 of the abcrypt encrypted data format.
+//!
+//! The segmented payload splits the payload into segments of the fixed number
+//! of bytes, and encrypts each segment with its own authentication tag. Only
+//! the last segment can be shorter than the segment size.
+//!
+//! The segments are encrypted and decrypted in parallel if the `rayon` feature
+//! is enabled. Since each segment is independent, the output is the same as
+//! when they are processed sequentially.
+
+use chacha20poly1305::{Key, Tag, XNonce};
+#[cfg(feature = "rayon")]
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::{ParallelSlice, ParallelSliceMut},
+};
+
+use crate::{Aead, Error, Result, TAG_SIZE};
+
+/// Returns the number of segments of `payload_len` bytes of the payload.
+pub const fn count(payload_len: usize, segment_size: usize) -> usize {
+    let count = payload_len.div_ceil(segment_size);
+    if count == 0 { 1 } else { count }
+}
+
+/// Returns the number of bytes of the encrypted payload of `payload_len` bytes
+/// of the payload.
+pub const fn ciphertext_len(payload_len: usize, segment_size: usize) -> usize {
+    let count = count(payload_len, segment_size);
+    assert!(count <= usize::MAX / TAG_SIZE, "data too long");
+    let tags_len = count * TAG_SIZE;
+    assert!(payload_len <= usize::MAX - tags_len, "data too long");
+    payload_len + tags_len
+}
+
+/// Returns the number of bytes of the payload in `ciphertext_len` bytes of the
+/// encrypted payload.
+///
+/// Returns [`None`] if the encrypted payload is not a valid sequence of the
+/// segments.
+pub const fn payload_len(ciphertext_len: usize, segment_size: usize) -> Option<usize> {
+    let encrypted_segment_size = segment_size.saturating_add(TAG_SIZE);
+    let count = ciphertext_len.div_ceil(encrypted_segment_size);
+    if count == 0 || ciphertext_len - ((count - 1) * encrypted_segment_size) <= TAG_SIZE {
+        return None;
+    }
+    Some(ciphertext_len - (count * TAG_SIZE))
+}
+
+/// Returns the minimum number of bytes of the encrypted payload which has the
+/// same complete segments as `ciphertext_len` bytes of the encrypted payload.
+///
+/// If [`payload_len`] returns [`None`], this is the number of bytes which the
+/// last segment requires at least.
+pub const fn min_len(ciphertext_len: usize, segment_size: usize) -> usize {
+    let encrypted_segment_size = segment_size.saturating_add(TAG_SIZE);
+    let complete = ciphertext_len.saturating_sub(1) / encrypted_segment_size;
+    complete * encrypted_segment_size + TAG_SIZE + 1
+}
+
+/// Encrypts the payload into the segmented payload in place.
+///
+/// `buf` must be [`ciphertext_len`] bytes long, and the payload is stored in
+/// the first `payload_len` bytes of `buf`. Returns [`Err`] if the payload has
+/// too many segments or a segment is too long to be encrypted.
+pub fn encrypt(
+    aead: Aead,
+    key: &Key,
+    nonce: &XNonce,
+    buf: &mut [u8],
+    payload_len: usize,
+    segment_size: usize,
+) -> Result<()> {
+    assert_eq!(
+        buf.len(),
+        ciphertext_len(payload_len, segment_size),
+        "`buf` and the segmented payload have different lengths"
+    );
+    let count = count(payload_len, segment_size);
+    let encrypted_segment_size = segment_size.saturating_add(TAG_SIZE);
+
+    // Moves the segments backwards to make room for the tags.
+    for index in (0..count).rev() {
+        let start = index * segment_size;
+        let end = (start + segment_size).min(payload_len);
+        buf.copy_within(start..end, index * encrypted_segment_size);
+    }
+    let encrypt_segment = |(index, segment): (usize, &mut [u8])| {
+        let (segment, tag) = segment.split_at_mut(segment.len() - TAG_SIZE);
+        let last = index == count - 1;
+        let index = u32::try_from(index).map_err(|_| Error::PlaintextTooLong)?;
+        let nonce = aead.segment_nonce(nonce, index, last);
+        tag.copy_from_slice(&aead.encrypt_in_place_detached(key, &nonce, segment)?);
+        Ok(())
+    };
+    #[cfg(feature = "rayon")]
+    return buf
+        .par_chunks_mut(encrypted_segment_size)
+        .enumerate()
+        .try_for_each(encrypt_segment);
+    #[cfg(not(feature = "rayon"))]
+    buf.chunks_mut(encrypted_segment_size)
+        .enumerate()
+        .try_for_each(encrypt_segment)
+}
+
+/// Decrypts the segmented payload `ciphertext` into `buf`.
+///
+/// `buf` must be [`payload_len`] bytes long, and `offset` is the byte offset
+/// of `ciphertext` in the encrypted data.
+pub fn decrypt(
+    aead: Aead,
+    key: &Key,
+    nonce: &XNonce,
+    ciphertext: &[u8],
+    buf: &mut [u8],
+    segment_size: usize,
+    offset: u64,
+) -> Result<()> {
+    let encrypted_segment_size = segment_size.saturating_add(TAG_SIZE);
+    let count = ciphertext.len().div_ceil(encrypted_segment_size);
+    let decrypt = |(index, (segment, buf)): (usize, (&[u8], &mut [u8]))| {
+        let (segment, tag) = segment.split_at(segment.len() - TAG_SIZE);
+        buf.copy_from_slice(segment);
+        decrypt_segment(
+            aead,
+            key,
+            nonce,
+            index,
+            index == count - 1,
+            buf,
+            Tag::from_slice(tag),
+            offset + (index * encrypted_segment_size) as u64,
+        )
+    };
+    // Reports the first corrupted segment, regardless of the order in which the
+    // segments are decrypted.
+    #[cfg(feature = "rayon")]
+    return ciphertext
+        .par_chunks(encrypted_segment_size)
+        .zip(buf.par_chunks_mut(segment_size))
+        .enumerate()
+        .map(decrypt)
+        .find_first(Result::is_err)
+        .unwrap_or(Ok(()));
+    #[cfg(not(feature = "rayon"))]
+    ciphertext
+        .chunks(encrypted_segment_size)
+        .zip(buf.chunks_mut(segment_size))
+        .enumerate()
+        .try_for_each(decrypt)
+}
+
+/// Decrypts the segment `index` in place.
+///
+/// `offset` is the byte offset of the segment in the encrypted data.
+#[allow(clippy::too_many_arguments)]
+pub fn decrypt_segment(
+    aead: Aead,
+    key: &Key,
+    nonce: &XNonce,
+    index: usize,
+    last: bool,
+    buf: &mut [u8],
+    tag: &Tag,
+    offset: u64,
+) -> Result<()> {
+    // The segments after the maximum index are never produced when encrypting,
+    // so they can not be authenticated.
+    let index = u32::try_from(index).map_err(|_| Error::InvalidMac { offset })?;
+    let nonce = aead.segment_nonce(nonce, index, last);
+    aead.decrypt_in_place_detached(key, &nonce, buf, tag, offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count() {
+        assert_eq!(super::count(0, 4), 1);
+        assert_eq!(super::count(1, 4), 1);
+        assert_eq!(super::count(4, 4), 1);
+        assert_eq!(super::count(5, 4), 2);
+    }
+
+    #[test]
+    fn ciphertext_len() {
+        assert_eq!(super::ciphertext_len(0, 4), 16);
+        assert_eq!(super::ciphertext_len(4, 4), 20);
+        assert_eq!(super::ciphertext_len(5, 4), 37);
+    }
+
+    #[test]
+    fn payload_len() {
+        assert_eq!(super::payload_len(17, 4), Some(1));
+        assert_eq!(super::payload_len(20, 4), Some(4));
+        assert_eq!(super::payload_len(37, 4), Some(5));
+        assert_eq!(super::payload_len(40, 4), Some(8));
+        assert_eq!(super::payload_len(17, u32::MAX as usize), Some(1));
+    }
+
+    #[test]
+    fn payload_len_with_invalid_length() {
+        assert_eq!(super::payload_len(0, 4), None);
+        assert_eq!(super::payload_len(16, 4), None);
+        assert_eq!(super::payload_len(36, 4), None);
+    }
+
+    #[test]
+    fn min_len() {
+        assert_eq!(super::min_len(0, 4), 17);
+        assert_eq!(super::min_len(16, 4), 17);
+        assert_eq!(super::min_len(20, 4), 17);
+        assert_eq!(super::min_len(36, 4), 37);
+        assert_eq!(super::min_len(40, 4), 37);
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let payload = b"Hello, world!\n";
+        let mut buf = [u8::default(); 14 + 4 * TAG_SIZE];
+        buf[..payload.len()].copy_from_slice(payload);
+        super::encrypt(Aead::default(), &key, &nonce, &mut buf, payload.len(), 4).unwrap();
+        assert_eq!(super::payload_len(buf.len(), 4), Some(payload.len()));
+
+        let mut plaintext = [u8::default(); 14];
+        super::decrypt(Aead::default(), &key, &nonce, &buf, &mut plaintext, 4, 0).unwrap();
+        assert_eq!(&plaintext, payload);
+    }
+
+    #[test]
+    fn encrypt_each_segment() {
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let payload = b"Hello, world!\n";
+        let mut buf = [u8::default(); 14 + 4 * TAG_SIZE];
+        buf[..payload.len()].copy_from_slice(payload);
+        super::encrypt(Aead::default(), &key, &nonce, &mut buf, payload.len(), 4).unwrap();
+
+        // Each segment is the same as the one encrypted on its own.
+        for (index, chunk) in payload.chunks(4).enumerate() {
+            let mut segment = [u8::default(); 4];
+            let segment = &mut segment[..chunk.len()];
+            segment.copy_from_slice(chunk);
+            let index_u32 = u32::try_from(index).unwrap();
+            let nonce = Aead::default().segment_nonce(&nonce, index_u32, index == 3);
+            let tag = Aead::default()
+                .encrypt_in_place_detached(&key, &nonce, segment)
+                .unwrap();
+            let start = index * (4 + TAG_SIZE);
+            assert_eq!(buf[start..(start + chunk.len())], *segment);
+            assert_eq!(
+                buf[(start + chunk.len())..(start + chunk.len() + TAG_SIZE)],
+                *tag
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn round_trip_in_thread_pool() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let payload = [u8::MAX; 1024];
+        let mut buf = [u8::default(); 1024 + 64 * TAG_SIZE];
+        buf[..payload.len()].copy_from_slice(&payload);
+        let mut expected = buf;
+        super::encrypt(
+            Aead::default(),
+            &key,
+            &nonce,
+            &mut expected,
+            payload.len(),
+            16,
+        )
+        .unwrap();
+        pool.install(|| super::encrypt(Aead::default(), &key, &nonce, &mut buf, payload.len(), 16))
+            .unwrap();
+        assert_eq!(buf, expected);
+
+        let mut plaintext = [u8::default(); 1024];
+        pool.install(|| super::decrypt(Aead::default(), &key, &nonce, &buf, &mut plaintext, 16, 0))
+            .unwrap();
+        assert_eq!(plaintext, payload);
+    }
+
+    #[test]
+    fn decrypt_with_multiple_corrupted_segments() {
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let mut buf = [u8::default(); 1024 + 64 * TAG_SIZE];
+        super::encrypt(Aead::default(), &key, &nonce, &mut buf, 1024, 16).unwrap();
+        buf[3 * (16 + TAG_SIZE)] ^= 1;
+        buf[40 * (16 + TAG_SIZE)] ^= 1;
+
+        // The first corrupted segment is always reported.
+        let mut plaintext = [u8::default(); 1024];
+        assert_eq!(
+            super::decrypt(Aead::default(), &key, &nonce, &buf, &mut plaintext, 16, 0).unwrap_err(),
+            Error::InvalidMac {
+                offset: (3 * (16 + TAG_SIZE)) as u64
+            }
+        );
+    }
+
+    #[test]
+    fn decrypt_with_reordered_segments() {
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let mut buf = [u8::default(); 8 + 2 * TAG_SIZE];
+        buf[..8].copy_from_slice(b"abcdefgh");
+        super::encrypt(Aead::default(), &key, &nonce, &mut buf, 8, 4).unwrap();
+        let (first, second) = buf.split_at_mut(4 + TAG_SIZE);
+        first.swap_with_slice(second);
+
+        let mut plaintext = [u8::default(); 8];
+        assert_eq!(
+            super::decrypt(Aead::default(), &key, &nonce, &buf, &mut plaintext, 4, 0).unwrap_err(),
+            Error::InvalidMac { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn decrypt_with_truncated_segments() {
+        let key = Key::default();
+        let nonce = XNonce::default();
+        let mut buf = [u8::default(); 8 + 2 * TAG_SIZE];
+        buf[..8].copy_from_slice(b"abcdefgh");
+        super::encrypt(Aead::default(), &key, &nonce, &mut buf, 8, 4).unwrap();
+
+        // The first segment is not marked as the last segment.
+        let mut plaintext = [u8::default(); 4];
+        assert_eq!(
+            super::decrypt(
+                Aead::default(),
+                &key,
+                &nonce,
+                &buf[..(4 + TAG_SIZE)],
+                &mut plaintext,
+                4,
+                148
+            )
+            .unwrap_err(),
+            Error::InvalidMac { offset: 148 }
+        );
+    }
+}
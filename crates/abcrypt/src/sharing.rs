@@ -0,0 +1,654 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Shamir's secret sharing of the file key.
+//!
+//! Instead of the passphrase, the data is encrypted with a random [`FileKey`]
+//! by [`Encryptor::with_file_key`](crate::Encryptor::with_file_key). The file
+//! key is split into `count` key shares by [`split`], any `threshold` of which
+//! recover the file key by [`combine`], while fewer than `threshold` key
+//! shares reveal nothing about it. Each byte of the file key is shared
+//! independently with a random polynomial of degree `threshold - 1` over
+//! GF(2<sup>8</sup>).
+//!
+//! The encrypted data records [`Kdf::FileKey`](crate::Kdf::FileKey) as the key
+//! derivation function, so it is told apart from the data encrypted with the
+//! passphrase.
+//!
+//! Each key share has the key identifier of the file key, so the key shares
+//! of different file keys are not mixed up, and the recovered file key is
+//! verified against it.
+//!
+//! # Examples
+//!
+//! ```
+//! # use abcrypt::{
+//! #     Decryptor, Encryptor,
+//! #     sharing::{self, FileKey},
+//! # };
+//! #
+//! let data = b"Hello, world!\n";
+//!
+//! let key = FileKey::generate();
+//! let ciphertext = Encryptor::with_file_key(data, &key).encrypt_to_vec();
+//! let shares = sharing::split(&key, 3, 5).unwrap();
+//!
+//! let key = sharing::combine(&shares[1..4]).unwrap();
+//! let plaintext = Decryptor::with_file_key(&ciphertext, &key)
+//!     .unwrap()
+//!     .decrypt_to_vec()
+//!     .unwrap();
+//! assert_eq!(plaintext, data);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use blake2::{Blake2bMac512, digest::Mac};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{Error, KeyId, Result, armor, format::DerivedKey};
+
+/// The line that marks the beginning of the armored key share.
+pub const BEGIN_LINE: &str = "-----BEGIN ABCRYPT KEY SHARE-----";
+
+/// The line that marks the end of the armored key share.
+pub const END_LINE: &str = "-----END ABCRYPT KEY SHARE-----";
+
+/// A random key which encrypts the data instead of the passphrase.
+///
+/// The file key is uniformly random, so it is not stretched by a costly key
+/// derivation function. The [`Debug`] representation does not show the key.
+#[derive(Clone, Eq, PartialEq)]
+pub struct FileKey([u8; Self::SIZE]);
+
+impl FileKey {
+    /// The number of bytes of the file key.
+    pub const SIZE: usize = 32;
+
+    /// Generates a new random `FileKey`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::sharing::FileKey;
+    /// #
+    /// assert_ne!(FileKey::generate(), FileKey::generate());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn generate() -> Self {
+        Self(StdRng::from_entropy().r#gen())
+    }
+
+    /// Returns the bytes of this file key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::sharing::FileKey;
+    /// #
+    /// let key = FileKey::from([0x01; 32]);
+    /// assert_eq!(key.as_bytes(), &[0x01; 32]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; Self::SIZE] {
+        &self.0
+    }
+
+    /// Returns the key identifier of this file key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::{KeyId, sharing::FileKey};
+    /// #
+    /// let key = FileKey::from([0x01; 32]);
+    /// assert_eq!(key.key_id(), KeyId::new([0x01; 32]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn key_id(&self) -> KeyId {
+        KeyId::new(self.0)
+    }
+
+    /// Expands this file key into the derived key with `salt`.
+    ///
+    /// The derived key is the first 96 bytes of the concatenation of
+    /// BLAKE2b-512-MAC of `salt` followed by the one-byte counter 1 and 2,
+    /// keyed with this file key.
+    pub(crate) fn expand(&self, salt: &[u8]) -> DerivedKey {
+        let mut dk = [u8::default(); DerivedKey::SIZE];
+        for (counter, chunk) in (1_u8..).zip(dk.chunks_mut(64)) {
+            let mut mac = Blake2bMac512::new_with_salt_and_personal(&self.0, &[], &[])
+                .expect("key size should be valid");
+            mac.update(salt);
+            mac.update(&[counter]);
+            chunk.copy_from_slice(&mac.finalize().into_bytes()[..chunk.len()]);
+        }
+        DerivedKey::new(dk)
+    }
+}
+
+impl fmt::Debug for FileKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FileKey").finish_non_exhaustive()
+    }
+}
+
+impl From<[u8; Self::SIZE]> for FileKey {
+    #[inline]
+    fn from(bytes: [u8; Self::SIZE]) -> Self {
+        Self(bytes)
+    }
+}
+
+/// A key share of the [`FileKey`].
+///
+/// The key share is encoded as the threshold, the index, the key identifier of
+/// the file key and the share of each byte of the file key, in that order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Share {
+    threshold: u8,
+    index: u8,
+    key_id: KeyId,
+    value: [u8; FileKey::SIZE],
+}
+
+impl Share {
+    /// The number of bytes of the encoded key share.
+    pub const SIZE: usize = 2 + KeyId::SIZE + FileKey::SIZE;
+
+    /// Returns the number of the key shares required to recover the file key.
+    #[must_use]
+    #[inline]
+    pub const fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Returns the index of this key share, which starts at 1.
+    #[must_use]
+    #[inline]
+    pub const fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the key identifier of the file key.
+    #[must_use]
+    #[inline]
+    pub const fn key_id(&self) -> KeyId {
+        self.key_id
+    }
+
+    /// Encodes this key share into bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::sharing::{self, FileKey, Share};
+    /// #
+    /// let shares = sharing::split(&FileKey::generate(), 2, 3).unwrap();
+    ///
+    /// let bytes = shares[0].to_bytes();
+    /// assert_eq!(Share::from_bytes(bytes).unwrap(), shares[0]);
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut buf = [u8::default(); Self::SIZE];
+        buf[0] = self.threshold;
+        buf[1] = self.index;
+        buf[2..][..KeyId::SIZE].copy_from_slice(self.key_id.as_bytes());
+        buf[2 + KeyId::SIZE..].copy_from_slice(&self.value);
+        buf
+    }
+
+    /// Decodes `bytes` into the key share.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `bytes` is not 42 bytes, or the threshold or the
+    /// index is zero.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>) -> Result<Self> {
+        let bytes: &[u8; Self::SIZE] =
+            bytes.as_ref().try_into().map_err(|_| Error::InvalidShare)?;
+        let (threshold, index) = (bytes[0], bytes[1]);
+        if threshold == 0 || index == 0 {
+            return Err(Error::InvalidShare);
+        }
+        let mut key_id = [u8::default(); KeyId::SIZE];
+        key_id.copy_from_slice(&bytes[2..][..KeyId::SIZE]);
+        let mut value = [u8::default(); FileKey::SIZE];
+        value.copy_from_slice(&bytes[2 + KeyId::SIZE..]);
+        Ok(Self {
+            threshold,
+            index,
+            key_id: key_id.into(),
+            value,
+        })
+    }
+
+    /// Encodes this key share into the armored key share with the checksum.
+    ///
+    /// The armored key share is enclosed between the [`BEGIN_LINE`] and the
+    /// [`END_LINE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use abcrypt::sharing::{self, FileKey, Share};
+    /// #
+    /// let shares = sharing::split(&FileKey::generate(), 2, 3).unwrap();
+    ///
+    /// let armored = shares[0].to_armored();
+    /// assert!(armored.starts_with("-----BEGIN ABCRYPT KEY SHARE-----\n"));
+    /// assert_eq!(Share::from_armored(armored).unwrap(), shares[0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_armored(&self) -> String {
+        armor::encode_with_lines(&self.to_bytes(), BEGIN_LINE, END_LINE)
+    }
+
+    /// Decodes the armored key share `armored`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if any of the following are true:
+    ///
+    /// - The ASCII armor is invalid.
+    /// - The decoded key share is invalid.
+    #[inline]
+    pub fn from_armored(armored: impl AsRef<[u8]>) -> Result<Self> {
+        armor::decode_with_lines(armored.as_ref(), BEGIN_LINE, END_LINE).and_then(Self::from_bytes)
+    }
+}
+
+/// Splits `key` into `count` key shares, any `threshold` of which recover
+/// `key`.
+///
+/// The index of the key shares starts at 1.
+///
+/// # Errors
+///
+/// Returns [`Err`] if `threshold` is zero or greater than `count`.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::sharing::{self, FileKey};
+/// #
+/// let shares = sharing::split(&FileKey::generate(), 3, 5).unwrap();
+/// assert_eq!(shares.len(), 5);
+/// assert!(shares.iter().all(|s| s.threshold() == 3));
+///
+/// assert!(sharing::split(&FileKey::generate(), 6, 5).is_err());
+/// ```
+pub fn split(key: &FileKey, threshold: u8, count: u8) -> Result<Vec<Share>> {
+    if threshold == 0 || threshold > count {
+        return Err(Error::InvalidSharingParams { threshold, count });
+    }
+    let key_id = key.key_id();
+    let mut shares = (1..=count)
+        .map(|index| Share {
+            threshold,
+            index,
+            key_id,
+            value: [u8::default(); FileKey::SIZE],
+        })
+        .collect::<Vec<_>>();
+    let mut rng = StdRng::from_entropy();
+    let mut coefficients = vec![u8::default(); usize::from(threshold)];
+    for (i, &secret) in key.as_bytes().iter().enumerate() {
+        coefficients[0] = secret;
+        rng.fill(&mut coefficients[1..]);
+        for share in &mut shares {
+            share.value[i] = gf256::evaluate(&coefficients, share.index);
+        }
+    }
+    Ok(shares)
+}
+
+/// Recovers the file key from `shares`.
+///
+/// The duplicate key shares are ignored, and only the first threshold
+/// distinct key shares are used.
+///
+/// # Errors
+///
+/// Returns [`Err`] if any of the following are true:
+///
+/// - `shares` have the different thresholds or key identifiers.
+/// - `shares` have the same index with the different values.
+/// - There are fewer distinct key shares than the threshold.
+/// - The key identifier of the recovered file key does not match the one of
+///   `shares`, which means any of `shares` is corrupted.
+///
+/// # Examples
+///
+/// ```
+/// # use abcrypt::sharing::{self, FileKey};
+/// #
+/// let key = FileKey::generate();
+/// let shares = sharing::split(&key, 3, 5).unwrap();
+///
+/// assert_eq!(sharing::combine(&shares[2..]).unwrap(), key);
+/// assert!(sharing::combine(&shares[3..]).is_err());
+/// ```
+pub fn combine(shares: &[Share]) -> Result<FileKey> {
+    let Some(first) = shares.first() else {
+        return Err(Error::InsufficientShares {
+            threshold: 1,
+            count: usize::default(),
+        });
+    };
+    let threshold = usize::from(first.threshold);
+    let mut distinct = Vec::<&Share>::with_capacity(threshold);
+    for share in shares {
+        if share.threshold != first.threshold || share.key_id != first.key_id {
+            return Err(Error::InvalidShare);
+        }
+        if let Some(s) = distinct.iter().find(|s| s.index == share.index) {
+            if s.value != share.value {
+                return Err(Error::InvalidShare);
+            }
+        } else if distinct.len() < threshold {
+            distinct.push(share);
+        }
+    }
+    if distinct.len() < threshold {
+        return Err(Error::InsufficientShares {
+            threshold: first.threshold,
+            count: distinct.len(),
+        });
+    }
+
+    let mut key = [u8::default(); FileKey::SIZE];
+    for (i, share) in distinct.iter().enumerate() {
+        // The Lagrange basis polynomial of this key share evaluated at zero.
+        let basis =
+            distinct
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(1, |acc, (_, other)| {
+                    gf256::mul(
+                        acc,
+                        gf256::mul(other.index, gf256::inv(other.index ^ share.index)),
+                    )
+                });
+        for (k, &y) in key.iter_mut().zip(&share.value) {
+            *k ^= gf256::mul(y, basis);
+        }
+    }
+    let key = FileKey(key);
+    if key.key_id() == first.key_id {
+        Ok(key)
+    } else {
+        Err(Error::InvalidShare)
+    }
+}
+
+/// Arithmetic over GF(2<sup>8</sup>) with the irreducible polynomial
+/// x<sup>8</sup> + x<sup>4</sup> + x<sup>3</sup> + x + 1.
+///
+/// The operations do not branch on the values, so they run in constant time.
+mod gf256 {
+    /// Multiplies `a` by `b`.
+    pub fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = u8::default();
+        for _ in 0..8 {
+            product ^= a & (b & 1).wrapping_neg();
+            let carry = (a >> 7).wrapping_neg();
+            a = (a << 1) ^ (0x1b & carry);
+            b >>= 1;
+        }
+        product
+    }
+
+    /// Returns the multiplicative inverse of `a`, or zero if `a` is zero.
+    ///
+    /// This computes a<sup>254</sup>.
+    pub fn inv(a: u8) -> u8 {
+        let a2 = mul(a, a);
+        let a3 = mul(a2, a);
+        let a6 = mul(a3, a3);
+        let a12 = mul(a6, a6);
+        let a15 = mul(a12, a3);
+        let a30 = mul(a15, a15);
+        let a60 = mul(a30, a30);
+        let a63 = mul(a60, a3);
+        let a126 = mul(a63, a63);
+        let a127 = mul(a126, a);
+        mul(a127, a127)
+    }
+
+    /// Evaluates the polynomial with `coefficients` in ascending order of
+    /// degree at `x`.
+    pub fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+        coefficients
+            .iter()
+            .rev()
+            .fold(u8::default(), |acc, &c| mul(acc, x) ^ c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gf256_mul() {
+        // The example in FIPS 197 Section 4.2.
+        assert_eq!(gf256::mul(0x57, 0x83), 0xc1);
+        assert_eq!(gf256::mul(0x57, 0x13), 0xfe);
+        assert_eq!(gf256::mul(0x00, 0xff), 0x00);
+        assert_eq!(gf256::mul(0x01, 0xff), 0xff);
+    }
+
+    #[test]
+    fn gf256_inv() {
+        assert_eq!(gf256::inv(0x00), 0x00);
+        for a in 1..=u8::MAX {
+            assert_eq!(gf256::mul(a, gf256::inv(a)), 0x01);
+        }
+    }
+
+    #[test]
+    fn gf256_evaluate() {
+        assert_eq!(gf256::evaluate(&[0x2a], 0x05), 0x2a);
+        assert_eq!(gf256::evaluate(&[0x2a, 0x01], 0x05), 0x2a ^ 0x05);
+        assert_eq!(
+            gf256::evaluate(&[0x2a, 0x00, 0x01], 0x05),
+            0x2a ^ gf256::mul(0x05, 0x05)
+        );
+    }
+
+    #[test]
+    fn debug_file_key() {
+        assert_eq!(format!("{:?}", FileKey::from([0x01; 32])), "FileKey(..)");
+    }
+
+    #[test]
+    fn expand_file_key() {
+        let key = FileKey::from([0x01; 32]);
+        let dk = key.expand(&[0x02; 32]);
+        assert_eq!(dk.encrypt(), key.expand(&[0x02; 32]).encrypt());
+        assert_eq!(dk.mac(), key.expand(&[0x02; 32]).mac());
+        assert_ne!(dk.encrypt(), key.expand(&[0x03; 32]).encrypt());
+        assert_ne!(
+            dk.mac(),
+            FileKey::from([0x04; 32]).expand(&[0x02; 32]).mac()
+        );
+        assert_ne!(dk.encrypt().as_slice(), &dk.mac()[..32]);
+    }
+
+    #[test]
+    fn split_and_combine() {
+        let key = FileKey::generate();
+        let shares = split(&key, 3, 5).unwrap();
+        assert_eq!(
+            shares.iter().map(Share::index).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5]
+        );
+        assert!(shares.iter().all(|s| s.key_id() == key.key_id()));
+        for i in 0..5 {
+            for j in 0..5 {
+                for k in 0..5 {
+                    if i == j || j == k || k == i {
+                        continue;
+                    }
+                    let subset = [shares[i].clone(), shares[j].clone(), shares[k].clone()];
+                    assert_eq!(combine(&subset).unwrap(), key);
+                }
+            }
+        }
+        assert_eq!(combine(&shares).unwrap(), key);
+    }
+
+    #[test]
+    fn split_with_edge_params() {
+        let key = FileKey::generate();
+
+        let shares = split(&key, 1, 1).unwrap();
+        assert_eq!(shares[0].value, *key.as_bytes());
+        assert_eq!(combine(&shares).unwrap(), key);
+
+        let shares = split(&key, u8::MAX, u8::MAX).unwrap();
+        assert_eq!(shares.len(), 255);
+        assert_eq!(combine(&shares).unwrap(), key);
+    }
+
+    #[test]
+    fn split_with_invalid_params() {
+        let key = FileKey::generate();
+        assert_eq!(
+            split(&key, 0, 5).unwrap_err(),
+            Error::InvalidSharingParams {
+                threshold: 0,
+                count: 5
+            }
+        );
+        assert_eq!(
+            split(&key, 6, 5).unwrap_err(),
+            Error::InvalidSharingParams {
+                threshold: 6,
+                count: 5
+            }
+        );
+    }
+
+    #[test]
+    fn combine_with_insufficient_shares() {
+        let shares = split(&FileKey::generate(), 3, 5).unwrap();
+        assert_eq!(
+            combine(&[]).unwrap_err(),
+            Error::InsufficientShares {
+                threshold: 1,
+                count: 0
+            }
+        );
+        assert_eq!(
+            combine(&shares[..2]).unwrap_err(),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), shares[0].clone()]).unwrap_err(),
+            Error::InsufficientShares {
+                threshold: 3,
+                count: 2
+            }
+        );
+    }
+
+    #[test]
+    fn combine_with_invalid_shares() {
+        let shares = split(&FileKey::generate(), 3, 5).unwrap();
+        let others = split(&FileKey::generate(), 3, 5).unwrap();
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), others[2].clone()]).unwrap_err(),
+            Error::InvalidShare
+        );
+
+        let mut corrupted = shares[2].clone();
+        corrupted.value[0] ^= 0x01;
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), corrupted.clone()]).unwrap_err(),
+            Error::InvalidShare
+        );
+        assert_eq!(
+            combine(&[shares[2].clone(), corrupted]).unwrap_err(),
+            Error::InvalidShare
+        );
+
+        let mut mismatched = shares[2].clone();
+        mismatched.threshold = 2;
+        assert_eq!(
+            combine(&[shares[0].clone(), shares[1].clone(), mismatched]).unwrap_err(),
+            Error::InvalidShare
+        );
+    }
+
+    #[test]
+    fn share_bytes() {
+        let shares = split(&FileKey::from([0x01; 32]), 2, 3).unwrap();
+        let bytes = shares[1].to_bytes();
+        assert_eq!(bytes.len(), Share::SIZE);
+        assert_eq!(bytes[..2], [2, 2]);
+        assert_eq!(bytes[2..10], *KeyId::new([0x01; 32]).as_bytes());
+        assert_eq!(Share::from_bytes(bytes).unwrap(), shares[1]);
+    }
+
+    #[test]
+    fn share_from_invalid_bytes() {
+        let bytes = split(&FileKey::generate(), 2, 3).unwrap()[0].to_bytes();
+        assert_eq!(
+            Share::from_bytes(&bytes[..41]).unwrap_err(),
+            Error::InvalidShare
+        );
+        assert_eq!(
+            Share::from_bytes([bytes.as_slice(), &[0x00]].concat()).unwrap_err(),
+            Error::InvalidShare
+        );
+
+        let mut zero_threshold = bytes;
+        zero_threshold[0] = 0;
+        assert_eq!(
+            Share::from_bytes(zero_threshold).unwrap_err(),
+            Error::InvalidShare
+        );
+
+        let mut zero_index = bytes;
+        zero_index[1] = 0;
+        assert_eq!(
+            Share::from_bytes(zero_index).unwrap_err(),
+            Error::InvalidShare
+        );
+    }
+
+    #[test]
+    fn share_armored() {
+        let share = split(&FileKey::generate(), 2, 3).unwrap().remove(0);
+        let armored = share.to_armored();
+        let lines = armored.lines().collect::<Vec<_>>();
+        assert_eq!(lines.first().unwrap(), &BEGIN_LINE);
+        assert_eq!(lines.last().unwrap(), &END_LINE);
+        assert!(lines[lines.len() - 2].starts_with('='));
+        assert_eq!(Share::from_armored(&armored).unwrap(), share);
+
+        assert_eq!(
+            Share::from_armored(crate::armor::encode(share.to_bytes())).unwrap_err(),
+            Error::InvalidArmor
+        );
+        assert_eq!(
+            Share::from_armored(armor::encode_with_lines(&[0x00; 41], BEGIN_LINE, END_LINE))
+                .unwrap_err(),
+            Error::InvalidShare
+        );
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The signature of the encrypted data.
+//!
+//! The signature is Ed25519ph with the context string "abcrypt" over the
+//! header and the ciphertext, and is appended to the ciphertext. Since the
+//! message is prehashed with SHA-512, the header and the ciphertext do not
+//! have to be contiguous.
+
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+use sha2::{Digest, Sha512};
+
+use crate::{Error, Result};
+
+/// The context string of Ed25519ph.
+const CONTEXT: &[u8] = b"abcrypt";
+
+/// Returns the SHA-512 hash of `header` and `ciphertext`.
+fn prehash(header: &[u8], ciphertext: &[u8]) -> Sha512 {
+    let mut digest = Sha512::new();
+    digest.update(header);
+    digest.update(ciphertext);
+    digest
+}
+
+/// Signs `header` and `ciphertext` with `key`.
+pub fn sign(key: &SigningKey, header: &[u8], ciphertext: &[u8]) -> Signature {
+    key.sign_prehashed(prehash(header, ciphertext), Some(CONTEXT))
+        .expect("context should be at most 255 bytes")
+}
+
+/// Verifies `signature` of `header` and `ciphertext` with `key`.
+pub fn verify(
+    key: &VerifyingKey,
+    header: &[u8],
+    ciphertext: &[u8],
+    signature: &[u8],
+) -> Result<()> {
+    let signature = Signature::from_slice(signature).map_err(|_| Error::InvalidSignature)?;
+    key.verify_prehashed_strict(prehash(header, ciphertext), Some(CONTEXT), &signature)
+        .map_err(|_| Error::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &[u8] = b"header";
+    const CIPHERTEXT: &[u8] = b"ciphertext";
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_bytes(&[1; 32])
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let key = signing_key();
+        let signature = sign(&key, HEADER, CIPHERTEXT);
+        assert!(
+            verify(
+                &key.verifying_key(),
+                HEADER,
+                CIPHERTEXT,
+                &signature.to_bytes()
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn verify_with_moved_boundary() {
+        let key = signing_key();
+        let signature = sign(&key, HEADER, CIPHERTEXT);
+        assert!(
+            verify(
+                &key.verifying_key(),
+                b"headerc",
+                b"iphertext",
+                &signature.to_bytes()
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn verify_with_tampered_ciphertext() {
+        let key = signing_key();
+        let signature = sign(&key, HEADER, CIPHERTEXT);
+        assert_eq!(
+            verify(
+                &key.verifying_key(),
+                HEADER,
+                b"ciphertexu",
+                &signature.to_bytes()
+            )
+            .unwrap_err(),
+            Error::InvalidSignature
+        );
+    }
+
+    #[test]
+    fn verify_with_wrong_key() {
+        let signature = sign(&signing_key(), HEADER, CIPHERTEXT);
+        let key = SigningKey::from_bytes(&[2; 32]).verifying_key();
+        assert_eq!(
+            verify(&key, HEADER, CIPHERTEXT, &signature.to_bytes()).unwrap_err(),
+            Error::InvalidSignature
+        );
+    }
+
+    #[test]
+    fn verify_with_invalid_length() {
+        let key = signing_key();
+        assert_eq!(
+            verify(
+                &key.verifying_key(),
+                HEADER,
+                CIPHERTEXT,
+                &[u8::default(); 63]
+            )
+            .unwrap_err(),
+            Error::InvalidSignature
+        );
+    }
+}
@@ -0,0 +1,245 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The validity period of the encrypted data.
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use crate::{Error, Result};
+
+/// The validity period of the encrypted data.
+///
+/// The validity period consists of the optional not-before and not-after
+/// timestamps, which are the durations since the Unix epoch with a precision
+/// of one second. It is stored in the header and authenticated by the MAC of
+/// the header, so it cannot be modified without the passphrase. Decrypting the
+/// data outside the validity period fails with [`Error::Expired`].
+///
+/// # Examples
+///
+/// ```
+/// # use core::time::Duration;
+/// #
+/// # use abcrypt::Validity;
+/// #
+/// let validity = Validity::new().with_not_after(Duration::from_secs(1_800_000_000));
+/// assert!(validity.check(Duration::from_secs(1_700_000_000)).is_ok());
+/// assert!(validity.check(Duration::from_secs(1_900_000_000)).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Validity {
+    not_before: Option<Duration>,
+    not_after: Option<Duration>,
+}
+
+impl Validity {
+    /// The number of bytes of the encoded validity period.
+    pub(crate) const SIZE: usize = 16;
+
+    /// Creates a new `Validity` without any bounds.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            not_before: None,
+            not_after: None,
+        }
+    }
+
+    /// Sets the time before which the encrypted data is not valid.
+    ///
+    /// `time` is the duration since the Unix epoch, and is truncated to whole
+    /// seconds.
+    #[must_use]
+    #[inline]
+    pub const fn with_not_before(mut self, time: Duration) -> Self {
+        self.not_before = Some(Duration::from_secs(time.as_secs()));
+        self
+    }
+
+    /// Sets the time after which the encrypted data is not valid.
+    ///
+    /// `time` is the duration since the Unix epoch, and is truncated to whole
+    /// seconds.
+    #[must_use]
+    #[inline]
+    pub const fn with_not_after(mut self, time: Duration) -> Self {
+        self.not_after = Some(Duration::from_secs(time.as_secs()));
+        self
+    }
+
+    /// Returns the time before which the encrypted data is not valid.
+    #[must_use]
+    #[inline]
+    pub const fn not_before(&self) -> Option<Duration> {
+        self.not_before
+    }
+
+    /// Returns the time after which the encrypted data is not valid.
+    #[must_use]
+    #[inline]
+    pub const fn not_after(&self) -> Option<Duration> {
+        self.not_after
+    }
+
+    /// Returns `true` if this validity period has neither bound.
+    #[must_use]
+    #[inline]
+    pub const fn is_unbounded(&self) -> bool {
+        self.not_before.is_none() && self.not_after.is_none()
+    }
+
+    /// Checks that `now` is within this validity period.
+    ///
+    /// `now` is the duration since the Unix epoch. Both bounds are inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if `now` is before the not-before time or after the
+    /// not-after time.
+    pub fn check(&self, now: Duration) -> Result<()> {
+        let too_early = self.not_before.is_some_and(|t| now < t);
+        let too_late = self.not_after.is_some_and(|t| now > t);
+        if too_early || too_late {
+            Err(Error::Expired)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that `now` is within this validity period, using the system
+    /// time if `now` is [`None`].
+    ///
+    /// Without the `std` feature, the system time is not available, so this
+    /// returns [`Err`] if this validity period is bounded and `now` is
+    /// [`None`].
+    pub(crate) fn check_at(&self, now: Option<Duration>) -> Result<()> {
+        if self.is_unbounded() {
+            return Ok(());
+        }
+        #[cfg(feature = "std")]
+        let now = now.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+        });
+        #[cfg(not(feature = "std"))]
+        let now = now.ok_or(Error::Expired)?;
+        self.check(now)
+    }
+
+    /// Encodes this validity period as the value of the extension.
+    ///
+    /// The not-before time is encoded as `0` and the not-after time is encoded
+    /// as [`u64::MAX`] if they are not set.
+    pub(crate) fn to_bytes(self) -> [u8; Self::SIZE] {
+        let not_before = self.not_before.map_or(u64::MIN, |t| t.as_secs());
+        let not_after = self.not_after.map_or(u64::MAX, |t| t.as_secs());
+        let mut buf = [u8::default(); Self::SIZE];
+        buf[..8].copy_from_slice(&not_before.to_le_bytes());
+        buf[8..].copy_from_slice(&not_after.to_le_bytes());
+        buf
+    }
+
+    /// Decodes the value of the extension into the validity period.
+    pub(crate) fn from_bytes(bytes: [u8; Self::SIZE]) -> Self {
+        let (not_before, not_after) = bytes.split_at(8);
+        let not_before = u64::from_le_bytes(
+            not_before
+                .try_into()
+                .expect("size of not-before time should be 8 bytes"),
+        );
+        let not_after = u64::from_le_bytes(
+            not_after
+                .try_into()
+                .expect("size of not-after time should be 8 bytes"),
+        );
+        Self {
+            not_before: Some(not_before)
+                .filter(|&t| t != u64::MIN)
+                .map(Duration::from_secs),
+            not_after: Some(not_after)
+                .filter(|&t| t != u64::MAX)
+                .map(Duration::from_secs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let validity = Validity::new();
+        assert_eq!(validity, Validity::default());
+        assert_eq!(validity.not_before(), None);
+        assert_eq!(validity.not_after(), None);
+        assert!(validity.is_unbounded());
+    }
+
+    #[test]
+    fn with_bounds() {
+        let validity = Validity::new()
+            .with_not_before(Duration::new(100, 999_999_999))
+            .with_not_after(Duration::from_secs(200));
+        assert_eq!(validity.not_before(), Some(Duration::from_secs(100)));
+        assert_eq!(validity.not_after(), Some(Duration::from_secs(200)));
+        assert!(!validity.is_unbounded());
+    }
+
+    #[test]
+    fn check() {
+        let validity = Validity::new()
+            .with_not_before(Duration::from_secs(100))
+            .with_not_after(Duration::from_secs(200));
+        assert_eq!(
+            validity.check(Duration::from_secs(99)).unwrap_err(),
+            Error::Expired
+        );
+        assert!(validity.check(Duration::from_secs(100)).is_ok());
+        assert!(validity.check(Duration::from_secs(200)).is_ok());
+        assert_eq!(
+            validity.check(Duration::new(200, 1)).unwrap_err(),
+            Error::Expired
+        );
+
+        assert!(Validity::new().check(Duration::ZERO).is_ok());
+        assert!(Validity::new().check(Duration::MAX).is_ok());
+    }
+
+    #[test]
+    fn check_at() {
+        assert!(Validity::new().check_at(None).is_ok());
+
+        let validity = Validity::new().with_not_after(Duration::from_secs(200));
+        assert!(validity.check_at(Some(Duration::from_secs(100))).is_ok());
+        assert_eq!(
+            validity
+                .check_at(Some(Duration::from_secs(300)))
+                .unwrap_err(),
+            Error::Expired
+        );
+        // The system time is after the not-after time.
+        assert_eq!(validity.check_at(None).unwrap_err(), Error::Expired);
+    }
+
+    #[test]
+    fn bytes() {
+        let validity = Validity::new();
+        let bytes = validity.to_bytes();
+        assert_eq!(bytes[..8], [u8::MIN; 8]);
+        assert_eq!(bytes[8..], [u8::MAX; 8]);
+        assert_eq!(Validity::from_bytes(bytes), validity);
+
+        let validity = Validity::new()
+            .with_not_before(Duration::from_secs(1))
+            .with_not_after(Duration::from_secs(2));
+        let bytes = validity.to_bytes();
+        assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Validity::from_bytes(bytes), validity);
+    }
+}
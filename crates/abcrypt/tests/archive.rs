@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "archive")]
+
+use std::{
+    env, fs,
+    io::{Cursor, ErrorKind},
+    path::PathBuf,
+    process,
+    time::Duration,
+};
+
+use abcrypt::{
+    Encryptor, Error,
+    archive::{self, Archive, Builder},
+    argon2::Params,
+};
+
+const PASSPHRASE: &str = "passphrase";
+const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
+
+fn params() -> Params {
+    Params::new(32, 3, 4, None).unwrap()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("abcrypt-{name}-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn archive() -> Vec<u8> {
+    let mut builder = Builder::new();
+    builder
+        .append(
+            "data.txt",
+            TEST_DATA,
+            0o644,
+            Duration::from_secs(1_767_225_600),
+        )
+        .unwrap()
+        .append("dir/empty", [], 0o600, Duration::ZERO)
+        .unwrap()
+        .append(
+            "dir/large.bin",
+            vec![0x42; 200_000],
+            0o755,
+            Duration::new(1, 2),
+        )
+        .unwrap();
+    builder.encrypt(PASSPHRASE, params()).unwrap()
+}
+
+#[test]
+fn list() {
+    let archive = Archive::new(Cursor::new(archive()), PASSPHRASE).unwrap();
+    let entries = archive.list();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].path(), "data.txt");
+    assert_eq!(entries[0].size(), TEST_DATA.len() as u64);
+    assert_eq!(entries[0].mode(), 0o644);
+    assert_eq!(entries[0].modified(), Duration::from_secs(1_767_225_600));
+    assert_eq!(entries[1].path(), "dir/empty");
+    assert_eq!(entries[1].size(), 0);
+    assert_eq!(entries[2].path(), "dir/large.bin");
+    assert_eq!(entries[2].size(), 200_000);
+    assert_eq!(entries[2].modified(), Duration::new(1, 2));
+}
+
+#[test]
+fn list_empty() {
+    let ciphertext = Builder::new().encrypt(PASSPHRASE, params()).unwrap();
+    let archive = Archive::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    assert!(archive.list().is_empty());
+}
+
+#[test]
+fn extract_one() {
+    let mut archive = Archive::new(Cursor::new(archive()), PASSPHRASE).unwrap();
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        archive.extract_one("dir/large.bin", &mut buf).unwrap(),
+        200_000
+    );
+    assert_eq!(buf, vec![0x42; 200_000]);
+
+    let mut buf = Vec::new();
+    archive.extract_one("data.txt", &mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+
+    let mut buf = Vec::new();
+    assert_eq!(archive.extract_one("dir/empty", &mut buf).unwrap(), 0);
+}
+
+#[test]
+fn extract_one_with_missing_entry() {
+    let mut archive = Archive::new(Cursor::new(archive()), PASSPHRASE).unwrap();
+    let err = archive.extract_one("dir", &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+}
+
+#[test]
+fn extract_one_with_corrupted_entry() {
+    let mut ciphertext = archive();
+    // Corrupts the second segment, which contains only the middle of the last
+    // entry.
+    let mid = ciphertext.len() / 2;
+    ciphertext[mid] ^= 1;
+
+    let mut archive = Archive::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    let mut buf = Vec::new();
+    archive.extract_one("data.txt", &mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+    let err = archive
+        .extract_one("dir/large.bin", &mut Vec::new())
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        Error::InvalidMac { .. }
+    ));
+}
+
+#[test]
+fn extract_all() {
+    let dir = temp_dir("extract-all");
+    let mut archive = Archive::new(Cursor::new(archive()), PASSPHRASE).unwrap();
+    archive.extract_all(&dir).unwrap();
+
+    assert_eq!(fs::read(dir.join("data.txt")).unwrap(), TEST_DATA);
+    assert!(fs::read(dir.join("dir/empty")).unwrap().is_empty());
+    assert_eq!(
+        fs::read(dir.join("dir/large.bin")).unwrap(),
+        vec![0x42; 200_000]
+    );
+    let metadata = fs::metadata(dir.join("data.txt")).unwrap();
+    assert_eq!(
+        metadata
+            .modified()
+            .unwrap()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap(),
+        Duration::from_secs(1_767_225_600)
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let metadata = fs::metadata(dir.join("dir/large.bin")).unwrap();
+        assert_eq!(metadata.permissions().mode() & 0o7777, 0o755);
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn extract_all_without_special_mode_bits() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir("extract-all-without-special-mode-bits");
+    let mut builder = Builder::new();
+    builder
+        .append("data.txt", TEST_DATA, 0o6755, Duration::ZERO)
+        .unwrap();
+    let ciphertext = builder.encrypt(PASSPHRASE, params()).unwrap();
+    let mut archive = Archive::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    assert_eq!(archive.list()[0].mode(), 0o6755);
+    archive.extract_all(&dir).unwrap();
+
+    let metadata = fs::metadata(dir.join("data.txt")).unwrap();
+    assert_eq!(metadata.permissions().mode() & 0o7777, 0o755);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn append_file_without_special_mode_bits() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = temp_dir("append-file-without-special-mode-bits");
+    let src = dir.join("data.txt");
+    fs::write(&src, TEST_DATA).unwrap();
+    fs::set_permissions(&src, fs::Permissions::from_mode(0o4755)).unwrap();
+
+    let mut builder = Builder::new();
+    builder.append_file("data.txt", &src).unwrap();
+    assert_eq!(builder.entries()[0].mode(), 0o755);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn append_dir_all() {
+    let src = temp_dir("append-dir-all");
+    fs::create_dir_all(src.join("b/c")).unwrap();
+    fs::write(src.join("a.txt"), TEST_DATA).unwrap();
+    fs::write(src.join("b/c/d.txt"), b"d").unwrap();
+
+    let mut builder = Builder::new();
+    builder.append_dir_all("root", &src).unwrap();
+    let paths = builder
+        .entries()
+        .iter()
+        .map(archive::Entry::path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["root/a.txt", "root/b/c/d.txt"]);
+
+    let mut builder = Builder::new();
+    builder.append_dir_all("", &src).unwrap();
+    let ciphertext = builder.encrypt(PASSPHRASE, params()).unwrap();
+    let mut archive = Archive::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    let mut buf = Vec::new();
+    archive.extract_one("b/c/d.txt", &mut buf).unwrap();
+    assert_eq!(buf, b"d");
+    fs::remove_dir_all(src).unwrap();
+}
+
+#[test]
+fn incorrect_passphrase() {
+    let err = Archive::new(Cursor::new(archive()), "password").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        Error::InvalidHeaderMac(_)
+    ));
+}
+
+#[test]
+fn invalid_archive() {
+    let cipher = Encryptor::with_params(&TEST_DATA, PASSPHRASE, params())
+        .and_then(|c| c.with_segment_size(archive::SEGMENT_SIZE))
+        .unwrap();
+    let err = Archive::new(Cursor::new(cipher.encrypt_to_vec()), PASSPHRASE).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        &Error::InvalidArchive
+    );
+}
+
+#[test]
+fn invalid_archive_with_trailing_data() {
+    let mut builder = Builder::new();
+    builder
+        .append("data.txt", TEST_DATA, 0o644, Duration::ZERO)
+        .unwrap();
+    let mut plaintext = builder.build();
+    plaintext.push(u8::default());
+    let cipher = Encryptor::with_params(&plaintext, PASSPHRASE, params()).unwrap();
+    let err = Archive::new(Cursor::new(cipher.encrypt_to_vec()), PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        &Error::InvalidArchive
+    );
+}
+
+#[test]
+fn into_inner() {
+    let ciphertext = archive();
+    let archive = Archive::new(Cursor::new(ciphertext.clone()), PASSPHRASE).unwrap();
+    assert_eq!(archive.into_inner().into_inner(), ciphertext);
+}
@@ -16,6 +16,21 @@ fn success() {
     assert!(argon2.is_ok());
 }
 
+#[test]
+fn success_with_armor() {
+    let argon2 = Argon2::new(include_bytes!("data/armor/data.txt.abcrypt.asc")).unwrap();
+    assert_eq!(argon2.variant(), Algorithm::Argon2id);
+    assert_eq!(argon2.version(), Version::V0x13);
+}
+
+#[test]
+fn invalid_armor() {
+    let mut data = *include_bytes!("data/armor/data.txt.abcrypt.asc");
+    data[40] = b'*';
+    let err = Argon2::new(data).unwrap_err();
+    assert_eq!(err, abcrypt::Error::InvalidArmor);
+}
+
 #[test]
 fn variant() {
     {
@@ -73,3 +88,201 @@ fn version() {
         assert_eq!(argon2.version(), Version::V0x13);
     }
 }
+
+#[test]
+fn params() {
+    {
+        let argon2 = Argon2::new(include_bytes!("data/v1/argon2d/v0x10/data.txt.abcrypt")).unwrap();
+        assert_eq!(argon2.params().memory_cost(), 47104);
+        assert_eq!(argon2.params().time_cost(), 1);
+        assert_eq!(argon2.params().parallelism(), 1);
+    }
+    {
+        let argon2 = Argon2::new(TEST_DATA_ENC).unwrap();
+        assert_eq!(argon2.params().memory_cost(), 32);
+        assert_eq!(argon2.params().time_cost(), 3);
+        assert_eq!(argon2.params().parallelism(), 4);
+    }
+}
+
+#[test]
+fn display() {
+    {
+        let argon2 = Argon2::new(include_bytes!("data/v1/argon2d/v0x10/data.txt.abcrypt")).unwrap();
+        assert_eq!(format!("{argon2}"), "$argon2d$v=16$m=47104,t=1,p=1");
+    }
+    {
+        let argon2 = Argon2::new(include_bytes!("data/v1/argon2i/v0x13/data.txt.abcrypt")).unwrap();
+        assert_eq!(format!("{argon2}"), "$argon2i$v=19$m=9216,t=4,p=1");
+    }
+    {
+        let argon2 = Argon2::new(TEST_DATA_ENC).unwrap();
+        assert_eq!(format!("{argon2}"), "$argon2id$v=19$m=32,t=3,p=4");
+    }
+}
+
+#[test]
+fn from_str() {
+    {
+        let argon2: Argon2 = "$argon2d$v=16$m=47104,t=1,p=1".parse().unwrap();
+        assert_eq!(argon2.variant(), Algorithm::Argon2d);
+        assert_eq!(argon2.version(), Version::V0x10);
+        assert_eq!(argon2.params().memory_cost(), 47104);
+        assert_eq!(argon2.params().time_cost(), 1);
+        assert_eq!(argon2.params().parallelism(), 1);
+    }
+    {
+        let argon2: Argon2 = "$argon2id$v=19$m=19456,t=2,p=1".parse().unwrap();
+        assert_eq!(argon2.variant(), Algorithm::Argon2id);
+        assert_eq!(argon2.version(), Version::V0x13);
+        assert_eq!(argon2.params().memory_cost(), 19456);
+        assert_eq!(argon2.params().time_cost(), 2);
+        assert_eq!(argon2.params().parallelism(), 1);
+    }
+}
+
+#[test]
+fn from_str_without_version() {
+    let argon2: Argon2 = "$argon2i$m=12288,t=3,p=1".parse().unwrap();
+    assert_eq!(argon2.variant(), Algorithm::Argon2i);
+    assert_eq!(argon2.version(), Version::V0x10);
+    assert_eq!(argon2.params().memory_cost(), 12288);
+}
+
+#[test]
+fn from_str_round_trip() {
+    let argon2 = Argon2::new(TEST_DATA_ENC).unwrap();
+    let parsed: Argon2 = argon2.to_string().parse().unwrap();
+    assert_eq!(parsed.variant(), argon2.variant());
+    assert_eq!(parsed.version(), argon2.version());
+    assert_eq!(parsed.params().memory_cost(), argon2.params().memory_cost());
+    assert_eq!(parsed.params().time_cost(), argon2.params().time_cost());
+    assert_eq!(parsed.params().parallelism(), argon2.params().parallelism());
+}
+
+#[test]
+fn from_str_with_invalid_phc_string() {
+    for s in [
+        "",
+        "argon2id$v=19$m=19456,t=2,p=1",
+        "$argon2$v=19$m=19456,t=2,p=1",
+        "$scrypt$ln=15,r=8,p=1",
+        "$argon2id$v=19",
+        "$argon2id$19$m=19456,t=2,p=1",
+        "$argon2id$v=+19$m=19456,t=2,p=1",
+        "$argon2id$v=019$m=19456,t=2,p=1",
+        "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ",
+        "$argon2id$v=19$m=19456,t=2,p=1$",
+        "$argon2id$v=19$t=2,m=19456,p=1",
+    ] {
+        let err = s.parse::<Argon2>().unwrap_err();
+        assert_eq!(err, abcrypt::Error::InvalidPhcString, "{s}");
+    }
+}
+
+#[test]
+fn from_str_with_invalid_argon2_version() {
+    let err = "$argon2id$v=18$m=19456,t=2,p=1"
+        .parse::<Argon2>()
+        .unwrap_err();
+    assert_eq!(err, abcrypt::Error::InvalidArgon2Version(18));
+}
+
+#[test]
+fn from_str_with_invalid_argon2_params() {
+    let err = "$argon2id$v=19$m=7,t=2,p=1".parse::<Argon2>().unwrap_err();
+    assert_eq!(
+        err,
+        abcrypt::Error::InvalidArgon2Params(abcrypt::argon2::Error::MemoryTooLittle)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize() {
+    use serde_test::{Token, assert_ser_tokens};
+
+    assert_ser_tokens(
+        &Argon2::new(TEST_DATA_ENC).unwrap(),
+        &[
+            Token::Struct {
+                name: "Argon2",
+                len: 3,
+            },
+            Token::Str("variant"),
+            Token::UnitVariant {
+                name: "Variant",
+                variant: "argon2id",
+            },
+            Token::Str("version"),
+            Token::U32(0x13),
+            Token::Str("params"),
+            Token::Struct {
+                name: "Params",
+                len: 3,
+            },
+            Token::Str("memoryCost"),
+            Token::U32(32),
+            Token::Str("timeCost"),
+            Token::U32(3),
+            Token::Str("parallelism"),
+            Token::U32(4),
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_json() {
+    let argon2 = Argon2::new(include_bytes!("data/v1/argon2d/v0x10/data.txt.abcrypt")).unwrap();
+    assert_eq!(
+        serde_json::to_string(&argon2).unwrap(),
+        r#"{"variant":"argon2d","version":16,"params":{"memoryCost":47104,"timeCost":1,"parallelism":1}}"#
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json() {
+    let argon2: Argon2 = serde_json::from_str(
+        r#"{"variant":"argon2i","version":19,"params":{"memoryCost":19456,"timeCost":2,"parallelism":1}}"#,
+    )
+    .unwrap();
+    assert_eq!(argon2.variant(), Algorithm::Argon2i);
+    assert_eq!(argon2.version(), Version::V0x13);
+    assert_eq!(argon2.params().memory_cost(), 19456);
+    assert_eq!(argon2.params().time_cost(), 2);
+    assert_eq!(argon2.params().parallelism(), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json_round_trip() {
+    let argon2 = Argon2::new(TEST_DATA_ENC).unwrap();
+    let deserialized: Argon2 =
+        serde_json::from_str(&serde_json::to_string(&argon2).unwrap()).unwrap();
+    assert_eq!(deserialized.to_string(), argon2.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json_with_invalid_context() {
+    assert!(
+        serde_json::from_str::<Argon2>(
+            r#"{"variant":"argon2","version":19,"params":{"memoryCost":19456,"timeCost":2,"parallelism":1}}"#,
+        )
+        .is_err()
+    );
+    let err = serde_json::from_str::<Argon2>(
+        r#"{"variant":"argon2id","version":18,"params":{"memoryCost":19456,"timeCost":2,"parallelism":1}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("invalid Argon2 version `0x12`"));
+    let err = serde_json::from_str::<Argon2>(
+        r#"{"variant":"argon2id","version":19,"params":{"memoryCost":19456,"timeCost":0,"parallelism":1}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("invalid Argon2 parameters"));
+}
@@ -2,8 +2,15 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::time::Duration;
+
+#[cfg(feature = "normalization")]
+use abcrypt::Normalization;
+#[cfg(feature = "sharing")]
+use abcrypt::sharing::{self, FileKey};
 use abcrypt::{
-    Decryptor, Error, HEADER_SIZE, TAG_SIZE, argon2, blake2::digest::MacError, chacha20poly1305,
+    Aead, Candidate, Decryptor, Encryptor, Error, Extension, HEADER_SIZE, KeyId, TAG_SIZE,
+    Validity, argon2, blake2::digest::MacError,
 };
 
 const PASSPHRASE: &str = "passphrase";
@@ -76,6 +83,201 @@ fn success() {
     }
 }
 
+#[test]
+fn success_with_padding() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/data.txt.abcrypt");
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); 80];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(len, TEST_DATA.len());
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "aes-gcm-siv")]
+#[test]
+fn success_with_aes_256_gcm_siv() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/aes-256-gcm-siv/data.txt.abcrypt");
+
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert_eq!(cipher.aead(), Aead::Aes256GcmSiv);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn success_with_segment_size() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert_eq!(cipher.segment_size(), Some(8));
+    let mut buf = [u8::default(); 36];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn invalid_segment_size() {
+    let mut data = *include_bytes!("data/v2/segmented/data.txt.abcrypt");
+
+    {
+        // The segment size must not be zero.
+        data[92] = 0;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+
+    {
+        // The segment size extension must be critical.
+        data[92] = 8;
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[test]
+fn invalid_length_with_segment_size() {
+    let data = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+    // The last segment is shorter than the authentication tag.
+    let data = &data[..(data.len() - 5)];
+    let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidLength { len: 271, min: 273 });
+}
+
+#[test]
+fn truncated_segments() {
+    let data = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+    // The last segment is removed.
+    let data = &data[..(data.len() - 20)];
+    let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); 32];
+    let err = cipher.decrypt(&mut buf).unwrap_err();
+    // The last remaining segment is not marked as the last segment.
+    assert_eq!(err, Error::InvalidMac { offset: 232 });
+}
+
+#[test]
+fn success_from_parts() {
+    let (header, ciphertext) = TEST_DATA_ENC.split_at(HEADER_SIZE);
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len()];
+    cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+}
+
+#[test]
+fn success_from_parts_with_segment_size() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+
+    let (header, ciphertext) = TEST_DATA_ENC.split_at(160);
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); 36];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn from_parts_with_trailing_bytes_in_header() {
+    let header = &TEST_DATA_ENC[..=HEADER_SIZE];
+    let ciphertext = &TEST_DATA_ENC[HEADER_SIZE..];
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    assert_eq!(cipher.out_len(), TEST_DATA.len());
+}
+
+#[test]
+fn from_parts_with_truncated_header() {
+    let header = &TEST_DATA_ENC[..(HEADER_SIZE - 1)];
+    let ciphertext = &TEST_DATA_ENC[HEADER_SIZE..];
+    let err = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidLength {
+            len: HEADER_SIZE - 1,
+            min: HEADER_SIZE
+        }
+    );
+}
+
+#[test]
+fn from_parts_with_short_ciphertext() {
+    const SEGMENTED: &[u8] = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+
+    let (header, ciphertext) = TEST_DATA_ENC.split_at(HEADER_SIZE);
+    let ciphertext = &ciphertext[..(TAG_SIZE - 1)];
+    let err = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidLength {
+            len: HEADER_SIZE + TAG_SIZE - 1,
+            min: HEADER_SIZE + TAG_SIZE
+        }
+    );
+
+    let (header, ciphertext) = SEGMENTED.split_at(160);
+    let ciphertext = &ciphertext[..(ciphertext.len() - 5)];
+    let err = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidLength { len: 271, min: 273 });
+}
+
+#[test]
+fn from_parts_with_mismatched_parts() {
+    const OTHER_DATA_ENC: &[u8] = include_bytes!("data/v1/argon2d/v0x13/data.txt.abcrypt");
+
+    let header = &TEST_DATA_ENC[..HEADER_SIZE];
+    let ciphertext = &OTHER_DATA_ENC[HEADER_SIZE..];
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len()];
+    let err = cipher.decrypt(&mut buf).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidMac {
+            offset: HEADER_SIZE as u64
+        }
+    );
+}
+
+#[cfg(not(feature = "aes-gcm-siv"))]
+#[test]
+fn unsupported_aead() {
+    let err = Decryptor::new(
+        &include_bytes!("data/v2/aes-256-gcm-siv/data.txt.abcrypt"),
+        PASSPHRASE,
+    )
+    .unwrap_err();
+    assert_eq!(err, Error::UnsupportedAead(1));
+}
+
+#[test]
+fn invalid_aead() {
+    let mut data = *include_bytes!("data/v2/aes-256-gcm-siv/data.txt.abcrypt");
+
+    {
+        data[92] = 2;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::UnsupportedAead(2));
+    }
+
+    {
+        // The AEAD extension must be critical.
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[cfg(all(feature = "aes-gcm-siv", feature = "alloc"))]
+#[test]
+fn invalid_mac_with_aes_256_gcm_siv() {
+    let mut data = *include_bytes!("data/v2/aes-256-gcm-siv/data.txt.abcrypt");
+    let start_mac = data.len() - TAG_SIZE;
+    data[start_mac] ^= 1;
+    let err = Decryptor::new(&data, PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec())
+        .unwrap_err();
+    assert_eq!(err, Error::InvalidMac { offset: 157 });
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn success_to_vec() {
@@ -85,6 +287,75 @@ fn success_to_vec() {
     assert_eq!(plaintext, TEST_DATA);
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn success_append() {
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    let mut buf = b"prefix".to_vec();
+    assert_eq!(cipher.decrypt_append(&mut buf).unwrap(), TEST_DATA.len());
+    assert_eq!(cipher.decrypt_append(&mut buf).unwrap(), TEST_DATA.len());
+    assert_eq!(buf, [b"prefix".as_slice(), TEST_DATA, TEST_DATA].concat());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decrypt_append_with_invalid_mac() {
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    let start_mac = data.len() - TAG_SIZE;
+    data[start_mac..].reverse();
+    let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+    let mut buf = b"prefix".to_vec();
+    let err = cipher.decrypt_append(&mut buf).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidMac {
+            offset: HEADER_SIZE as u64
+        }
+    );
+    assert_eq!(buf, b"prefix");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn success_append_bytes() {
+    use abcrypt::bytes::BytesMut;
+
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    let mut buf = BytesMut::from(b"prefix".as_slice());
+    assert_eq!(
+        cipher.decrypt_append_bytes(&mut buf).unwrap(),
+        TEST_DATA.len()
+    );
+    assert_eq!(buf, [b"prefix".as_slice(), TEST_DATA].concat());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_to_vec_with_metadata() {
+    let (plaintext, metadata) =
+        Decryptor::new(&include_bytes!("data/v2/data.txt.abcrypt"), PASSPHRASE)
+            .and_then(|c| c.decrypt_to_vec_with_metadata())
+            .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+    assert_eq!(metadata.file_name(), Some("data.txt"));
+    assert_eq!(
+        metadata.modified(),
+        Some(Duration::from_secs(1_767_225_600))
+    );
+    assert_eq!(metadata.mode(), Some(0o644));
+    assert_eq!(metadata.content_type(), Some("text/plain"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_to_vec_without_metadata() {
+    let (plaintext, metadata) = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec_with_metadata())
+        .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+    assert!(metadata.is_empty());
+}
+
 #[test]
 #[should_panic(expected = "source slice length (14) does not match destination slice length (15)")]
 fn invalid_output_length() {
@@ -93,6 +364,27 @@ fn invalid_output_length() {
     let _ = cipher.decrypt(&mut buf);
 }
 
+#[test]
+fn try_decrypt() {
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len()];
+    assert_eq!(cipher.try_decrypt(&mut buf).unwrap(), TEST_DATA.len());
+    assert_eq!(buf, TEST_DATA);
+}
+
+#[test]
+fn try_decrypt_with_invalid_output_length() {
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    let expected = TEST_DATA.len();
+    for actual in [0, expected - 1, expected + 1] {
+        let mut buf = [u8::default(); TEST_DATA.len() + 1];
+        assert_eq!(
+            cipher.try_decrypt(&mut buf[..actual]).unwrap_err(),
+            Error::InvalidOutputLength { expected, actual }
+        );
+    }
+}
+
 #[test]
 fn incorrect_passphrase() {
     let err = Decryptor::new(&TEST_DATA_ENC, "password").unwrap_err();
@@ -104,7 +396,13 @@ fn invalid_input_length() {
     {
         let data = [u8::default(); (HEADER_SIZE + TAG_SIZE) - 1];
         let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
-        assert_eq!(err, Error::InvalidLength);
+        assert_eq!(
+            err,
+            Error::InvalidLength {
+                len: (HEADER_SIZE + TAG_SIZE) - 1,
+                min: HEADER_SIZE + TAG_SIZE
+            }
+        );
     }
 
     {
@@ -117,7 +415,7 @@ fn invalid_input_length() {
 #[test]
 fn invalid_magic_number() {
     let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
-    data[0] = u32::from('b').try_into().unwrap();
+    data[0] = b'b';
     let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
     assert_eq!(err, Error::InvalidMagicNumber);
 }
@@ -131,9 +429,9 @@ fn unsupported_version() {
 #[test]
 fn unknown_version() {
     let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
-    data[7] = 2;
+    data[7] = 4;
     let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
-    assert_eq!(err, Error::UnknownVersion(2));
+    assert_eq!(err, Error::UnknownVersion(4));
 }
 
 #[test]
@@ -192,6 +490,386 @@ fn invalid_header_mac() {
     assert_eq!(err, MacError.into());
 }
 
+#[test]
+fn invalid_header_mac_with_extensions() {
+    let mut data = *include_bytes!("data/v2/data.txt.abcrypt");
+    // Tampers with the value of the extension.
+    data[92] = b'A';
+    let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, MacError.into());
+}
+
+#[test]
+fn invalid_extensions() {
+    let mut data = *include_bytes!("data/v2/data.txt.abcrypt");
+
+    {
+        data[84..88].copy_from_slice(&u32::to_le_bytes(1025));
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+
+    {
+        data[84..88].copy_from_slice(&u32::to_le_bytes(10));
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+
+    {
+        data[84..88].copy_from_slice(&u32::to_le_bytes(11));
+        data[89] = 0xc0;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::UnknownCriticalExtension(0xc000));
+    }
+}
+
+#[test]
+fn invalid_key_commitment() {
+    let cipher = Encryptor::with_params(
+        &TEST_DATA,
+        PASSPHRASE,
+        argon2::Params::new(32, 3, 4, None).unwrap(),
+    )
+    .and_then(Encryptor::with_key_commitment)
+    .unwrap();
+    let mut data = [u8::default(); 19 + 36 + HEADER_SIZE + 4 + TAG_SIZE];
+    cipher.encrypt(&mut data);
+    assert!(
+        Decryptor::new(&data, PASSPHRASE)
+            .unwrap()
+            .has_key_commitment()
+    );
+
+    {
+        let err = Decryptor::new(&data, "password").unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+
+    {
+        // The key commitment is authenticated by the MAC of the header.
+        let mut data = data;
+        data[92] ^= 1;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+
+    {
+        // The key commitment extension must be critical.
+        let mut data = data;
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[test]
+fn success_with_any() {
+    let cipher = Encryptor::with_params(
+        &TEST_DATA,
+        PASSPHRASE,
+        argon2::Params::new(32, 3, 4, None).unwrap(),
+    )
+    .and_then(|c| c.with_key_id(KeyId::new("secret")))
+    .unwrap();
+    let mut data = [u8::default(); 19 + 12 + HEADER_SIZE + 4 + TAG_SIZE];
+    cipher.encrypt(&mut data);
+
+    let candidates = [
+        Candidate::new(PASSPHRASE).with_key_id(KeyId::new("other")),
+        Candidate::new("password"),
+        Candidate::new(PASSPHRASE),
+    ];
+    let (index, cipher) = Decryptor::with_any(&data, &candidates).unwrap();
+    assert_eq!(index, 2);
+    assert_eq!(cipher.key_id(), Some(KeyId::new("secret")));
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+
+    let err = Decryptor::with_any(&data, &candidates[..2]).unwrap_err();
+    assert_eq!(err, MacError.into());
+}
+
+#[test]
+fn invalid_key_id_extension() {
+    let cipher = Encryptor::with_params(
+        &TEST_DATA,
+        PASSPHRASE,
+        argon2::Params::new(32, 3, 4, None).unwrap(),
+    )
+    .and_then(|c| c.with_key_id(KeyId::new("secret")))
+    .unwrap();
+    let mut data = [u8::default(); 19 + 12 + HEADER_SIZE + 4 + TAG_SIZE];
+    cipher.encrypt(&mut data);
+
+    {
+        // The key identifier is authenticated by the MAC of the header.
+        let mut data = data;
+        data[92] ^= 1;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+
+    {
+        // The key identifier extension must not be critical.
+        let mut data = data;
+        data[89] = 0x80;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+fn encrypt_with_validity(validity: Validity) -> [u8; 19 + 20 + HEADER_SIZE + 4 + TAG_SIZE] {
+    let cipher = Encryptor::with_params(
+        &TEST_DATA,
+        PASSPHRASE,
+        argon2::Params::new(32, 3, 4, None).unwrap(),
+    )
+    .and_then(|c| c.with_validity(validity))
+    .unwrap();
+    let mut data = [u8::default(); 19 + 20 + HEADER_SIZE + 4 + TAG_SIZE];
+    cipher.encrypt(&mut data);
+    data
+}
+
+#[test]
+fn success_with_validity() {
+    let data = encrypt_with_validity(
+        Validity::new()
+            .with_not_before(Duration::from_secs(100))
+            .with_not_after(Duration::from_secs(200)),
+    );
+    for now in [100, 150, 200] {
+        let cipher = Decryptor::new(&data, PASSPHRASE)
+            .unwrap()
+            .with_time(Duration::from_secs(now));
+        let mut buf = [u8::default(); 19];
+        let len = cipher.decrypt(&mut buf).unwrap();
+        assert_eq!(&buf[..len], TEST_DATA);
+    }
+
+    // The validity period without the not-after time is valid at the system
+    // time.
+    #[cfg(feature = "std")]
+    {
+        let data = encrypt_with_validity(Validity::new().with_not_before(Duration::from_secs(1)));
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        let mut buf = [u8::default(); 19];
+        let len = cipher.decrypt(&mut buf).unwrap();
+        assert_eq!(&buf[..len], TEST_DATA);
+    }
+}
+
+#[test]
+fn expired() {
+    let data = encrypt_with_validity(
+        Validity::new()
+            .with_not_before(Duration::from_secs(100))
+            .with_not_after(Duration::from_secs(200)),
+    );
+    for now in [Duration::from_secs(99), Duration::new(200, 1)] {
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap().with_time(now);
+        let mut buf = [u8::default(); 19];
+        let err = cipher.decrypt(&mut buf).unwrap_err();
+        assert_eq!(err, Error::Expired);
+    }
+
+    // The system time is after the not-after time, or is not available.
+    {
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        let mut buf = [u8::default(); 19];
+        let err = cipher.decrypt(&mut buf).unwrap_err();
+        assert_eq!(err, Error::Expired);
+    }
+}
+
+#[test]
+fn invalid_validity_extension() {
+    let data = encrypt_with_validity(Validity::new().with_not_after(Duration::from_secs(200)));
+
+    {
+        // The validity period is authenticated by the MAC of the header.
+        let mut data = data;
+        data[100] ^= 1;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+
+    {
+        // The validity period extension must be critical.
+        let mut data = data;
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[cfg(feature = "sharing")]
+#[test]
+fn success_with_file_key() {
+    let key = FileKey::generate();
+    let ciphertext = Encryptor::with_file_key(&TEST_DATA, &key).encrypt_to_vec();
+    let shares = sharing::split(&key, 3, 5).unwrap();
+
+    let key = sharing::combine(&[shares[4].clone(), shares[0].clone(), shares[2].clone()]).unwrap();
+    let cipher = Decryptor::with_file_key(&ciphertext, &key).unwrap();
+    assert_eq!(cipher.decrypt_to_vec().unwrap(), TEST_DATA);
+}
+
+#[cfg(feature = "sharing")]
+#[test]
+fn invalid_file_key() {
+    let ciphertext = Encryptor::with_file_key(&TEST_DATA, &FileKey::generate()).encrypt_to_vec();
+
+    let err = Decryptor::with_file_key(&ciphertext, &FileKey::generate()).unwrap_err();
+    assert_eq!(err, Error::InvalidHeaderMac(MacError));
+}
+
+#[cfg(feature = "sharing")]
+#[test]
+fn file_key_with_passphrase_data() {
+    let err = Decryptor::with_file_key(&TEST_DATA_ENC, &FileKey::generate()).unwrap_err();
+    assert_eq!(err, Error::UnsupportedKdf(2));
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn success_with_normalization() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/normalized/data.txt.abcrypt");
+
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert_eq!(cipher.normalization(), Some(Normalization::Nfc));
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn invalid_passphrase_with_normalization() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/normalized/data.txt.abcrypt");
+
+    let err = Decryptor::new(&TEST_DATA_ENC, b"passphrase\xff").unwrap_err();
+    assert_eq!(err, Error::InvalidPassphrase);
+
+    // The candidate which is not valid UTF-8 is skipped.
+    let candidates = [Candidate::new(b"\xff"), Candidate::new(PASSPHRASE)];
+    let (index, _) = Decryptor::with_any(&TEST_DATA_ENC, &candidates).unwrap();
+    assert_eq!(index, 1);
+}
+
+#[test]
+fn invalid_normalization_extension() {
+    let mut data = *include_bytes!("data/v2/normalized/data.txt.abcrypt");
+
+    #[cfg(not(feature = "normalization"))]
+    {
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::UnsupportedNormalization(0));
+    }
+
+    {
+        data[92] = 2;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::UnsupportedNormalization(2));
+    }
+
+    {
+        // The normalization extension must be critical.
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[test]
+fn success_with_signature() {
+    const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/signed/data.txt.abcrypt");
+
+    // The signature can be skipped without verifying it.
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert!(cipher.has_signature());
+    assert_eq!(cipher.out_len(), 19);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn invalid_signature_extension() {
+    let mut data = *include_bytes!("data/v2/signed/data.txt.abcrypt");
+
+    {
+        // The signature extension must be critical.
+        data[89] = 0x00;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+
+    {
+        // The public key must be 32 bytes.
+        data[89] = 0x80;
+        data[90] = 31;
+        let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+        assert_eq!(err, Error::InvalidExtension);
+    }
+}
+
+#[test]
+fn invalid_length_with_signature() {
+    let data = include_bytes!("data/v2/signed/data.txt.abcrypt");
+    // The signature is not a part of the ciphertext.
+    let data = &data[..(data.len() - 64)];
+    let err = Decryptor::new(&data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidLength { len: 223, min: 268 });
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn verify_signature() {
+    use abcrypt::ed25519_dalek::SigningKey;
+
+    let key = SigningKey::from_bytes(&[1; 32]).verifying_key();
+    let data = include_bytes!("data/v2/signed/data.txt.abcrypt");
+
+    {
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        assert!(cipher.verify_signature(&key).is_ok());
+    }
+
+    {
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        let other = SigningKey::from_bytes(&[2; 32]).verifying_key();
+        let err = cipher.verify_signature(&other).unwrap_err();
+        assert_eq!(err, Error::InvalidSignature);
+    }
+
+    {
+        // The data is not signed.
+        let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+        let err = cipher.verify_signature(&key).unwrap_err();
+        assert_eq!(err, Error::InvalidSignature);
+    }
+
+    {
+        let mut data = *data;
+        let len = data.len();
+        data[len - 1] ^= 1;
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        let err = cipher.verify_signature(&key).unwrap_err();
+        assert_eq!(err, Error::InvalidSignature);
+    }
+
+    {
+        // The ciphertext is covered by the signature.
+        let mut data = *data;
+        data[188] ^= 1;
+        let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
+        let err = cipher.verify_signature(&key).unwrap_err();
+        assert_eq!(err, Error::InvalidSignature);
+    }
+}
+
 #[test]
 fn invalid_mac() {
     let data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
@@ -203,7 +881,36 @@ fn invalid_mac() {
     let cipher = Decryptor::new(&data, PASSPHRASE).unwrap();
     let mut buf = [u8::default(); TEST_DATA.len()];
     let err = cipher.decrypt(&mut buf).unwrap_err();
-    assert_eq!(err, chacha20poly1305::Error.into());
+    assert_eq!(
+        err,
+        Error::InvalidMac {
+            offset: HEADER_SIZE as u64
+        }
+    );
+}
+
+#[test]
+fn aead() {
+    let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert_eq!(cipher.aead(), Aead::XChaCha20Poly1305);
+
+    let cipher = Decryptor::new(&include_bytes!("data/v2/data.txt.abcrypt"), PASSPHRASE).unwrap();
+    assert_eq!(cipher.aead(), Aead::XChaCha20Poly1305);
+}
+
+#[test]
+fn extensions() {
+    {
+        let cipher =
+            Decryptor::new(&include_bytes!("data/v2/data.txt.abcrypt"), PASSPHRASE).unwrap();
+        let extensions = cipher.extensions().collect::<Vec<_>>();
+        assert_eq!(extensions, [Extension::new(0x4000, b"abcrypt")]);
+    }
+
+    {
+        let cipher = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE).unwrap();
+        assert_eq!(cipher.extensions().next(), None);
+    }
 }
 
 #[test]
@@ -212,6 +919,58 @@ fn out_len() {
     assert_eq!(cipher.out_len(), TEST_DATA.len());
 }
 
+#[test]
+fn out_len_with_padding() {
+    let cipher = Decryptor::new(&include_bytes!("data/v2/data.txt.abcrypt"), PASSPHRASE).unwrap();
+    assert_eq!(cipher.out_len(), 80);
+}
+
+#[test]
+fn out_len_of() {
+    assert_eq!(
+        Decryptor::out_len_of(TEST_DATA_ENC).unwrap(),
+        TEST_DATA.len()
+    );
+    assert_eq!(
+        Decryptor::out_len_of(include_bytes!("data/v2/data.txt.abcrypt")).unwrap(),
+        80
+    );
+}
+
+#[test]
+fn out_len_of_with_invalid_input_length() {
+    let err = Decryptor::out_len_of(&TEST_DATA_ENC[..(HEADER_SIZE + TAG_SIZE) - 1]).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidLength {
+            len: (HEADER_SIZE + TAG_SIZE) - 1,
+            min: HEADER_SIZE + TAG_SIZE
+        }
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_convenience_function_with_armor() {
+    let plaintext = abcrypt::decrypt(
+        include_bytes!("data/armor/data.txt.abcrypt.asc"),
+        PASSPHRASE,
+    )
+    .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn invalid_armor_convenience_function() {
+    let mut data = *include_bytes!("data/armor/data.txt.abcrypt.asc");
+    let checksum_pos = data.len() - 43;
+    assert_eq!(data[checksum_pos], b'=');
+    data[checksum_pos + 1] = b'A';
+    let err = abcrypt::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidArmor);
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn success_convenience_function() {
@@ -260,3 +1019,84 @@ fn success_convenience_function() {
         assert_eq!(plaintext, TEST_DATA);
     }
 }
+
+#[cfg(feature = "alloc")]
+fn encrypt_with_key_id(key_id: &str) -> Vec<u8> {
+    Encryptor::with_params(
+        &TEST_DATA,
+        PASSPHRASE,
+        argon2::Params::new(32, 3, 4, None).unwrap(),
+    )
+    .and_then(|c| c.with_key_id(KeyId::new(key_id)))
+    .map(|c| c.encrypt_to_vec())
+    .unwrap()
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_decrypt_with_any() {
+    let data = encrypt_with_key_id("2026-q1");
+    let candidates = [
+        Candidate::new("password").with_key_id(KeyId::new("2025-q4")),
+        Candidate::new(PASSPHRASE).with_key_id(KeyId::new("2025-q4")),
+        Candidate::new("password"),
+        Candidate::new(PASSPHRASE).with_key_id(KeyId::new("2026-q1")),
+    ];
+    // The candidate with a different key identifier is skipped even if the
+    // passphrase is correct.
+    let (index, plaintext) = abcrypt::decrypt_with_any(data, &candidates).unwrap();
+    assert_eq!(index, 3);
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decrypt_with_any_without_key_id() {
+    let candidates = [
+        Candidate::new("password").with_key_id(KeyId::new("2025-q4")),
+        Candidate::new(PASSPHRASE).with_key_id(KeyId::new("2026-q1")),
+    ];
+    let (index, plaintext) = abcrypt::decrypt_with_any(TEST_DATA_ENC, &candidates).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decrypt_with_any_with_armor() {
+    let candidates = [Candidate::new("password"), Candidate::new(PASSPHRASE)];
+    let (index, plaintext) = abcrypt::decrypt_with_any(
+        include_bytes!("data/armor/data.txt.abcrypt.asc"),
+        &candidates,
+    )
+    .unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decrypt_with_any_without_matching_candidate() {
+    let data = encrypt_with_key_id("2026-q1");
+    {
+        let candidates = [
+            Candidate::new("password"),
+            Candidate::new(PASSPHRASE).with_key_id(KeyId::new("2025-q4")),
+        ];
+        let err = abcrypt::decrypt_with_any(&data, &candidates).unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+    {
+        let err = abcrypt::decrypt_with_any(&data, &[]).unwrap_err();
+        assert_eq!(err, MacError.into());
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decrypt_with_any_with_invalid_header() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[0] = b'b';
+    let err = abcrypt::decrypt_with_any(data, &[Candidate::new(PASSPHRASE)]).unwrap_err();
+    assert_eq!(err, Error::InvalidMagicNumber);
+}
@@ -2,14 +2,31 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
+#[cfg(feature = "alloc")]
+use abcrypt::Metadata;
+#[cfg(feature = "normalization")]
+use abcrypt::Normalization;
+#[cfg(feature = "ed25519")]
+use abcrypt::ed25519_dalek::SigningKey;
 use abcrypt::{
-    Argon2, Decryptor, Encryptor, HEADER_SIZE, TAG_SIZE,
+    Aead, Argon2, Decryptor, Encryptor, Error, Extension, HEADER_SIZE, KeyId, Padding, TAG_SIZE,
+    Validity,
     argon2::{Algorithm, Params, Version},
 };
+#[cfg(feature = "sharing")]
+use abcrypt::{Kdf, sharing::FileKey};
 
 const PASSPHRASE: &str = "passphrase";
 const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
 
+// The header of version 2 also contains the length of the extensions.
+const V2_HEADER_SIZE: usize = HEADER_SIZE + 4;
+
 #[cfg(feature = "alloc")]
 #[test]
 fn success() {
@@ -58,6 +75,7 @@ fn success_with_params() {
 }
 
 #[test]
+#[allow(clippy::too_many_lines)]
 fn success_with_context() {
     #[cfg(feature = "alloc")]
     {
@@ -249,6 +267,113 @@ fn success_to_vec() {
     assert_eq!(plaintext, TEST_DATA);
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn success_append() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    let mut buf = b"prefix".to_vec();
+    cipher.encrypt_append(&mut buf);
+    assert_eq!(buf.len(), 6 + TEST_DATA.len() + HEADER_SIZE + TAG_SIZE);
+    assert!(buf.starts_with(b"prefix"));
+
+    let plaintext = Decryptor::new(&&buf[6..], PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec())
+        .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn success_append_bytes() {
+    use abcrypt::bytes::{BufMut, BytesMut};
+
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    let mut buf = BytesMut::new();
+    buf.put_u32(u32::try_from(cipher.out_len()).unwrap());
+    cipher.encrypt_append_bytes(&mut buf);
+    assert_eq!(buf.len(), 4 + TEST_DATA.len() + HEADER_SIZE + TAG_SIZE);
+
+    let frame = buf.split_off(4).freeze();
+    assert_eq!(
+        buf.as_ref(),
+        u32::try_from(frame.len()).unwrap().to_be_bytes()
+    );
+    let plaintext = Decryptor::new(&frame, PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec())
+        .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[test]
+fn success_with_padding() {
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_padding(Padding::Padme);
+        let mut buf = [u8::default(); 20 + V2_HEADER_SIZE + TAG_SIZE];
+        cipher.encrypt(&mut buf);
+
+        let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+        let mut buf = [u8::default(); 20];
+        let len = cipher.decrypt(&mut buf).unwrap();
+        assert_eq!(&buf[..len], TEST_DATA);
+    }
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_padding(Padding::Multiple(NonZeroUsize::new(512).unwrap()));
+        let mut buf = [u8::default(); 512 + V2_HEADER_SIZE + TAG_SIZE];
+        cipher.encrypt(&mut buf);
+
+        let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+        let mut buf = [u8::default(); 512];
+        let len = cipher.decrypt(&mut buf).unwrap();
+        assert_eq!(&buf[..len], TEST_DATA);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_to_vec_with_padding() {
+    let ciphertext =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .map(|c| c.with_padding(Padding::Padme).encrypt_to_vec())
+            .unwrap();
+    assert_eq!(ciphertext.len(), 20 + V2_HEADER_SIZE + TAG_SIZE);
+
+    let plaintext = Decryptor::new(&ciphertext, PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec())
+        .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_to_vec_with_metadata() {
+    let metadata = Metadata::new()
+        .with_file_name("data.txt")
+        .with_modified(Duration::new(1_767_225_600, 123_456_789))
+        .with_mode(0o644)
+        .with_content_type("text/plain");
+    let ciphertext =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .map(|c| c.with_metadata(&metadata).encrypt_to_vec())
+            .unwrap();
+    assert_eq!(ciphertext.len(), 77 + V2_HEADER_SIZE + TAG_SIZE);
+
+    let cipher = Decryptor::new(&ciphertext, PASSPHRASE).unwrap();
+    assert_eq!(cipher.decrypt_to_vec().unwrap(), TEST_DATA);
+    let (plaintext, decrypted_metadata) = cipher.decrypt_to_vec_with_metadata().unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+    assert_eq!(decrypted_metadata, metadata);
+}
+
 #[test]
 #[should_panic(expected = "source slice length (16) does not match destination slice length (15)")]
 fn invalid_output_length() {
@@ -259,6 +384,81 @@ fn invalid_output_length() {
     cipher.encrypt(&mut buf);
 }
 
+#[test]
+fn try_encrypt() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len() + HEADER_SIZE + TAG_SIZE];
+    cipher.try_encrypt(&mut buf).unwrap();
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len()];
+    cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+}
+
+#[test]
+fn try_encrypt_with_invalid_output_length() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    let expected = TEST_DATA.len() + HEADER_SIZE + TAG_SIZE;
+    for actual in [0, expected - 1, expected + 1] {
+        let mut buf = [u8::default(); TEST_DATA.len() + HEADER_SIZE + TAG_SIZE + 1];
+        assert_eq!(
+            cipher.try_encrypt(&mut buf[..actual]).unwrap_err(),
+            Error::InvalidOutputLength { expected, actual }
+        );
+    }
+}
+
+#[test]
+fn success_detached() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    assert_eq!(cipher.header_len(), HEADER_SIZE);
+    let mut header = [u8::default(); HEADER_SIZE];
+    let mut ciphertext = [u8::default(); TEST_DATA.len() + TAG_SIZE];
+    cipher.encrypt_detached(&mut header, &mut ciphertext);
+    let mut buf = [u8::default(); TEST_DATA.len() + HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[..HEADER_SIZE], header);
+    assert_eq!(buf[HEADER_SIZE..], ciphertext);
+
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); TEST_DATA.len()];
+    cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn success_detached_to_vec_with_segment_size() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+            .unwrap();
+    let (header, ciphertext) = cipher.encrypt_detached_to_vec();
+    assert_eq!(header.len(), cipher.header_len());
+    assert_eq!(header.len() + ciphertext.len(), cipher.out_len());
+
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    assert_eq!(cipher.decrypt_to_vec().unwrap(), TEST_DATA);
+}
+
+#[test]
+#[should_panic(expected = "`header` and the header have different lengths")]
+fn invalid_header_length_detached() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap();
+    let mut header = [u8::default(); HEADER_SIZE + 1];
+    let mut ciphertext = [u8::default(); TEST_DATA.len() + TAG_SIZE];
+    cipher.encrypt_detached(&mut header, &mut ciphertext);
+}
+
 #[test]
 fn minimum_output_length() {
     let cipher =
@@ -288,6 +488,403 @@ fn version() {
     assert_eq!(buf[7], 1);
 }
 
+#[test]
+fn version_with_padding() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap()
+            .with_padding(Padding::Padme);
+    let mut buf = [u8::default(); 20 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn version_with_metadata() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap()
+            .with_metadata(&Metadata::new());
+    let mut buf = [u8::default(); 19 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+}
+
+#[test]
+fn success_with_extension() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_extension(Extension::new(0x4000, b"abc")))
+            .and_then(|c| c.with_extension(Extension::new(0xc001, &[])))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 11 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE);
+    assert_eq!(cipher.unwrap_err(), Error::UnknownCriticalExtension(0xc001));
+}
+
+#[cfg(feature = "aes-gcm-siv")]
+#[test]
+fn success_with_aes_256_gcm_siv() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_aead(Aead::Aes256GcmSiv))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 5 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..93], [5, 0, 0, 0, 0x01, 0x80, 1, 0, 1]);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert_eq!(cipher.aead(), Aead::Aes256GcmSiv);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "aes-gcm-siv")]
+#[test]
+fn with_aead_twice() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_aead(Aead::Aes256GcmSiv))
+            .and_then(|c| c.with_aead(Aead::Aes256GcmSiv))
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 5 + V2_HEADER_SIZE + TAG_SIZE);
+}
+
+#[test]
+fn with_default_aead() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_aead(Aead::XChaCha20Poly1305))
+            .unwrap();
+    assert_eq!(cipher.out_len(), TEST_DATA.len() + HEADER_SIZE + TAG_SIZE);
+    let mut buf = [u8::default(); TEST_DATA.len() + HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 1);
+}
+
+#[test]
+fn success_with_key_commitment() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(Encryptor::with_key_commitment)
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 36 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..92], [36, 0, 0, 0, 0x03, 0x80, 32, 0]);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert!(cipher.has_key_commitment());
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn with_key_commitment_twice() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(Encryptor::with_key_commitment)
+            .and_then(Encryptor::with_key_commitment)
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 36 + V2_HEADER_SIZE + TAG_SIZE);
+}
+
+#[test]
+fn success_with_key_id() {
+    let key_id = KeyId::new("secret");
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_key_id(key_id))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 12 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..92], [12, 0, 0, 0, 0x07, 0x00, 8, 0]);
+    assert_eq!(&buf[92..100], key_id.as_bytes());
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert_eq!(cipher.key_id(), Some(key_id));
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn with_key_id_twice() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_key_id(KeyId::new("secret")))
+            .and_then(|c| c.with_key_id(KeyId::new("other")))
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 12 + V2_HEADER_SIZE + TAG_SIZE);
+}
+
+#[test]
+fn success_with_validity() {
+    let validity = Validity::new()
+        .with_not_before(Duration::from_secs(1))
+        .with_not_after(Duration::from_secs(2));
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_validity(validity))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 20 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..92], [20, 0, 0, 0, 0x08, 0x80, 16, 0]);
+    assert_eq!(
+        &buf[92..108],
+        [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE)
+        .unwrap()
+        .with_time(Duration::from_secs(2));
+    assert_eq!(cipher.validity(), validity);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "sharing")]
+#[test]
+fn success_with_file_key() {
+    let key = FileKey::generate();
+    let cipher = Encryptor::with_file_key(&TEST_DATA, &key);
+    let mut buf = [u8::default(); TEST_DATA.len() + 5 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 3);
+    assert_eq!(&buf[8..12], [4, 0, 0, 0]);
+    assert_eq!(&buf[12..28], [u8::default(); 16]);
+    assert!(matches!(Kdf::new(buf).unwrap(), Kdf::FileKey));
+
+    // The file key is not a passphrase.
+    let err = Decryptor::new(&buf, key.as_bytes()).unwrap_err();
+    assert_eq!(err, Error::UnsupportedKdf(4));
+
+    let cipher = Decryptor::with_file_key(&buf, &key).unwrap();
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn success_with_normalization() {
+    let cipher = Encryptor::with_normalization(
+        &TEST_DATA,
+        "cafe\u{301}",
+        Algorithm::default(),
+        Version::default(),
+        Params::new(32, 3, 4, None).unwrap(),
+        Normalization::Nfc,
+    )
+    .unwrap();
+    let mut buf = [u8::default(); 19 + 5 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..93], [5, 0, 0, 0, 0x06, 0x80, 1, 0, 0]);
+
+    // The passphrase is normalized in the same way when decrypting.
+    for passphrase in ["caf\u{e9}", "cafe\u{301}"] {
+        let cipher = Decryptor::new(&buf, passphrase).unwrap();
+        assert_eq!(cipher.normalization(), Some(Normalization::Nfc));
+        let mut buf = [u8::default(); 19];
+        let len = cipher.decrypt(&mut buf).unwrap();
+        assert_eq!(&buf[..len], TEST_DATA);
+    }
+    assert!(Decryptor::new(&buf, "cafe").is_err());
+}
+
+#[cfg(feature = "normalization")]
+#[test]
+fn success_with_nfkc_normalization() {
+    let cipher = Encryptor::with_normalization(
+        &TEST_DATA,
+        "\u{ff50}\u{ff41}\u{ff53}\u{ff53}phrase",
+        Algorithm::default(),
+        Version::default(),
+        Params::new(32, 3, 4, None).unwrap(),
+        Normalization::Nfkc,
+    )
+    .unwrap();
+    let mut buf = [u8::default(); 19 + 5 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(&buf[84..93], [5, 0, 0, 0, 0x06, 0x80, 1, 0, 1]);
+
+    // The full-width letters are the same as the ASCII ones.
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert_eq!(cipher.normalization(), Some(Normalization::Nfkc));
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn success_with_signature() {
+    let key = SigningKey::from_bytes(&[1; 32]);
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.sign_with(&key))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 36 + V2_HEADER_SIZE + TAG_SIZE + 64];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..92], [36, 0, 0, 0, 0x04, 0x80, 32, 0]);
+    assert_eq!(&buf[92..124], key.verifying_key().as_bytes());
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert!(cipher.has_signature());
+    assert!(cipher.verify_signature(&key.verifying_key()).is_ok());
+    assert_eq!(cipher.out_len(), 19);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn success_with_signature_and_segment_size() {
+    let key = SigningKey::from_bytes(&[1; 32]);
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+            .and_then(|c| c.sign_with(&key))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 8 + 36 + V2_HEADER_SIZE + 3 * TAG_SIZE + 64];
+    cipher.encrypt(&mut buf);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert!(cipher.verify_signature(&key.verifying_key()).is_ok());
+    assert_eq!(cipher.out_len(), 19);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn success_detached_with_signature() {
+    let key = SigningKey::from_bytes(&[1; 32]);
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.sign_with(&key))
+            .unwrap();
+    let mut header = [u8::default(); 36 + V2_HEADER_SIZE];
+    let mut ciphertext = [u8::default(); 19 + TAG_SIZE + 64];
+    cipher.encrypt_detached(&mut header, &mut ciphertext);
+
+    let cipher = Decryptor::from_parts(&header, &ciphertext, PASSPHRASE).unwrap();
+    assert!(cipher.verify_signature(&key.verifying_key()).is_ok());
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[cfg(feature = "ed25519")]
+#[test]
+fn sign_with_twice() {
+    let key = SigningKey::from_bytes(&[1; 32]);
+    let other = SigningKey::from_bytes(&[2; 32]);
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.sign_with(&key))
+            .and_then(|c| c.sign_with(&other))
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 36 + V2_HEADER_SIZE + TAG_SIZE + 64);
+    let mut buf = [u8::default(); 19 + 36 + V2_HEADER_SIZE + TAG_SIZE + 64];
+    cipher.encrypt(&mut buf);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert_eq!(
+        cipher.verify_signature(&key.verifying_key()).unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert!(cipher.verify_signature(&other.verifying_key()).is_ok());
+}
+
+#[test]
+fn success_with_segment_size() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 8 + V2_HEADER_SIZE + 3 * TAG_SIZE];
+    cipher.encrypt(&mut buf);
+    assert_eq!(buf[7], 2);
+    assert_eq!(&buf[84..96], [8, 0, 0, 0, 0x02, 0x80, 4, 0, 8, 0, 0, 0]);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    assert_eq!(cipher.segment_size(), Some(8));
+    assert_eq!(cipher.out_len(), 19);
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn success_with_segment_size_of_payload_length() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(19).unwrap()))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 8 + V2_HEADER_SIZE + TAG_SIZE];
+    cipher.encrypt(&mut buf);
+
+    let cipher = Decryptor::new(&buf, PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); 19];
+    let len = cipher.decrypt(&mut buf).unwrap();
+    assert_eq!(&buf[..len], TEST_DATA);
+}
+
+#[test]
+fn with_segment_size_twice() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(4).unwrap()))
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 8 + V2_HEADER_SIZE + 3 * TAG_SIZE);
+}
+
+#[test]
+#[should_panic(expected = "`buf` and the segmented payload have different lengths")]
+fn invalid_output_length_with_segment_size() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(NonZeroU32::new(8).unwrap()))
+            .unwrap();
+    let mut buf = [u8::default(); 19 + 8 + V2_HEADER_SIZE + 3 * TAG_SIZE - 1];
+    cipher.encrypt(&mut buf);
+}
+
+#[test]
+fn with_extension_with_reserved_type() {
+    for ty in [0x0000, 0x3fff, 0x8000, 0xbfff] {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .and_then(|c| c.with_extension(Extension::new(ty, &[])));
+        assert_eq!(cipher.unwrap_err(), Error::InvalidExtension);
+    }
+}
+
+#[test]
+fn with_extension_with_duplicate_type() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_extension(Extension::new(0x4000, &[])))
+            .and_then(|c| c.with_extension(Extension::new(0xc000, &[])));
+    assert_eq!(cipher.unwrap_err(), Error::InvalidExtension);
+}
+
 #[test]
 fn argon2_type() {
     {
@@ -444,6 +1041,66 @@ fn out_len() {
     assert_eq!(cipher.out_len(), TEST_DATA.len() + HEADER_SIZE + TAG_SIZE);
 }
 
+#[test]
+fn out_len_with_padding() {
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_padding(Padding::Padme);
+        assert_eq!(cipher.out_len(), 20 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_padding(Padding::Multiple(NonZeroUsize::new(512).unwrap()));
+        assert_eq!(cipher.out_len(), 512 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+    {
+        let cipher = Encryptor::with_params(&[], PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .unwrap()
+            .with_padding(Padding::Padme);
+        assert_eq!(cipher.out_len(), 5 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+}
+
+#[test]
+fn out_len_with_extension() {
+    let cipher =
+        Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_extension(Extension::new(0x4000, b"abc")))
+            .unwrap();
+    assert_eq!(cipher.out_len(), 19 + 7 + V2_HEADER_SIZE + TAG_SIZE);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn out_len_with_metadata() {
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_metadata(&Metadata::new());
+        assert_eq!(cipher.out_len(), 19 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_metadata(&Metadata::new().with_mode(0o644));
+        assert_eq!(cipher.out_len(), 29 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+    {
+        let cipher =
+            Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+                .unwrap()
+                .with_metadata(&Metadata::new().with_mode(0o644))
+                .with_padding(Padding::Padme);
+        assert_eq!(cipher.out_len(), 30 + V2_HEADER_SIZE + TAG_SIZE);
+    }
+}
+
 #[cfg(feature = "alloc")]
 #[test]
 fn success_convenience_function() {
@@ -492,6 +1149,7 @@ fn success_convenience_function_with_params() {
 
 #[cfg(feature = "alloc")]
 #[test]
+#[allow(clippy::too_many_lines)]
 fn success_convenience_function_with_context() {
     {
         let ciphertext = abcrypt::encrypt_with_context(
@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use abcrypt::{Error, FormatVersion, argon2};
+
+const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
+// Generated using `abcrypt` crate version 0.4.0.
+const TEST_DATA_ENC: &[u8] = include_bytes!("data/v1/argon2id/v0x13/data.txt.abcrypt");
+
+#[test]
+fn detect() {
+    assert_eq!(
+        abcrypt::detect(include_bytes!("data/v0/data.txt.abcrypt")),
+        Some(FormatVersion::V0)
+    );
+    assert_eq!(abcrypt::detect(TEST_DATA_ENC), Some(FormatVersion::V1));
+    assert_eq!(
+        abcrypt::detect(include_bytes!("data/v2/data.txt.abcrypt")),
+        Some(FormatVersion::V2)
+    );
+}
+
+#[test]
+fn detect_with_armor() {
+    assert_eq!(
+        abcrypt::detect(include_bytes!("data/armor/data.txt.abcrypt.asc")),
+        Some(FormatVersion::V1)
+    );
+
+    let mut data = *include_bytes!("data/armor/data.txt.abcrypt.asc");
+    data[40] = b'*';
+    assert_eq!(abcrypt::detect(data), None);
+}
+
+#[test]
+fn detect_with_invalid_data() {
+    assert_eq!(abcrypt::detect([]), None);
+    assert_eq!(abcrypt::detect(b"abcrypt"), None);
+    assert_eq!(abcrypt::detect(TEST_DATA), None);
+    assert_eq!(
+        abcrypt::detect(include_bytes!("data/scrypt/data.txt.scrypt")),
+        None
+    );
+
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    data[7] = 4;
+    assert_eq!(abcrypt::detect(data), None);
+}
+
+#[test]
+fn detect_with_truncated_data() {
+    assert_eq!(
+        abcrypt::detect(&TEST_DATA_ENC[..8]),
+        Some(FormatVersion::V1)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect() {
+    let report = abcrypt::inspect(TEST_DATA_ENC);
+    assert_eq!(report.version(), Some(FormatVersion::V1));
+    assert!(report.problems().is_empty());
+    assert!(report.is_valid());
+
+    let report = abcrypt::inspect(include_bytes!("data/v2/data.txt.abcrypt"));
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert!(report.is_valid());
+
+    let report = abcrypt::inspect(include_bytes!("data/v2/segmented/data.txt.abcrypt"));
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert!(report.is_valid());
+
+    let report = abcrypt::inspect(include_bytes!("data/v2/signed/data.txt.abcrypt"));
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert!(report.is_valid());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_armor() {
+    let report = abcrypt::inspect(include_bytes!("data/armor/data.txt.abcrypt.asc"));
+    assert_eq!(report.version(), Some(FormatVersion::V1));
+    assert!(report.is_valid());
+
+    let mut data = *include_bytes!("data/armor/data.txt.abcrypt.asc");
+    data[40] = b'*';
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), None);
+    assert_eq!(report.problems(), [Error::InvalidArmor]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_invalid_magic_number() {
+    let report = abcrypt::inspect(TEST_DATA);
+    assert_eq!(report.version(), None);
+    assert_eq!(
+        report.problems(),
+        [
+            Error::InvalidLength { len: 14, min: 164 },
+            Error::InvalidMagicNumber
+        ]
+    );
+
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    data[0] = u32::from('A').try_into().unwrap();
+    data[8] = 3;
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), None);
+    assert_eq!(report.problems(), [Error::InvalidMagicNumber]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_unsupported_version() {
+    let report = abcrypt::inspect(include_bytes!("data/v0/data.txt.abcrypt"));
+    assert_eq!(report.version(), Some(FormatVersion::V0));
+    assert_eq!(report.problems(), [Error::UnsupportedVersion(0)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_unknown_version() {
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    data[7] = 4;
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), None);
+    assert_eq!(report.problems(), [Error::UnknownVersion(4)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_every_invalid_field() {
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    data[7] = u8::MAX;
+    data[8..12].copy_from_slice(&u32::to_le_bytes(3));
+    data[12..16].copy_from_slice(&u32::to_le_bytes(0x14));
+    data[24..28].copy_from_slice(&u32::to_le_bytes(u32::MIN));
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), None);
+    assert_eq!(
+        report.problems(),
+        [
+            Error::UnknownVersion(u8::MAX),
+            Error::InvalidArgon2Type(3),
+            Error::InvalidArgon2Version(0x14),
+            Error::InvalidArgon2Params(argon2::Error::ThreadsTooFew)
+        ]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_truncated_data() {
+    let report = abcrypt::inspect(&TEST_DATA_ENC[..(TEST_DATA_ENC.len() - 30)]);
+    assert_eq!(report.version(), Some(FormatVersion::V1));
+    assert_eq!(
+        report.problems(),
+        [Error::InvalidLength { len: 148, min: 164 }]
+    );
+
+    let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+    data[8..12].copy_from_slice(&u32::to_le_bytes(3));
+    let report = abcrypt::inspect(&data[..100]);
+    assert_eq!(
+        report.problems(),
+        [
+            Error::InvalidLength { len: 100, min: 164 },
+            Error::InvalidArgon2Type(3)
+        ]
+    );
+
+    let report = abcrypt::inspect(&data[..40]);
+    assert_eq!(report.version(), Some(FormatVersion::V1));
+    assert_eq!(
+        report.problems(),
+        [Error::InvalidLength { len: 40, min: 164 }]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_invalid_extensions() {
+    let mut data = *include_bytes!("data/v2/data.txt.abcrypt");
+
+    {
+        data[84..88].copy_from_slice(&u32::to_le_bytes(1025));
+        let report = abcrypt::inspect(data);
+        assert_eq!(report.version(), Some(FormatVersion::V2));
+        assert_eq!(report.problems(), [Error::InvalidExtension]);
+    }
+
+    {
+        data[84..88].copy_from_slice(&u32::to_le_bytes(11));
+        data[89] = 0xc0;
+        data[12..16].copy_from_slice(&u32::to_le_bytes(0x11));
+        let report = abcrypt::inspect(data);
+        assert_eq!(
+            report.problems(),
+            [
+                Error::InvalidArgon2Version(0x11),
+                Error::UnknownCriticalExtension(0xc000)
+            ]
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_invalid_key_commitment() {
+    let mut data = *include_bytes!("data/v2/data.txt.abcrypt");
+    // The key commitment must be 32 bytes.
+    data[88..90].copy_from_slice(&u16::to_le_bytes(0x8003));
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert_eq!(report.problems(), [Error::InvalidExtension]);
+}
+
+#[cfg(all(feature = "alloc", not(feature = "aes-gcm-siv")))]
+#[test]
+fn inspect_with_unsupported_aead() {
+    let report = abcrypt::inspect(include_bytes!("data/v2/aes-256-gcm-siv/data.txt.abcrypt"));
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert_eq!(report.problems(), [Error::UnsupportedAead(1)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_truncated_segments() {
+    let data = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+    // The last segment is shorter than the tag.
+    let report = abcrypt::inspect(&data[..(data.len() - 10)]);
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert_eq!(
+        report.problems(),
+        [Error::InvalidLength { len: 266, min: 273 }]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_truncated_signature() {
+    let data = include_bytes!("data/v2/signed/data.txt.abcrypt");
+    let report = abcrypt::inspect(&data[..(data.len() - 64)]);
+    assert_eq!(report.version(), Some(FormatVersion::V2));
+    assert_eq!(
+        report.problems(),
+        [Error::InvalidLength { len: 223, min: 268 }]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_invalid_signature_extension() {
+    let mut data = *include_bytes!("data/v2/signed/data.txt.abcrypt");
+    data[89] = 0x00;
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.problems(), [Error::InvalidExtension]);
+}
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use abcrypt::{Error, Kdf, argon2::Algorithm};
+
+// Generated using `abcrypt` crate version 0.4.0.
+const TEST_DATA_ENC: &[u8] = include_bytes!("data/v1/argon2id/v0x13/data.txt.abcrypt");
+const TEST_DATA_SCRYPT_ENC: &[u8] = include_bytes!("data/v3/scrypt/data.txt.abcrypt");
+
+#[test]
+fn success() {
+    let kdf = Kdf::new(TEST_DATA_ENC).unwrap();
+    assert!(matches!(kdf, Kdf::Argon2(argon2) if argon2.variant() == Algorithm::Argon2id));
+}
+
+#[test]
+fn success_with_armor() {
+    let kdf = Kdf::new(include_bytes!("data/armor/data.txt.abcrypt.asc")).unwrap();
+    assert!(matches!(kdf, Kdf::Argon2(_)));
+}
+
+#[cfg(feature = "scrypt")]
+#[test]
+fn success_with_scrypt() {
+    let Kdf::Scrypt(params) = Kdf::new(TEST_DATA_SCRYPT_ENC).unwrap() else {
+        unreachable!();
+    };
+    assert_eq!(params.log_n(), 10);
+    assert_eq!(params.r(), 8);
+    assert_eq!(params.p(), 1);
+    assert_eq!(format!("{params}"), "ln=10,r=8,p=1");
+}
+
+#[cfg(feature = "scrypt")]
+#[test]
+fn decrypt_with_scrypt() {
+    let cipher = abcrypt::Decryptor::new(&TEST_DATA_SCRYPT_ENC, "passphrase").unwrap();
+    assert!(matches!(cipher.kdf(), Kdf::Scrypt(_)));
+    assert_eq!(
+        cipher.decrypt_to_vec().unwrap(),
+        include_bytes!("data/data.txt")
+    );
+
+    let err = abcrypt::Decryptor::new(&TEST_DATA_SCRYPT_ENC, "password").unwrap_err();
+    assert_eq!(err, abcrypt::blake2::digest::MacError.into());
+}
+
+#[cfg(feature = "scrypt")]
+#[test]
+fn encrypt_with_scrypt() {
+    use abcrypt::{Decryptor, Encryptor, FormatVersion, Metadata, Padding, scrypt::Params};
+
+    let data = include_bytes!("data/data.txt");
+    let params = Params::new(10, 8, 2, Params::RECOMMENDED_LEN).unwrap();
+    let metadata = Metadata::new().with_file_name("data.txt");
+    let ciphertext = Encryptor::with_scrypt(data, "passphrase", params)
+        .map(|c| c.with_metadata(&metadata).with_padding(Padding::Padme))
+        .map(|c| c.encrypt_to_vec())
+        .unwrap();
+    assert_eq!(abcrypt::detect(&ciphertext), Some(FormatVersion::V3));
+    assert!(abcrypt::inspect(&ciphertext).is_valid());
+
+    let (plaintext, decrypted_metadata) = Decryptor::new(&ciphertext, "passphrase")
+        .and_then(|c| c.decrypt_to_vec_with_metadata())
+        .unwrap();
+    assert_eq!(plaintext, data);
+    assert_eq!(decrypted_metadata, metadata);
+}
+
+#[cfg(feature = "scrypt")]
+#[test]
+fn argon2_with_scrypt() {
+    assert_eq!(
+        abcrypt::Argon2::new(TEST_DATA_SCRYPT_ENC).unwrap_err(),
+        Error::UnsupportedKdf(3)
+    );
+    assert_eq!(
+        abcrypt::Params::new(TEST_DATA_SCRYPT_ENC).unwrap_err(),
+        Error::UnsupportedKdf(3)
+    );
+    assert_eq!(
+        abcrypt::ScryptParams::new(TEST_DATA_ENC).unwrap_err(),
+        Error::UnsupportedKdf(2)
+    );
+}
+
+#[cfg(feature = "scrypt")]
+#[test]
+fn invalid_scrypt_params() {
+    let mut data: [u8; TEST_DATA_SCRYPT_ENC.len()] = TEST_DATA_SCRYPT_ENC.try_into().unwrap();
+    data[12..16].copy_from_slice(&64_u32.to_le_bytes());
+    assert_eq!(
+        Kdf::new(data).unwrap_err(),
+        Error::InvalidScryptParams {
+            log_n: 64,
+            r: 8,
+            p: 1
+        }
+    );
+
+    data[12..16].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(
+        Kdf::new(data).unwrap_err(),
+        Error::InvalidScryptParams {
+            log_n: u8::MAX,
+            r: 8,
+            p: 1
+        }
+    );
+
+    data[12..16].copy_from_slice(&10_u32.to_le_bytes());
+    data[20..24].copy_from_slice(&u32::MIN.to_le_bytes());
+    assert_eq!(
+        Kdf::new(data).unwrap_err(),
+        Error::InvalidScryptParams {
+            log_n: 10,
+            r: 8,
+            p: 0
+        }
+    );
+}
+
+#[cfg(not(feature = "scrypt"))]
+#[test]
+fn unsupported_scrypt() {
+    assert_eq!(
+        Kdf::new(TEST_DATA_SCRYPT_ENC).unwrap_err(),
+        Error::UnsupportedKdf(3)
+    );
+}
+
+#[test]
+fn unsupported_kdf() {
+    let mut data: [u8; TEST_DATA_SCRYPT_ENC.len()] = TEST_DATA_SCRYPT_ENC.try_into().unwrap();
+    data[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(Kdf::new(data).unwrap_err(), Error::UnsupportedKdf(u32::MAX));
+}
+
+#[test]
+fn argon2_in_version_3() {
+    let mut data: [u8; TEST_DATA_SCRYPT_ENC.len()] = TEST_DATA_SCRYPT_ENC.try_into().unwrap();
+    data[8..28].copy_from_slice(&TEST_DATA_ENC[8..28]);
+    let Kdf::Argon2(argon2) = Kdf::new(data).unwrap() else {
+        unreachable!();
+    };
+    assert_eq!(argon2.variant(), Algorithm::Argon2id);
+    assert_eq!(argon2.params().memory_cost(), 32);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn inspect_with_unsupported_kdf() {
+    let mut data: [u8; TEST_DATA_SCRYPT_ENC.len()] = TEST_DATA_SCRYPT_ENC.try_into().unwrap();
+    data[8..12].copy_from_slice(&5_u32.to_le_bytes());
+    let report = abcrypt::inspect(data);
+    assert_eq!(report.version(), Some(abcrypt::FormatVersion::V3));
+    assert_eq!(report.problems(), [Error::UnsupportedKdf(5)]);
+}
+
+#[cfg(all(feature = "serde", feature = "scrypt"))]
+#[test]
+fn serialize() {
+    let kdf = Kdf::new(TEST_DATA_SCRYPT_ENC).unwrap();
+    assert_eq!(
+        serde_json::to_string(&kdf).unwrap(),
+        r#"{"scrypt":{"logN":10,"r":8,"p":1}}"#
+    );
+    let kdf = serde_json::from_str::<Kdf>(r#"{"scrypt":{"logN":10,"r":8,"p":1}}"#).unwrap();
+    assert!(matches!(kdf, Kdf::Scrypt(params) if params.log_n() == 10));
+    assert!(serde_json::from_str::<Kdf>(r#"{"scrypt":{"logN":64,"r":8,"p":1}}"#).is_err());
+}
@@ -0,0 +1,223 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "log")]
+
+use std::io::{self, Cursor, ErrorKind};
+
+use abcrypt::{
+    Decryptor, Encryptor, Error, SeekableDecryptor,
+    argon2::Params,
+    log::{LogReader, LogWriter},
+};
+
+const PASSPHRASE: &str = "passphrase";
+const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
+
+// The header with the record log extension.
+const HEADER_SIZE: usize = 156;
+
+fn params() -> Params {
+    Params::new(32, 3, 4, None).unwrap()
+}
+
+fn log(records: &[&[u8]], finish: bool) -> Vec<u8> {
+    let mut writer = LogWriter::new(Vec::new(), PASSPHRASE, params()).unwrap();
+    for record in records {
+        writer.append(record).unwrap();
+    }
+    if finish {
+        writer.finish().unwrap()
+    } else {
+        writer.into_inner()
+    }
+}
+
+fn read_all(log: &[u8]) -> io::Result<(Vec<Vec<u8>>, bool)> {
+    let mut reader = LogReader::new(log, PASSPHRASE)?;
+    let records = reader.by_ref().collect::<io::Result<_>>()?;
+    Ok((records, reader.is_finished()))
+}
+
+fn unwrap_error(err: &io::Error) -> &Error {
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    err.get_ref().unwrap().downcast_ref::<Error>().unwrap()
+}
+
+#[test]
+fn success() {
+    let log = log(&[TEST_DATA, b"", b"last"], true);
+    assert_eq!(
+        log.len(),
+        HEADER_SIZE + (4 + TEST_DATA.len() + 16) + (4 + 16) + (4 + 4 + 16) + (4 + 16)
+    );
+    let (records, finished) = read_all(&log).unwrap();
+    assert_eq!(records, [TEST_DATA, b"", b"last"]);
+    assert!(finished);
+}
+
+#[test]
+fn success_empty() {
+    let log = log(&[], true);
+    assert_eq!(log.len(), HEADER_SIZE + 4 + 16);
+    let (records, finished) = read_all(&log).unwrap();
+    assert!(records.is_empty());
+    assert!(finished);
+
+    let log = self::log(&[], false);
+    assert_eq!(log.len(), HEADER_SIZE);
+    let (records, finished) = read_all(&log).unwrap();
+    assert!(records.is_empty());
+    assert!(!finished);
+}
+
+#[test]
+fn success_unfinished() {
+    let log = log(&[b"first", b"second"], false);
+    let (records, finished) = read_all(&log).unwrap();
+    assert_eq!(records, [b"first".as_slice(), b"second"]);
+    assert!(!finished);
+}
+
+#[test]
+fn open() {
+    let mut inner = Cursor::new(log(&[b"first"], false));
+    let mut writer = LogWriter::open(&mut inner, PASSPHRASE).unwrap();
+    writer.append(b"second").unwrap();
+    writer.finish().unwrap();
+
+    let (records, finished) = read_all(inner.get_ref()).unwrap();
+    assert_eq!(records, [b"first".as_slice(), b"second"]);
+    assert!(finished);
+}
+
+#[test]
+fn open_finished() {
+    let mut inner = Cursor::new(log(&[b"first"], true));
+    let err = LogWriter::open(&mut inner, PASSPHRASE).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn open_with_truncated_record() {
+    let mut log = log(&[b"first"], false);
+    log.pop();
+    let mut inner = Cursor::new(log);
+    let err = LogWriter::open(&mut inner, PASSPHRASE).unwrap_err();
+    assert!(matches!(unwrap_error(&err), Error::InvalidLength { .. }));
+}
+
+#[test]
+fn truncated_log() {
+    // Dropping the final record is detected.
+    let mut log = log(&[b"first", b"second"], true);
+    log.truncate(log.len() - (4 + 16));
+    let (records, finished) = read_all(&log).unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(!finished);
+
+    // Truncating in the middle of a record is an error.
+    log.pop();
+    let err = read_all(&log).unwrap_err();
+    assert_eq!(
+        unwrap_error(&err),
+        &Error::InvalidLength {
+            len: log.len(),
+            min: log.len() + 1
+        }
+    );
+    log.truncate(HEADER_SIZE + 2);
+    let err = read_all(&log).unwrap_err();
+    assert_eq!(
+        unwrap_error(&err),
+        &Error::InvalidLength {
+            len: HEADER_SIZE + 2,
+            min: HEADER_SIZE + 4
+        }
+    );
+}
+
+#[test]
+fn reordered_records() {
+    let log = log(&[b"first", b"other"], true);
+    let first = HEADER_SIZE..(HEADER_SIZE + 4 + 5 + 16);
+    let second = first.end..(first.end + 4 + 5 + 16);
+    let mut reordered = log[..HEADER_SIZE].to_vec();
+    reordered.extend_from_slice(&log[second]);
+    reordered.extend_from_slice(&log[first]);
+    reordered.extend_from_slice(&log[(HEADER_SIZE + 2 * (4 + 5 + 16))..]);
+    let err = read_all(&reordered).unwrap_err();
+    assert_eq!(
+        unwrap_error(&err),
+        &Error::InvalidMac {
+            offset: HEADER_SIZE as u64
+        }
+    );
+}
+
+#[test]
+fn tampered_record() {
+    let mut log = log(&[b"first", b"second"], true);
+    let offset = HEADER_SIZE + 4 + 5 + 16;
+    log[offset + 4] ^= 1;
+    let mut reader = LogReader::new(log.as_slice(), PASSPHRASE).unwrap();
+    assert_eq!(reader.read_record().unwrap().unwrap(), b"first");
+    let err = reader.read_record().unwrap_err();
+    assert_eq!(
+        unwrap_error(&err),
+        &Error::InvalidMac {
+            offset: offset as u64
+        }
+    );
+}
+
+#[test]
+fn data_after_final_record() {
+    let mut log = log(&[b"first"], true);
+    log.extend_from_within(HEADER_SIZE..(HEADER_SIZE + 4 + 5 + 16));
+    let err = read_all(&log).unwrap_err();
+    assert_eq!(unwrap_error(&err), &Error::InvalidRecordLog);
+}
+
+#[test]
+fn incorrect_passphrase() {
+    let log = log(&[b"first"], true);
+    let err = LogReader::new(log.as_slice(), "password").unwrap_err();
+    assert!(matches!(unwrap_error(&err), Error::InvalidHeaderMac(_)));
+}
+
+#[test]
+fn not_record_log() {
+    let ciphertext = Encryptor::with_params(&TEST_DATA, PASSPHRASE, params())
+        .map(|c| c.encrypt_to_vec())
+        .unwrap();
+    let err = LogReader::new(ciphertext.as_slice(), PASSPHRASE).unwrap_err();
+    assert_eq!(unwrap_error(&err), &Error::InvalidRecordLog);
+}
+
+#[test]
+fn invalid_header() {
+    let err = LogReader::new([].as_slice(), PASSPHRASE).unwrap_err();
+    assert_eq!(
+        unwrap_error(&err),
+        &Error::InvalidLength { len: 0, min: 148 }
+    );
+
+    let mut log = log(&[b"first"], true);
+    log[0] = b'b';
+    let err = LogReader::new(log.as_slice(), PASSPHRASE).unwrap_err();
+    assert_eq!(unwrap_error(&err), &Error::InvalidMagicNumber);
+}
+
+#[test]
+fn decrypt_record_log() {
+    let log = log(&[b"first"], true);
+    assert_eq!(
+        Decryptor::new(&log, PASSPHRASE).unwrap_err(),
+        Error::InvalidRecordLog
+    );
+    let err = SeekableDecryptor::new(Cursor::new(&log), PASSPHRASE).unwrap_err();
+    assert_eq!(unwrap_error(&err), &Error::InvalidRecordLog);
+    assert!(abcrypt::inspect(&log).is_valid());
+}
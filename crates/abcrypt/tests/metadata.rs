@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "alloc")]
+
+use core::time::Duration;
+
+use abcrypt::{Decryptor, Metadata};
+
+const PASSPHRASE: &str = "passphrase";
+const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/data.txt.abcrypt");
+
+#[test]
+fn default() {
+    assert_eq!(Metadata::default(), Metadata::new());
+    assert!(Metadata::default().is_empty());
+}
+
+#[test]
+fn decrypted() {
+    let (_, metadata) = Decryptor::new(&TEST_DATA_ENC, PASSPHRASE)
+        .and_then(|c| c.decrypt_to_vec_with_metadata())
+        .unwrap();
+    assert_eq!(
+        metadata,
+        Metadata::new()
+            .with_file_name("data.txt")
+            .with_modified(Duration::from_secs(1_767_225_600))
+            .with_mode(0o644)
+            .with_content_type("text/plain")
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize() {
+    use serde_test::{Token, assert_ser_tokens};
+
+    assert_ser_tokens(
+        &Metadata::new().with_file_name("data.txt").with_mode(0o644),
+        &[
+            Token::Struct {
+                name: "Metadata",
+                len: 4,
+            },
+            Token::Str("fileName"),
+            Token::Some,
+            Token::Str("data.txt"),
+            Token::Str("modified"),
+            Token::None,
+            Token::Str("mode"),
+            Token::Some,
+            Token::U32(0o644),
+            Token::Str("contentType"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_json() {
+    let metadata = Metadata::new()
+        .with_file_name("data.txt")
+        .with_modified(Duration::new(1_767_225_600, 5));
+    assert_eq!(
+        serde_json::to_string(&metadata).unwrap(),
+        r#"{"fileName":"data.txt","modified":{"secs":1767225600,"nanos":5},"mode":null,"contentType":null}"#
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize() {
+    use serde_test::{Token, assert_de_tokens};
+
+    assert_de_tokens(
+        &Metadata::new().with_file_name("data.txt").with_mode(0o644),
+        &[
+            Token::Struct {
+                name: "Metadata",
+                len: 4,
+            },
+            Token::Str("fileName"),
+            Token::Some,
+            Token::Str("data.txt"),
+            Token::Str("modified"),
+            Token::None,
+            Token::Str("mode"),
+            Token::Some,
+            Token::U32(0o644),
+            Token::Str("contentType"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json() {
+    let metadata = Metadata::new()
+        .with_file_name("data.txt")
+        .with_modified(Duration::new(1_767_225_600, 5));
+    assert_eq!(
+        serde_json::from_str::<Metadata>(
+            r#"{"fileName":"data.txt","modified":{"secs":1767225600,"nanos":5},"mode":null,"contentType":null}"#
+        )
+        .unwrap(),
+        metadata
+    );
+}
@@ -13,6 +13,22 @@ fn success() {
     assert!(params.is_ok());
 }
 
+#[test]
+fn success_with_armor() {
+    let params = Params::new(include_bytes!("data/armor/data.txt.abcrypt.asc")).unwrap();
+    assert_eq!(params.memory_cost(), 32);
+    assert_eq!(params.time_cost(), 3);
+    assert_eq!(params.parallelism(), 4);
+}
+
+#[test]
+fn invalid_armor() {
+    let mut data = *include_bytes!("data/armor/data.txt.abcrypt.asc");
+    data[40] = b'*';
+    let err = Params::new(data).unwrap_err();
+    assert_eq!(err, abcrypt::Error::InvalidArmor);
+}
+
 #[test]
 fn memory_cost() {
     {
@@ -100,6 +116,65 @@ fn parallelism() {
     }
 }
 
+#[test]
+fn display() {
+    {
+        let params = Params::new(include_bytes!("data/v1/argon2d/v0x10/data.txt.abcrypt")).unwrap();
+        assert_eq!(format!("{params}"), "m=47104,t=1,p=1");
+    }
+    {
+        let params = Params::new(TEST_DATA_ENC).unwrap();
+        assert_eq!(format!("{params}"), "m=32,t=3,p=4");
+    }
+}
+
+#[test]
+fn from_str() {
+    let params: Params = "m=19456,t=2,p=1".parse().unwrap();
+    assert_eq!(params.memory_cost(), 19456);
+    assert_eq!(params.time_cost(), 2);
+    assert_eq!(params.parallelism(), 1);
+}
+
+#[test]
+fn from_str_round_trip() {
+    let params = Params::new(TEST_DATA_ENC).unwrap();
+    let parsed: Params = params.to_string().parse().unwrap();
+    assert_eq!(parsed.memory_cost(), params.memory_cost());
+    assert_eq!(parsed.time_cost(), params.time_cost());
+    assert_eq!(parsed.parallelism(), params.parallelism());
+}
+
+#[test]
+fn from_str_with_invalid_phc_string() {
+    for s in [
+        "",
+        "m=19456,t=2",
+        "m=19456,t=2,p=1,",
+        "m=19456,t=2,p=1,data=AA",
+        "t=2,m=19456,p=1",
+        "m=19456,t=2,p=",
+        "m=19456,t=2,p=+1",
+        "m=019456,t=2,p=1",
+        "m=19456,t=two,p=1",
+        "m=4294967296,t=2,p=1",
+        "m:19456,t:2,p:1",
+        "$argon2id$v=19$m=19456,t=2,p=1",
+    ] {
+        let err = s.parse::<Params>().unwrap_err();
+        assert_eq!(err, abcrypt::Error::InvalidPhcString, "{s}");
+    }
+}
+
+#[test]
+fn from_str_with_invalid_argon2_params() {
+    let err = "m=19456,t=0,p=1".parse::<Params>().unwrap_err();
+    assert_eq!(
+        err,
+        abcrypt::Error::InvalidArgon2Params(abcrypt::argon2::Error::TimeTooSmall)
+    );
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn serialize() {
@@ -132,3 +207,22 @@ fn serialize_json() {
         r#"{"memoryCost":32,"timeCost":3,"parallelism":4}"#
     );
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json() {
+    let params: Params =
+        serde_json::from_str(r#"{"memoryCost":19456,"timeCost":2,"parallelism":1}"#).unwrap();
+    assert_eq!(params.memory_cost(), 19456);
+    assert_eq!(params.time_cost(), 2);
+    assert_eq!(params.parallelism(), 1);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_json_with_invalid_argon2_params() {
+    let err = serde_json::from_str::<Params>(r#"{"memoryCost":7,"timeCost":2,"parallelism":1}"#)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "invalid Argon2 parameters");
+    assert!(serde_json::from_str::<Params>(r#"{"memoryCost":19456,"timeCost":2}"#).is_err());
+}
@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "scrypt-compat")]
+
+use abcrypt::{
+    Decryptor, Error,
+    argon2::Params,
+    blake2::digest::MacError,
+    scrypt_compat::{self, HEADER_SIZE, TAG_SIZE},
+};
+
+const PASSPHRASE: &str = "passphrase";
+const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
+// Generated using the reference implementation of the scrypt encrypted data
+// format with `logN = 10`, `r = 8` and `p = 1`.
+const TEST_DATA_ENC: &[u8] = include_bytes!("data/scrypt/data.txt.scrypt");
+
+#[test]
+fn is_scrypt() {
+    assert!(scrypt_compat::is_scrypt(TEST_DATA_ENC));
+    assert!(!scrypt_compat::is_scrypt(include_bytes!(
+        "data/v1/argon2id/v0x13/data.txt.abcrypt"
+    )));
+    assert!(!scrypt_compat::is_scrypt([]));
+}
+
+#[test]
+fn success() {
+    let plaintext = scrypt_compat::decrypt(TEST_DATA_ENC, PASSPHRASE).unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[test]
+fn incorrect_passphrase() {
+    let err = scrypt_compat::decrypt(TEST_DATA_ENC, "password").unwrap_err();
+    assert_eq!(err, Error::InvalidHeaderMac(MacError));
+}
+
+#[test]
+fn invalid_length() {
+    let data = [u8::default(); (HEADER_SIZE + TAG_SIZE) - 1];
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidLength {
+            len: (HEADER_SIZE + TAG_SIZE) - 1,
+            min: HEADER_SIZE + TAG_SIZE
+        }
+    );
+}
+
+#[test]
+fn invalid_magic_number() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[0] = u32::from('A').try_into().unwrap();
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidMagicNumber);
+}
+
+#[test]
+fn unknown_version() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[6] = 1;
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::UnknownVersion(1));
+}
+
+#[test]
+fn invalid_params() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[7] = 64;
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidScryptParams {
+            log_n: 64,
+            r: 8,
+            p: 1
+        }
+    );
+}
+
+#[test]
+fn invalid_checksum() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[48] ^= 1;
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidHeaderMac(MacError));
+}
+
+#[test]
+fn invalid_header_mac() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[64] ^= 1;
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(err, Error::InvalidHeaderMac(MacError));
+}
+
+#[test]
+fn invalid_mac() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    data[HEADER_SIZE] ^= 1;
+    let err = scrypt_compat::decrypt(data, PASSPHRASE).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidMac {
+            offset: HEADER_SIZE as u64
+        }
+    );
+}
+
+#[test]
+fn convert() {
+    let ciphertext = scrypt_compat::convert(
+        TEST_DATA_ENC,
+        PASSPHRASE,
+        Params::new(32, 3, 4, None).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(&ciphertext[..7], b"abcrypt");
+    assert_eq!(ciphertext[7], 1);
+
+    let cipher = Decryptor::new(&ciphertext, PASSPHRASE).unwrap();
+    assert_eq!(cipher.decrypt_to_vec().unwrap(), TEST_DATA);
+}
+
+#[test]
+fn convert_with_incorrect_passphrase() {
+    let err = scrypt_compat::convert(
+        TEST_DATA_ENC,
+        "password",
+        Params::new(32, 3, 4, None).unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(err, Error::InvalidHeaderMac(MacError));
+}
@@ -0,0 +1,202 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "std")]
+
+use std::{
+    io::{Cursor, ErrorKind, Read, Seek, SeekFrom},
+    time::Duration,
+};
+
+use abcrypt::{Encryptor, Error, SeekableDecryptor, Validity, argon2::Params};
+
+const PASSPHRASE: &str = "passphrase";
+const TEST_DATA: &[u8] = include_bytes!("data/data.txt");
+const TEST_DATA_ENC: &[u8] = include_bytes!("data/v2/segmented/data.txt.abcrypt");
+
+#[test]
+fn success() {
+    let mut cipher = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), PASSPHRASE).unwrap();
+    assert_eq!(cipher.len(), TEST_DATA.len() as u64);
+    let mut buf = Vec::new();
+    cipher.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, TEST_DATA);
+}
+
+#[test]
+fn success_with_non_segmented_payload() {
+    for ciphertext in [
+        include_bytes!("data/v1/argon2id/v0x13/data.txt.abcrypt").as_slice(),
+        include_bytes!("data/v2/data.txt.abcrypt"),
+        include_bytes!("data/v2/signed/data.txt.abcrypt"),
+    ] {
+        let mut cipher = SeekableDecryptor::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+        assert_eq!(cipher.len(), TEST_DATA.len() as u64);
+        let mut buf = Vec::new();
+        cipher.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, TEST_DATA);
+    }
+}
+
+#[test]
+fn success_with_empty_plaintext() {
+    let ciphertext = Encryptor::with_params(&[], PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+        .map(|c| c.encrypt_to_vec())
+        .unwrap();
+
+    let mut cipher = SeekableDecryptor::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    assert!(cipher.is_empty());
+    let mut buf = Vec::new();
+    assert_eq!(cipher.read_to_end(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn success_with_segment_size_of_one() {
+    let data = (u8::MIN..=u8::MAX).collect::<Vec<_>>();
+    let ciphertext =
+        Encryptor::with_params(&data, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+            .and_then(|c| c.with_segment_size(1.try_into().unwrap()))
+            .map(|c| c.encrypt_to_vec())
+            .unwrap();
+
+    let mut cipher = SeekableDecryptor::new(Cursor::new(ciphertext), PASSPHRASE).unwrap();
+    assert_eq!(cipher.len(), 256);
+    let mut buf = Vec::new();
+    cipher.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, data);
+
+    cipher.seek(SeekFrom::Start(100)).unwrap();
+    let mut buf = [u8::default(); 10];
+    cipher.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, data[100..110]);
+}
+
+#[test]
+fn seek() {
+    let mut cipher = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), PASSPHRASE).unwrap();
+    let mut buf = [u8::default(); 5];
+
+    assert_eq!(cipher.seek(SeekFrom::Start(7)).unwrap(), 7);
+    cipher.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"world");
+
+    assert_eq!(cipher.seek(SeekFrom::Current(-12)).unwrap(), 0);
+    cipher.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"Hello");
+
+    assert_eq!(cipher.seek(SeekFrom::End(-2)).unwrap(), 12);
+    let mut buf = Vec::new();
+    cipher.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"!\n");
+}
+
+#[test]
+fn seek_beyond_end() {
+    let mut cipher = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), PASSPHRASE).unwrap();
+    assert_eq!(cipher.seek(SeekFrom::End(1)).unwrap(), 15);
+    let mut buf = [u8::default(); 5];
+    assert_eq!(cipher.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn seek_before_start() {
+    let mut cipher = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), PASSPHRASE).unwrap();
+    let err = cipher.seek(SeekFrom::Current(-1)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn incorrect_passphrase() {
+    let err = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), "password").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(matches!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        Error::InvalidHeaderMac(_)
+    ));
+}
+
+#[test]
+fn invalid_mac() {
+    let mut data = TEST_DATA_ENC.to_vec();
+    // The third segment contains the start of the plaintext.
+    data[208] ^= 1;
+    let mut cipher = SeekableDecryptor::new(Cursor::new(data), PASSPHRASE).unwrap();
+
+    // The segments which are not touched are not authenticated.
+    cipher.seek(SeekFrom::End(-5)).unwrap();
+    let mut buf = [u8::default(); 5];
+    cipher.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"rld!\n");
+
+    cipher.rewind().unwrap();
+    let err = cipher.read(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(
+        err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+        &Error::InvalidMac { offset: 208 }
+    );
+}
+
+fn encrypt_with_validity(validity: Validity) -> Vec<u8> {
+    Encryptor::with_params(&TEST_DATA, PASSPHRASE, Params::new(32, 3, 4, None).unwrap())
+        .and_then(|c| c.with_segment_size(4.try_into().unwrap()))
+        .and_then(|c| c.with_validity(validity))
+        .map(|c| c.encrypt_to_vec())
+        .unwrap()
+}
+
+#[test]
+fn success_with_validity() {
+    let ciphertext = encrypt_with_validity(
+        Validity::new()
+            .with_not_before(Duration::from_secs(100))
+            .with_not_after(Duration::from_secs(200)),
+    );
+    for now in [100, 150, 200] {
+        let mut cipher = SeekableDecryptor::new(Cursor::new(&ciphertext), PASSPHRASE)
+            .unwrap()
+            .with_time(Duration::from_secs(now));
+        let mut buf = Vec::new();
+        cipher.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, TEST_DATA);
+    }
+}
+
+#[test]
+fn expired() {
+    let ciphertext = encrypt_with_validity(
+        Validity::new()
+            .with_not_before(Duration::from_secs(100))
+            .with_not_after(Duration::from_secs(200)),
+    );
+    for now in [Duration::from_secs(99), Duration::new(200, 1)] {
+        let mut cipher = SeekableDecryptor::new(Cursor::new(&ciphertext), PASSPHRASE)
+            .unwrap()
+            .with_time(now);
+        let mut buf = [u8::default(); 5];
+        let err = cipher.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+            &Error::Expired
+        );
+    }
+
+    // The system time is after the not-after time.
+    {
+        let mut cipher = SeekableDecryptor::new(Cursor::new(&ciphertext), PASSPHRASE).unwrap();
+        let mut buf = [u8::default(); 5];
+        let err = cipher.read(&mut buf).unwrap_err();
+        assert_eq!(
+            err.get_ref().unwrap().downcast_ref::<Error>().unwrap(),
+            &Error::Expired
+        );
+    }
+}
+
+#[test]
+fn into_inner() {
+    let cipher = SeekableDecryptor::new(Cursor::new(TEST_DATA_ENC), PASSPHRASE).unwrap();
+    assert_eq!(cipher.into_inner().into_inner(), TEST_DATA_ENC);
+}
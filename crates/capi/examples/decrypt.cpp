@@ -44,6 +44,19 @@ int main(int argc, char *argv[]) {
       (std::istreambuf_iterator<char>(input_file)),
       std::istreambuf_iterator<char>());
 
+  std::uintptr_t out_len = 0;
+  auto error_code =
+      abcrypt_decrypt_out_len(ciphertext.data(), ciphertext.size(), &out_len);
+  if (error_code != ABCRYPT_ERROR_CODE_OK) {
+    std::vector<std::uint8_t> buf(abcrypt_error_message_out_len(error_code));
+    abcrypt_error_message(error_code, buf.data(), buf.size());
+    std::string error_message(buf.cbegin(), buf.cend());
+    std::println(stderr,
+                 "Error: the header in the encrypted data is invalid: {}",
+                 error_message);
+    return EXIT_FAILURE;
+  }
+
   struct termios term;
   struct termios old_term;
   tcgetattr(STDIN_FILENO, &term);
@@ -56,12 +69,12 @@ int main(int argc, char *argv[]) {
   std::println();
   tcsetattr(STDIN_FILENO, TCSANOW, &old_term);
 
-  std::vector<std::uint8_t> plaintext(ciphertext.size() -
-                                      (ABCRYPT_HEADER_SIZE + ABCRYPT_TAG_SIZE));
-  auto error_code =
-      abcrypt_decrypt(ciphertext.data(), ciphertext.size(),
-                      reinterpret_cast<uint8_t *>(passphrase.data()),
-                      passphrase.size(), plaintext.data(), plaintext.size());
+  std::vector<std::uint8_t> plaintext(out_len);
+  std::uintptr_t written = 0;
+  error_code = abcrypt_decrypt(
+      ciphertext.data(), ciphertext.size(),
+      reinterpret_cast<uint8_t *>(passphrase.data()), passphrase.size(),
+      plaintext.data(), plaintext.size(), &written);
   if (error_code != ABCRYPT_ERROR_CODE_OK) {
     std::vector<std::uint8_t> buf(abcrypt_error_message_out_len(error_code));
     abcrypt_error_message(error_code, buf.data(), buf.size());
@@ -83,6 +96,7 @@ int main(int argc, char *argv[]) {
     }
     return EXIT_FAILURE;
   }
+  plaintext.resize(written);
 
   if (output_filename) {
     auto ofn = output_filename.value();
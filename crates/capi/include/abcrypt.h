@@ -20,7 +20,7 @@ typedef enum abcrypt_error_code {
   ABCRYPT_ERROR_CODE_OK,
   // General error.
   ABCRYPT_ERROR_CODE_ERROR,
-  // The encrypted data was shorter than 164 bytes.
+  // The encrypted data was shorter than expected.
   ABCRYPT_ERROR_CODE_INVALID_LENGTH,
   // The magic number (file signature) was invalid.
   ABCRYPT_ERROR_CODE_INVALID_MAGIC_NUMBER,
@@ -40,6 +40,36 @@ typedef enum abcrypt_error_code {
   ABCRYPT_ERROR_CODE_INVALID_HEADER_MAC,
   // The MAC (authentication tag) of the ciphertext was invalid.
   ABCRYPT_ERROR_CODE_INVALID_MAC,
+  // The padding of the plaintext was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_PADDING,
+  // The ASCII armor was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_ARMOR,
+  // The metadata of the plaintext was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_METADATA,
+  // The extensions of the header were invalid.
+  ABCRYPT_ERROR_CODE_INVALID_EXTENSION,
+  // The header contained the unrecognized critical extension.
+  ABCRYPT_ERROR_CODE_UNKNOWN_CRITICAL_EXTENSION,
+  // The AEAD was unrecognized or not enabled by the feature flags.
+  ABCRYPT_ERROR_CODE_UNSUPPORTED_AEAD,
+  // The scrypt parameters were invalid.
+  ABCRYPT_ERROR_CODE_INVALID_SCRYPT_PARAMS,
+  // The PHC string was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_PHC_STRING,
+  // The output buffer had the wrong length.
+  ABCRYPT_ERROR_CODE_INVALID_OUTPUT_LENGTH,
+  // The key derivation function is unrecognized or not enabled.
+  ABCRYPT_ERROR_CODE_UNSUPPORTED_KDF,
+  // The key commitment of the header was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_KEY_COMMITMENT,
+  // The signature of the encrypted data was missing or invalid.
+  ABCRYPT_ERROR_CODE_INVALID_SIGNATURE,
+  // The record log was invalid.
+  ABCRYPT_ERROR_CODE_INVALID_RECORD_LOG,
+  // The passphrase normalization form is unrecognized or not enabled.
+  ABCRYPT_ERROR_CODE_UNSUPPORTED_NORMALIZATION,
+  // The encrypted data was used outside its validity period.
+  ABCRYPT_ERROR_CODE_EXPIRED,
 } abcrypt_error_code;
 
 // The Argon2 parameters used for the encrypted data.
@@ -55,6 +85,13 @@ extern "C" {
 
 // Decrypts `ciphertext` and write to `out`.
 //
+// `out_len` must be the number of output bytes returned by
+// `abcrypt_decrypt_out_len`. The number of bytes of the plaintext is written
+// to `written`. If the plaintext was padded or the metadata was encrypted
+// together when encrypting, the plaintext is written to the beginning of
+// `out`, and `written` is less than `out_len`. The contents of `out` after
+// the plaintext are unspecified.
+//
 // # Errors
 //
 // Returns an error if any of the following are true:
@@ -69,7 +106,9 @@ extern "C" {
 // - The Argon2 context is invalid.
 // - The MAC (authentication tag) of the header is invalid.
 // - The MAC (authentication tag) of the ciphertext is invalid.
+// - The padding of the plaintext is invalid.
 // - One of the parameters is null.
+// - `out_len` is not the number of output bytes of the decrypted data.
 //
 // # Safety
 //
@@ -79,12 +118,45 @@ extern "C" {
 // - `ciphertext` and `ciphertext_len`.
 // - `passphrase` and `passphrase_len`.
 // - `out` and `out_len`.
+//
+// Behavior is undefined if `written` is not valid for writes.
 enum abcrypt_error_code abcrypt_decrypt(uint8_t *ciphertext,
                                         uintptr_t ciphertext_len,
                                         uint8_t *passphrase,
                                         uintptr_t passphrase_len,
                                         uint8_t *out,
-                                        uintptr_t out_len);
+                                        uintptr_t out_len,
+                                        uintptr_t *written);
+
+// Gets the number of output bytes of the decrypted data of `ciphertext`.
+//
+// This only parses the header and does not derive the key, so this can be
+// used to allocate `out` of `abcrypt_decrypt` before reading the passphrase.
+// If the plaintext was padded or the metadata was encrypted together when
+// encrypting, this includes them, so this is an upper bound of the length of
+// the plaintext.
+//
+// # Errors
+//
+// Returns an error if any of the following are true:
+//
+// - `ciphertext` is shorter than 164 bytes.
+// - The magic number is invalid.
+// - The version number is the unsupported abcrypt version number.
+// - The version number is the unrecognized abcrypt version number.
+// - The Argon2 type is invalid.
+// - The Argon2 version is invalid.
+// - The Argon2 parameters are invalid.
+// - One of the parameters is null.
+//
+// # Safety
+//
+// Behavior is undefined if `ciphertext` and `ciphertext_len` violates the
+// safety conditions of `slice::from_raw_parts`, or `out_len` is not valid
+// for writes.
+enum abcrypt_error_code abcrypt_decrypt_out_len(uint8_t *ciphertext,
+                                                uintptr_t ciphertext_len,
+                                                uintptr_t *out_len);
 
 // Encrypts `plaintext` and write to `out`.
 //
@@ -98,6 +170,7 @@ enum abcrypt_error_code abcrypt_decrypt(uint8_t *ciphertext,
 //
 // - The Argon2 context is invalid.
 // - One of the parameters is null.
+// - `out_len` is not the number of output bytes of the encrypted data.
 //
 // # Safety
 //
@@ -127,6 +200,7 @@ enum abcrypt_error_code abcrypt_encrypt(uint8_t *plaintext,
 // - The Argon2 parameters are invalid.
 // - The Argon2 context is invalid.
 // - One of the parameters is null.
+// - `out_len` is not the number of output bytes of the encrypted data.
 //
 // # Safety
 //
@@ -158,6 +232,7 @@ enum abcrypt_error_code abcrypt_encrypt_with_params(uint8_t *plaintext,
 // - The Argon2 parameters are invalid.
 // - The Argon2 context is invalid.
 // - One of the parameters is null.
+// - `out_len` is not the number of output bytes of the encrypted data.
 //
 // # Safety
 //
@@ -179,11 +254,60 @@ enum abcrypt_error_code abcrypt_encrypt_with_context(uint8_t *plaintext,
                                                      uint32_t time_cost,
                                                      uint32_t parallelism);
 
+// Encrypts `plaintext` with the specified Argon2 parameters after normalizing
+// `passphrase` to the specified Unicode normalization form, and write to
+// `out`.
+//
+// `normalization` is 0 for NFC and 1 for NFKC. The normalization form is
+// stored in the header, and the passphrase is normalized in the same way when
+// decrypting. `out_len` must be the number of output bytes returned by
+// `abcrypt_encrypt_with_normalization_out_len`. When decrypting it, the
+// number of output bytes of `abcrypt_decrypt` is returned by
+// `abcrypt_decrypt_out_len`. This uses Argon2id as the Argon2 type and version
+// 0x13 as the Argon2 version.
+//
+// # Errors
+//
+// Returns an error if any of the following are true:
+//
+// - The normalization form is invalid.
+// - The Argon2 parameters are invalid.
+// - The Argon2 context is invalid.
+// - One of the parameters is null.
+// - `passphrase` is not valid UTF-8.
+// - `out_len` is not the number of output bytes of the encrypted data.
+//
+// # Safety
+//
+// Behavior is undefined if any of the following violates the safety conditions
+// of `slice::from_raw_parts`:
+//
+// - `plaintext` and `plaintext_len`.
+// - `passphrase` and `passphrase_len`.
+// - `out` and `out_len`.
+enum abcrypt_error_code abcrypt_encrypt_with_normalization(uint8_t *plaintext,
+                                                           uintptr_t plaintext_len,
+                                                           uint8_t *passphrase,
+                                                           uintptr_t passphrase_len,
+                                                           uint8_t *out,
+                                                           uintptr_t out_len,
+                                                           uint32_t memory_cost,
+                                                           uint32_t time_cost,
+                                                           uint32_t parallelism,
+                                                           uint32_t normalization);
+
+// Returns the number of output bytes of the encrypted data of `plaintext_len`
+// bytes of the plaintext encrypted by `abcrypt_encrypt_with_normalization`.
+uintptr_t abcrypt_encrypt_with_normalization_out_len(uintptr_t plaintext_len);
+
 // Gets a detailed error message.
 //
 // # Errors
 //
-// Returns an error if `buf` is null.
+// Returns an error if any of the following are true:
+//
+// - `buf` is null.
+// - `buf_len` is not the number of output bytes of the error message.
 //
 // # Safety
 //
@@ -13,6 +13,13 @@ use crate::ErrorCode;
 #[allow(clippy::module_name_repetitions)]
 /// Decrypts `ciphertext` and write to `out`.
 ///
+/// `out_len` must be the number of output bytes returned by
+/// `abcrypt_decrypt_out_len`. The number of bytes of the plaintext is written
+/// to `written`. If the plaintext was padded or the metadata was encrypted
+/// together when encrypting, the plaintext is written to the beginning of
+/// `out`, and `written` is less than `out_len`. The contents of `out` after
+/// the plaintext are unspecified.
+///
 /// # Errors
 ///
 /// Returns an error if any of the following are true:
@@ -27,7 +34,9 @@ use crate::ErrorCode;
 /// - The Argon2 context is invalid.
 /// - The MAC (authentication tag) of the header is invalid.
 /// - The MAC (authentication tag) of the ciphertext is invalid.
+/// - The padding of the plaintext is invalid.
 /// - One of the parameters is null.
+/// - `out_len` is not the number of output bytes of the decrypted data.
 ///
 /// # Safety
 ///
@@ -37,6 +46,8 @@ use crate::ErrorCode;
 /// - `ciphertext` and `ciphertext_len`.
 /// - `passphrase` and `passphrase_len`.
 /// - `out` and `out_len`.
+///
+/// Behavior is undefined if `written` is not valid for writes.
 #[unsafe(no_mangle)]
 pub unsafe extern "C-unwind" fn abcrypt_decrypt(
     ciphertext: Option<NonNull<u8>>,
@@ -45,6 +56,7 @@ pub unsafe extern "C-unwind" fn abcrypt_decrypt(
     passphrase_len: usize,
     out: Option<NonNull<u8>>,
     out_len: usize,
+    written: Option<NonNull<usize>>,
 ) -> ErrorCode {
     let Some(ciphertext) = ciphertext else {
         return ErrorCode::Error;
@@ -68,9 +80,69 @@ pub unsafe extern "C-unwind" fn abcrypt_decrypt(
     };
     // SAFETY: just checked that `out` is not a null pointer.
     let out = unsafe { slice::from_raw_parts_mut(out.as_ptr(), out_len) };
-    cipher
-        .decrypt(out)
-        .map_or_else(ErrorCode::from, |()| ErrorCode::Ok)
+    let Some(written) = written else {
+        return ErrorCode::Error;
+    };
+    match cipher.try_decrypt(out) {
+        Ok(len) => {
+            // SAFETY: just checked that `written` is not a null pointer.
+            unsafe { written.write(len) };
+            ErrorCode::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Gets the number of output bytes of the decrypted data of `ciphertext`.
+///
+/// This only parses the header and does not derive the key, so this can be
+/// used to allocate `out` of `abcrypt_decrypt` before reading the passphrase.
+/// If the plaintext was padded or the metadata was encrypted together when
+/// encrypting, this includes them, so this is an upper bound of the length of
+/// the plaintext.
+///
+/// # Errors
+///
+/// Returns an error if any of the following are true:
+///
+/// - `ciphertext` is shorter than 164 bytes.
+/// - The magic number is invalid.
+/// - The version number is the unsupported abcrypt version number.
+/// - The version number is the unrecognized abcrypt version number.
+/// - The Argon2 type is invalid.
+/// - The Argon2 version is invalid.
+/// - The Argon2 parameters are invalid.
+/// - One of the parameters is null.
+///
+/// # Safety
+///
+/// Behavior is undefined if `ciphertext` and `ciphertext_len` violates the
+/// safety conditions of `slice::from_raw_parts`, or `out_len` is not valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn abcrypt_decrypt_out_len(
+    ciphertext: Option<NonNull<u8>>,
+    ciphertext_len: usize,
+    out_len: Option<NonNull<usize>>,
+) -> ErrorCode {
+    let Some(ciphertext) = ciphertext else {
+        return ErrorCode::Error;
+    };
+    // SAFETY: just checked that `ciphertext` is not a null pointer.
+    let ciphertext = unsafe { slice::from_raw_parts(ciphertext.as_ptr(), ciphertext_len) };
+    let len = match Decryptor::out_len_of(ciphertext) {
+        Ok(len) => len,
+        Err(err) => {
+            return err.into();
+        }
+    };
+
+    let Some(out_len) = out_len else {
+        return ErrorCode::Error;
+    };
+    // SAFETY: just checked that `out_len` is not a null pointer.
+    unsafe { out_len.write(len) };
+    ErrorCode::Ok
 }
 
 #[cfg(test)]
@@ -84,6 +156,7 @@ mod tests {
     const TEST_DATA_ENC: &[u8] = include_bytes!("../tests/data/v1/argon2id/v0x13/data.txt.abcrypt");
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn success() {
         {
             const TEST_DATA_ENC: &[u8] =
@@ -92,6 +165,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -100,9 +174,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -112,6 +188,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -120,9 +197,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -132,6 +211,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -140,9 +220,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -152,6 +234,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -160,9 +243,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -172,6 +257,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -180,9 +266,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -190,6 +278,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -198,23 +287,113 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
     }
 
     #[test]
-    #[should_panic(
-        expected = "source slice length (14) does not match destination slice length (15)"
-    )]
+    fn success_with_padding() {
+        const TEST_DATA_ENC: &[u8] = include_bytes!("../tests/data/v2/data.txt.abcrypt");
+        let mut ciphertext: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+        let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
+        let mut out_len = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt_out_len(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(&raw mut out_len),
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(out_len, 80);
+        let mut plaintext = vec![u8::default(); out_len];
+        let mut written = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(passphrase.as_mut_ptr()),
+                passphrase.len(),
+                NonNull::new(plaintext.as_mut_ptr()),
+                plaintext.len(),
+                NonNull::new(&raw mut written),
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(written, TEST_DATA.len());
+        assert_eq!(&plaintext[..written], TEST_DATA);
+    }
+
+    #[test]
+    fn null_written() {
+        let mut ciphertext: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+        let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
+        let mut plaintext = [u8::default(); TEST_DATA.len()];
+        let code = unsafe {
+            abcrypt_decrypt(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(passphrase.as_mut_ptr()),
+                passphrase.len(),
+                NonNull::new(plaintext.as_mut_ptr()),
+                plaintext.len(),
+                None,
+            )
+        };
+        assert_eq!(code, ErrorCode::Error);
+    }
+
+    #[test]
+    fn out_len() {
+        let mut ciphertext: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
+        let mut out_len = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt_out_len(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(&raw mut out_len),
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(out_len, TEST_DATA.len());
+
+        let code = unsafe {
+            abcrypt_decrypt_out_len(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                None,
+            )
+        };
+        assert_eq!(code, ErrorCode::Error);
+    }
+
+    #[test]
+    fn out_len_with_invalid_input_length() {
+        let mut ciphertext = [u8::default(); (HEADER_SIZE + TAG_SIZE) - 1];
+        let mut out_len = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt_out_len(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(&raw mut out_len),
+            )
+        };
+        assert_eq!(code, ErrorCode::InvalidLength);
+    }
+
+    #[test]
     fn invalid_output_length() {
         let mut ciphertext: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut plaintext = [u8::default(); TEST_DATA.len() + 1];
         assert_ne!(plaintext, TEST_DATA);
-        let _ = unsafe {
+        let mut written = usize::default();
+        let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
                 ciphertext.len(),
@@ -222,8 +401,10 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
+        assert_eq!(code, ErrorCode::InvalidOutputLength);
     }
 
     #[test]
@@ -233,6 +414,7 @@ mod tests {
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -241,6 +423,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::InvalidHeaderMac);
@@ -253,6 +436,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -261,6 +445,7 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::InvalidLength);
@@ -271,6 +456,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -279,6 +465,7 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::InvalidMagicNumber);
@@ -292,6 +479,7 @@ mod tests {
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -300,6 +488,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::InvalidMagicNumber);
@@ -312,6 +501,7 @@ mod tests {
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -320,6 +510,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::UnsupportedVersion);
@@ -328,10 +519,11 @@ mod tests {
     #[test]
     fn unknown_version() {
         let mut ciphertext: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
-        ciphertext[7] = 2;
+        ciphertext[7] = 4;
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -340,6 +532,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::UnknownVersion);
@@ -354,6 +547,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -362,6 +556,7 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::InvalidArgon2Params);
@@ -372,6 +567,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -380,6 +576,7 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::InvalidArgon2Params);
@@ -390,6 +587,7 @@ mod tests {
             let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -398,6 +596,7 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::InvalidArgon2Params);
@@ -413,6 +612,7 @@ mod tests {
         let mut header_mac: [u8; 64] = ciphertext[84..148].try_into().unwrap();
         header_mac.reverse();
         ciphertext[84..148].copy_from_slice(&header_mac);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -421,6 +621,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::InvalidHeaderMac);
@@ -436,6 +637,7 @@ mod tests {
         let mut mac: [u8; TAG_SIZE] = ciphertext[start_mac..].try_into().unwrap();
         mac.reverse();
         ciphertext[start_mac..].copy_from_slice(&mac);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -444,6 +646,7 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::InvalidMac);
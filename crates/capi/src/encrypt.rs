@@ -4,14 +4,21 @@
 
 //! Encrypts to the abcrypt encrypted data format.
 
-use std::{ptr::NonNull, slice};
+use std::{ptr::NonNull, slice, str};
 
 use abcrypt::{
-    Encryptor,
-    argon2::{Algorithm, Params},
+    Encryptor, Normalization,
+    argon2::{Algorithm, Params, Version},
 };
 
-use crate::ErrorCode;
+use crate::{ErrorCode, HEADER_SIZE, TAG_SIZE};
+
+/// The number of bytes added by the passphrase normalization.
+///
+/// This is the length of the extensions (4 bytes) and the normalization
+/// extension (5 bytes) in the header, and the length of the metadata (4 bytes)
+/// and the end of the plaintext (1 byte) in the extended payload.
+const NORMALIZATION_OVERHEAD: usize = 14;
 
 #[allow(clippy::module_name_repetitions)]
 /// Encrypts `plaintext` and write to `out`.
@@ -26,6 +33,7 @@ use crate::ErrorCode;
 ///
 /// - The Argon2 context is invalid.
 /// - One of the parameters is null.
+/// - `out_len` is not the number of output bytes of the encrypted data.
 ///
 /// # Safety
 ///
@@ -66,8 +74,9 @@ pub unsafe extern "C-unwind" fn abcrypt_encrypt(
     };
     // SAFETY: just checked that `out` is not a null pointer.
     let out = unsafe { slice::from_raw_parts_mut(out.as_ptr(), out_len) };
-    cipher.encrypt(out);
-    ErrorCode::Ok
+    cipher
+        .try_encrypt(out)
+        .map_or_else(ErrorCode::from, |()| ErrorCode::Ok)
 }
 
 /// Encrypts `plaintext` with the specified Argon2 parameters and write to
@@ -83,6 +92,7 @@ pub unsafe extern "C-unwind" fn abcrypt_encrypt(
 /// - The Argon2 parameters are invalid.
 /// - The Argon2 context is invalid.
 /// - One of the parameters is null.
+/// - `out_len` is not the number of output bytes of the encrypted data.
 ///
 /// # Safety
 ///
@@ -129,8 +139,9 @@ pub unsafe extern "C-unwind" fn abcrypt_encrypt_with_params(
     };
     // SAFETY: just checked that `out` is not a null pointer.
     let out = unsafe { slice::from_raw_parts_mut(out.as_ptr(), out_len) };
-    cipher.encrypt(out);
-    ErrorCode::Ok
+    cipher
+        .try_encrypt(out)
+        .map_or_else(ErrorCode::from, |()| ErrorCode::Ok)
 }
 
 /// Encrypts `plaintext` with the specified Argon2 type, Argon2 version and
@@ -145,6 +156,7 @@ pub unsafe extern "C-unwind" fn abcrypt_encrypt_with_params(
 /// - The Argon2 parameters are invalid.
 /// - The Argon2 context is invalid.
 /// - One of the parameters is null.
+/// - `out_len` is not the number of output bytes of the encrypted data.
 ///
 /// # Safety
 ///
@@ -208,8 +220,109 @@ pub unsafe extern "C-unwind" fn abcrypt_encrypt_with_context(
     };
     // SAFETY: just checked that `out` is not a null pointer.
     let out = unsafe { slice::from_raw_parts_mut(out.as_ptr(), out_len) };
-    cipher.encrypt(out);
-    ErrorCode::Ok
+    cipher
+        .try_encrypt(out)
+        .map_or_else(ErrorCode::from, |()| ErrorCode::Ok)
+}
+
+/// Encrypts `plaintext` with the specified Argon2 parameters after normalizing
+/// `passphrase` to the specified Unicode normalization form, and write to
+/// `out`.
+///
+/// `normalization` is 0 for NFC and 1 for NFKC. The normalization form is
+/// stored in the header, and the passphrase is normalized in the same way when
+/// decrypting. `out_len` must be the number of output bytes returned by
+/// `abcrypt_encrypt_with_normalization_out_len`. When decrypting it, the
+/// number of output bytes of `abcrypt_decrypt` is returned by
+/// `abcrypt_decrypt_out_len`. This uses Argon2id as the Argon2 type and version
+/// 0x13 as the Argon2 version.
+///
+/// # Errors
+///
+/// Returns an error if any of the following are true:
+///
+/// - The normalization form is invalid.
+/// - The Argon2 parameters are invalid.
+/// - The Argon2 context is invalid.
+/// - One of the parameters is null.
+/// - `passphrase` is not valid UTF-8.
+/// - `out_len` is not the number of output bytes of the encrypted data.
+///
+/// # Safety
+///
+/// Behavior is undefined if any of the following violates the safety conditions
+/// of `slice::from_raw_parts`:
+///
+/// - `plaintext` and `plaintext_len`.
+/// - `passphrase` and `passphrase_len`.
+/// - `out` and `out_len`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C-unwind" fn abcrypt_encrypt_with_normalization(
+    plaintext: Option<NonNull<u8>>,
+    plaintext_len: usize,
+    passphrase: Option<NonNull<u8>>,
+    passphrase_len: usize,
+    out: Option<NonNull<u8>>,
+    out_len: usize,
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    normalization: u32,
+) -> ErrorCode {
+    let Some(plaintext) = plaintext else {
+        return ErrorCode::Error;
+    };
+    // SAFETY: just checked that `plaintext` is not a null pointer.
+    let plaintext = unsafe { slice::from_raw_parts(plaintext.as_ptr(), plaintext_len) };
+    let Some(passphrase) = passphrase else {
+        return ErrorCode::Error;
+    };
+    // SAFETY: just checked that `passphrase` is not a null pointer.
+    let passphrase = unsafe { slice::from_raw_parts(passphrase.as_ptr(), passphrase_len) };
+    let Ok(passphrase) = str::from_utf8(passphrase) else {
+        return ErrorCode::Error;
+    };
+    let Some(normalization) = u8::try_from(normalization)
+        .ok()
+        .and_then(|n| Normalization::try_from(n).ok())
+    else {
+        return ErrorCode::UnsupportedNormalization;
+    };
+    let Ok(params) = Params::new(memory_cost, time_cost, parallelism, None) else {
+        return ErrorCode::InvalidArgon2Params;
+    };
+    let cipher = match Encryptor::with_normalization(
+        &plaintext,
+        passphrase,
+        Algorithm::default(),
+        Version::default(),
+        params,
+        normalization,
+    ) {
+        Ok(c) => c,
+        Err(err) => {
+            return err.into();
+        }
+    };
+
+    let Some(out) = out else {
+        return ErrorCode::Error;
+    };
+    // SAFETY: just checked that `out` is not a null pointer.
+    let out = unsafe { slice::from_raw_parts_mut(out.as_ptr(), out_len) };
+    cipher
+        .try_encrypt(out)
+        .map_or_else(ErrorCode::from, |()| ErrorCode::Ok)
+}
+
+/// Returns the number of output bytes of the encrypted data of `plaintext_len`
+/// bytes of the plaintext encrypted by `abcrypt_encrypt_with_normalization`.
+#[must_use]
+#[unsafe(no_mangle)]
+pub const extern "C-unwind" fn abcrypt_encrypt_with_normalization_out_len(
+    plaintext_len: usize,
+) -> usize {
+    plaintext_len.saturating_add(HEADER_SIZE + NORMALIZATION_OVERHEAD + TAG_SIZE)
 }
 
 #[cfg(test)]
@@ -217,7 +330,7 @@ mod tests {
     use abcrypt::{Argon2, argon2::Version};
 
     use super::*;
-    use crate::{HEADER_SIZE, TAG_SIZE, abcrypt_decrypt};
+    use crate::{abcrypt_decrypt, abcrypt_decrypt_out_len};
 
     const PASSPHRASE: &str = "passphrase";
     const TEST_DATA: &[u8] = include_bytes!("../tests/data/data.txt");
@@ -251,6 +364,7 @@ mod tests {
 
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -259,9 +373,11 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(written, TEST_DATA.len());
         assert_eq!(plaintext, TEST_DATA);
     }
 
@@ -297,6 +413,7 @@ mod tests {
 
         let mut plaintext = [u8::default(); TEST_DATA.len()];
         assert_ne!(plaintext, TEST_DATA);
+        let mut written = usize::default();
         let code = unsafe {
             abcrypt_decrypt(
                 NonNull::new(ciphertext.as_mut_ptr()),
@@ -305,13 +422,16 @@ mod tests {
                 passphrase.len(),
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
+                NonNull::new(&raw mut written),
             )
         };
         assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(written, TEST_DATA.len());
         assert_eq!(plaintext, TEST_DATA);
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn success_with_context() {
         {
             let mut plaintext: [u8; TEST_DATA.len()] = TEST_DATA.try_into().unwrap();
@@ -346,6 +466,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -354,9 +475,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -392,6 +515,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -400,9 +524,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -438,6 +564,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -446,9 +573,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -484,6 +613,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -492,9 +622,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -530,6 +662,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -538,9 +671,11 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
         {
@@ -576,6 +711,7 @@ mod tests {
 
             let mut plaintext = [u8::default(); TEST_DATA.len()];
             assert_ne!(plaintext, TEST_DATA);
+            let mut written = usize::default();
             let code = unsafe {
                 abcrypt_decrypt(
                     NonNull::new(ciphertext.as_mut_ptr()),
@@ -584,22 +720,130 @@ mod tests {
                     passphrase.len(),
                     NonNull::new(plaintext.as_mut_ptr()),
                     plaintext.len(),
+                    NonNull::new(&raw mut written),
                 )
             };
             assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(written, TEST_DATA.len());
             assert_eq!(plaintext, TEST_DATA);
         }
     }
 
     #[test]
-    #[should_panic(
-        expected = "source slice length (16) does not match destination slice length (15)"
-    )]
+    fn success_with_normalization() {
+        let mut plaintext: [u8; TEST_DATA.len()] = TEST_DATA.try_into().unwrap();
+        let mut passphrase: [u8; 6] = "cafe\u{301}".as_bytes().try_into().unwrap();
+        let mut ciphertext =
+            [u8::default(); abcrypt_encrypt_with_normalization_out_len(TEST_DATA.len())];
+        let code = unsafe {
+            abcrypt_encrypt_with_normalization(
+                NonNull::new(plaintext.as_mut_ptr()),
+                plaintext.len(),
+                NonNull::new(passphrase.as_mut_ptr()),
+                passphrase.len(),
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                32,
+                3,
+                4,
+                0,
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(&ciphertext[88..93], [0x06, 0x80, 1, 0, 0]);
+
+        // The composed form of the passphrase derives the same key.
+        let mut passphrase: [u8; 5] = "caf\u{e9}".as_bytes().try_into().unwrap();
+        let mut out_len = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt_out_len(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(&raw mut out_len),
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        let mut plaintext = vec![u8::default(); out_len];
+        let mut written = usize::default();
+        let code = unsafe {
+            abcrypt_decrypt(
+                NonNull::new(ciphertext.as_mut_ptr()),
+                ciphertext.len(),
+                NonNull::new(passphrase.as_mut_ptr()),
+                passphrase.len(),
+                NonNull::new(plaintext.as_mut_ptr()),
+                plaintext.len(),
+                NonNull::new(&raw mut written),
+            )
+        };
+        assert_eq!(code, ErrorCode::Ok);
+        assert_eq!(&plaintext[..written], TEST_DATA);
+    }
+
+    #[test]
+    fn with_normalization_out_len() {
+        assert_eq!(
+            abcrypt_encrypt_with_normalization_out_len(TEST_DATA.len()),
+            TEST_DATA.len() + HEADER_SIZE + TAG_SIZE + 14
+        );
+        assert_eq!(
+            abcrypt_encrypt_with_normalization_out_len(usize::MAX),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn invalid_normalization() {
+        let mut plaintext: [u8; TEST_DATA.len()] = TEST_DATA.try_into().unwrap();
+        let mut ciphertext =
+            [u8::default(); abcrypt_encrypt_with_normalization_out_len(TEST_DATA.len())];
+
+        {
+            let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
+            let code = unsafe {
+                abcrypt_encrypt_with_normalization(
+                    NonNull::new(plaintext.as_mut_ptr()),
+                    plaintext.len(),
+                    NonNull::new(passphrase.as_mut_ptr()),
+                    passphrase.len(),
+                    NonNull::new(ciphertext.as_mut_ptr()),
+                    ciphertext.len(),
+                    32,
+                    3,
+                    4,
+                    2,
+                )
+            };
+            assert_eq!(code, ErrorCode::UnsupportedNormalization);
+        }
+
+        {
+            // The passphrase must be valid UTF-8.
+            let mut passphrase = [0xff; 4];
+            let code = unsafe {
+                abcrypt_encrypt_with_normalization(
+                    NonNull::new(plaintext.as_mut_ptr()),
+                    plaintext.len(),
+                    NonNull::new(passphrase.as_mut_ptr()),
+                    passphrase.len(),
+                    NonNull::new(ciphertext.as_mut_ptr()),
+                    ciphertext.len(),
+                    32,
+                    3,
+                    4,
+                    0,
+                )
+            };
+            assert_eq!(code, ErrorCode::Error);
+        }
+    }
+
+    #[test]
     fn invalid_output_length() {
         let mut plaintext: [u8; TEST_DATA.len()] = TEST_DATA.try_into().unwrap();
         let mut passphrase: [u8; PASSPHRASE.len()] = PASSPHRASE.as_bytes().try_into().unwrap();
         let mut ciphertext = [u8::default(); TEST_DATA.len() + HEADER_SIZE + TAG_SIZE - 1];
-        let _ = unsafe {
+        let code = unsafe {
             abcrypt_encrypt_with_params(
                 NonNull::new(plaintext.as_mut_ptr()),
                 plaintext.len(),
@@ -612,6 +856,7 @@ mod tests {
                 4,
             )
         };
+        assert_eq!(code, ErrorCode::InvalidOutputLength);
     }
 
     #[test]
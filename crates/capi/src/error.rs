@@ -6,7 +6,7 @@
 
 use std::{ffi::CString, fmt, ptr::NonNull, slice};
 
-use abcrypt::Error;
+use abcrypt::{Error, ErrorKind};
 
 /// The error code for the abcrypt encrypted data format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -19,7 +19,7 @@ pub enum ErrorCode {
     /// General error.
     Error,
 
-    /// The encrypted data was shorter than 164 bytes.
+    /// The encrypted data was shorter than expected.
     InvalidLength,
 
     /// The magic number (file signature) was invalid.
@@ -48,6 +48,51 @@ pub enum ErrorCode {
 
     /// The MAC (authentication tag) of the ciphertext was invalid.
     InvalidMac,
+
+    /// The padding of the plaintext was invalid.
+    InvalidPadding,
+
+    /// The ASCII armor was invalid.
+    InvalidArmor,
+
+    /// The metadata of the plaintext was invalid.
+    InvalidMetadata,
+
+    /// The extensions of the header were invalid.
+    InvalidExtension,
+
+    /// The header contained the unrecognized critical extension.
+    UnknownCriticalExtension,
+
+    /// The AEAD was unrecognized or not enabled by the feature flags.
+    UnsupportedAead,
+
+    /// The scrypt parameters were invalid.
+    InvalidScryptParams,
+
+    /// The PHC string was invalid.
+    InvalidPhcString,
+
+    /// The output buffer had the wrong length.
+    InvalidOutputLength,
+
+    /// The key derivation function is unrecognized or not enabled.
+    UnsupportedKdf,
+
+    /// The key commitment of the header was invalid.
+    InvalidKeyCommitment,
+
+    /// The signature of the encrypted data was missing or invalid.
+    InvalidSignature,
+
+    /// The record log was invalid.
+    InvalidRecordLog,
+
+    /// The passphrase normalization form is unrecognized or not enabled.
+    UnsupportedNormalization,
+
+    /// The encrypted data was used outside its validity period.
+    Expired,
 }
 
 impl ErrorCode {
@@ -55,7 +100,10 @@ impl ErrorCode {
     ///
     /// # Errors
     ///
-    /// Returns an error if `buf` is null.
+    /// Returns an error if any of the following are true:
+    ///
+    /// - `buf` is null.
+    /// - `buf_len` is not the number of output bytes of the error message.
     ///
     /// # Safety
     ///
@@ -67,6 +115,9 @@ impl ErrorCode {
             .expect("error message should not contain the null character");
         let message = message.as_bytes_with_nul();
         let Some(buf) = buf else { return Self::Error };
+        if buf_len != message.len() {
+            return Self::InvalidOutputLength;
+        }
         // SAFETY: just checked that `buf` is not a null pointer.
         let buf = unsafe { slice::from_raw_parts_mut(buf.as_ptr(), buf_len) };
         buf.copy_from_slice(message);
@@ -88,7 +139,7 @@ impl fmt::Display for ErrorCode {
         match self {
             Self::Ok => write!(f, "everything is ok"),
             Self::Error => write!(f, "general error"),
-            Self::InvalidLength => write!(f, "{}", Error::InvalidLength),
+            Self::InvalidLength => write!(f, "encrypted data is too short"),
             Self::InvalidMagicNumber => write!(f, "{}", Error::InvalidMagicNumber),
             Self::UnsupportedVersion => write!(f, "unsupported version number"),
             Self::UnknownVersion => write!(f, "unknown version number"),
@@ -98,6 +149,57 @@ impl fmt::Display for ErrorCode {
             Self::InvalidArgon2Context => write!(f, "invalid Argon2 context"),
             Self::InvalidHeaderMac => write!(f, "invalid header MAC"),
             Self::InvalidMac => write!(f, "invalid ciphertext MAC"),
+            Self::InvalidPadding => write!(f, "invalid padding"),
+            Self::InvalidArmor => write!(f, "invalid ASCII armor"),
+            Self::InvalidMetadata => write!(f, "invalid metadata"),
+            Self::InvalidExtension => write!(f, "invalid header extensions"),
+            Self::UnknownCriticalExtension => write!(f, "unknown critical extension"),
+            Self::UnsupportedAead => write!(f, "unsupported AEAD"),
+            Self::InvalidScryptParams => write!(f, "invalid scrypt parameters"),
+            Self::InvalidPhcString => write!(f, "invalid PHC string"),
+            Self::InvalidOutputLength => write!(f, "invalid output length"),
+            Self::UnsupportedKdf => write!(f, "unsupported KDF"),
+            Self::InvalidKeyCommitment => write!(f, "invalid key commitment"),
+            Self::InvalidSignature => write!(f, "invalid signature"),
+            Self::InvalidRecordLog => write!(f, "invalid record log"),
+            Self::UnsupportedNormalization => {
+                write!(f, "unsupported passphrase normalization form")
+            }
+            Self::Expired => write!(f, "encrypted data is outside its validity period"),
+        }
+    }
+}
+
+impl From<ErrorKind> for ErrorCode {
+    #[inline]
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::InvalidLength => Self::InvalidLength,
+            ErrorKind::InvalidMagicNumber => Self::InvalidMagicNumber,
+            ErrorKind::UnsupportedVersion => Self::UnsupportedVersion,
+            ErrorKind::UnknownVersion => Self::UnknownVersion,
+            ErrorKind::InvalidArgon2Type => Self::InvalidArgon2Type,
+            ErrorKind::InvalidArgon2Version => Self::InvalidArgon2Version,
+            ErrorKind::InvalidArgon2Params => Self::InvalidArgon2Params,
+            ErrorKind::InvalidArgon2Context => Self::InvalidArgon2Context,
+            ErrorKind::InvalidHeaderMac => Self::InvalidHeaderMac,
+            ErrorKind::InvalidMac => Self::InvalidMac,
+            ErrorKind::InvalidPadding => Self::InvalidPadding,
+            ErrorKind::InvalidArmor => Self::InvalidArmor,
+            ErrorKind::InvalidMetadata => Self::InvalidMetadata,
+            ErrorKind::InvalidExtension => Self::InvalidExtension,
+            ErrorKind::UnknownCriticalExtension => Self::UnknownCriticalExtension,
+            ErrorKind::UnsupportedAead => Self::UnsupportedAead,
+            ErrorKind::InvalidScryptParams => Self::InvalidScryptParams,
+            ErrorKind::InvalidPhcString => Self::InvalidPhcString,
+            ErrorKind::InvalidOutputLength => Self::InvalidOutputLength,
+            ErrorKind::UnsupportedKdf => Self::UnsupportedKdf,
+            ErrorKind::InvalidKeyCommitment => Self::InvalidKeyCommitment,
+            ErrorKind::InvalidSignature => Self::InvalidSignature,
+            ErrorKind::InvalidRecordLog => Self::InvalidRecordLog,
+            ErrorKind::UnsupportedNormalization => Self::UnsupportedNormalization,
+            ErrorKind::Expired => Self::Expired,
+            _ => Self::Error,
         }
     }
 }
@@ -105,18 +207,7 @@ impl fmt::Display for ErrorCode {
 impl From<Error> for ErrorCode {
     #[inline]
     fn from(error: Error) -> Self {
-        match error {
-            Error::InvalidLength => Self::InvalidLength,
-            Error::InvalidMagicNumber => Self::InvalidMagicNumber,
-            Error::UnsupportedVersion(_) => Self::UnsupportedVersion,
-            Error::UnknownVersion(_) => Self::UnknownVersion,
-            Error::InvalidArgon2Type(_) => Self::InvalidArgon2Type,
-            Error::InvalidArgon2Version(_) => Self::InvalidArgon2Version,
-            Error::InvalidArgon2Params(_) => Self::InvalidArgon2Params,
-            Error::InvalidArgon2Context(_) => Self::InvalidArgon2Context,
-            Error::InvalidHeaderMac(_) => Self::InvalidHeaderMac,
-            Error::InvalidMac(_) => Self::InvalidMac,
-        }
+        error.kind().into()
     }
 }
 
@@ -124,7 +215,10 @@ impl From<Error> for ErrorCode {
 ///
 /// # Errors
 ///
-/// Returns an error if `buf` is null.
+/// Returns an error if any of the following are true:
+///
+/// - `buf` is null.
+/// - `buf_len` is not the number of output bytes of the error message.
 ///
 /// # Safety
 ///
@@ -166,6 +260,21 @@ mod tests {
         assert_eq!(ErrorCode::InvalidArgon2Context as c_int, 9);
         assert_eq!(ErrorCode::InvalidHeaderMac as c_int, 10);
         assert_eq!(ErrorCode::InvalidMac as c_int, 11);
+        assert_eq!(ErrorCode::InvalidPadding as c_int, 12);
+        assert_eq!(ErrorCode::InvalidArmor as c_int, 13);
+        assert_eq!(ErrorCode::InvalidMetadata as c_int, 14);
+        assert_eq!(ErrorCode::InvalidExtension as c_int, 15);
+        assert_eq!(ErrorCode::UnknownCriticalExtension as c_int, 16);
+        assert_eq!(ErrorCode::UnsupportedAead as c_int, 17);
+        assert_eq!(ErrorCode::InvalidScryptParams as c_int, 18);
+        assert_eq!(ErrorCode::InvalidPhcString as c_int, 19);
+        assert_eq!(ErrorCode::InvalidOutputLength as c_int, 20);
+        assert_eq!(ErrorCode::UnsupportedKdf as c_int, 21);
+        assert_eq!(ErrorCode::InvalidKeyCommitment as c_int, 22);
+        assert_eq!(ErrorCode::InvalidSignature as c_int, 23);
+        assert_eq!(ErrorCode::InvalidRecordLog as c_int, 24);
+        assert_eq!(ErrorCode::UnsupportedNormalization as c_int, 25);
+        assert_eq!(ErrorCode::Expired as c_int, 26);
     }
 
     #[test]
@@ -203,9 +312,58 @@ mod tests {
             ErrorCode::InvalidHeaderMac
         );
         assert_eq!(ErrorCode::InvalidMac.clone(), ErrorCode::InvalidMac);
+        assert_eq!(ErrorCode::InvalidPadding.clone(), ErrorCode::InvalidPadding);
+        assert_eq!(ErrorCode::InvalidArmor.clone(), ErrorCode::InvalidArmor);
+        assert_eq!(
+            ErrorCode::InvalidMetadata.clone(),
+            ErrorCode::InvalidMetadata
+        );
+        assert_eq!(
+            ErrorCode::InvalidExtension.clone(),
+            ErrorCode::InvalidExtension
+        );
+        assert_eq!(
+            ErrorCode::UnknownCriticalExtension.clone(),
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_eq!(
+            ErrorCode::UnsupportedAead.clone(),
+            ErrorCode::UnsupportedAead
+        );
+        assert_eq!(
+            ErrorCode::InvalidScryptParams.clone(),
+            ErrorCode::InvalidScryptParams
+        );
+        assert_eq!(
+            ErrorCode::InvalidPhcString.clone(),
+            ErrorCode::InvalidPhcString
+        );
+        assert_eq!(
+            ErrorCode::InvalidOutputLength.clone(),
+            ErrorCode::InvalidOutputLength
+        );
+        assert_eq!(ErrorCode::UnsupportedKdf.clone(), ErrorCode::UnsupportedKdf);
+        assert_eq!(
+            ErrorCode::InvalidKeyCommitment.clone(),
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_eq!(
+            ErrorCode::InvalidSignature.clone(),
+            ErrorCode::InvalidSignature
+        );
+        assert_eq!(
+            ErrorCode::InvalidRecordLog.clone(),
+            ErrorCode::InvalidRecordLog
+        );
+        assert_eq!(
+            ErrorCode::UnsupportedNormalization.clone(),
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_eq!(ErrorCode::Expired.clone(), ErrorCode::Expired);
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn copy() {
         {
             let a = ErrorCode::Ok;
@@ -278,6 +436,96 @@ mod tests {
             let b = a;
             assert_eq!(a, b);
         }
+
+        {
+            let a = ErrorCode::InvalidPadding;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidArmor;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidMetadata;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidExtension;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::UnknownCriticalExtension;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::UnsupportedAead;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidScryptParams;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidPhcString;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidOutputLength;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::UnsupportedKdf;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidKeyCommitment;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidSignature;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::InvalidRecordLog;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::UnsupportedNormalization;
+            let b = a;
+            assert_eq!(a, b);
+        }
+
+        {
+            let a = ErrorCode::Expired;
+            let b = a;
+            assert_eq!(a, b);
+        }
     }
 
     #[test]
@@ -315,9 +563,58 @@ mod tests {
             "InvalidHeaderMac"
         );
         assert_eq!(format!("{:?}", ErrorCode::InvalidMac), "InvalidMac");
+        assert_eq!(format!("{:?}", ErrorCode::InvalidPadding), "InvalidPadding");
+        assert_eq!(format!("{:?}", ErrorCode::InvalidArmor), "InvalidArmor");
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidMetadata),
+            "InvalidMetadata"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidExtension),
+            "InvalidExtension"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::UnknownCriticalExtension),
+            "UnknownCriticalExtension"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::UnsupportedAead),
+            "UnsupportedAead"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidScryptParams),
+            "InvalidScryptParams"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidPhcString),
+            "InvalidPhcString"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidOutputLength),
+            "InvalidOutputLength"
+        );
+        assert_eq!(format!("{:?}", ErrorCode::UnsupportedKdf), "UnsupportedKdf");
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidKeyCommitment),
+            "InvalidKeyCommitment"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidSignature),
+            "InvalidSignature"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::InvalidRecordLog),
+            "InvalidRecordLog"
+        );
+        assert_eq!(
+            format!("{:?}", ErrorCode::UnsupportedNormalization),
+            "UnsupportedNormalization"
+        );
+        assert_eq!(format!("{:?}", ErrorCode::Expired), "Expired");
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn equality() {
         assert_eq!(ErrorCode::Ok, ErrorCode::Ok);
         assert_ne!(ErrorCode::Ok, ErrorCode::Error);
@@ -331,6 +628,21 @@ mod tests {
         assert_ne!(ErrorCode::Ok, ErrorCode::InvalidArgon2Context);
         assert_ne!(ErrorCode::Ok, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::Ok, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidExtension);
+        assert_ne!(ErrorCode::Ok, ErrorCode::UnknownCriticalExtension);
+        assert_ne!(ErrorCode::Ok, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::Ok, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::Ok, ErrorCode::InvalidRecordLog);
+        assert_ne!(ErrorCode::Ok, ErrorCode::UnsupportedNormalization);
+        assert_ne!(ErrorCode::Ok, ErrorCode::Expired);
         assert_ne!(ErrorCode::Error, ErrorCode::Ok);
         assert_eq!(ErrorCode::Error, ErrorCode::Error);
         assert_ne!(ErrorCode::Error, ErrorCode::InvalidLength);
@@ -343,6 +655,21 @@ mod tests {
         assert_ne!(ErrorCode::Error, ErrorCode::InvalidArgon2Context);
         assert_ne!(ErrorCode::Error, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::Error, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidExtension);
+        assert_ne!(ErrorCode::Error, ErrorCode::UnknownCriticalExtension);
+        assert_ne!(ErrorCode::Error, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::Error, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::Error, ErrorCode::InvalidRecordLog);
+        assert_ne!(ErrorCode::Error, ErrorCode::UnsupportedNormalization);
+        assert_ne!(ErrorCode::Error, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidLength, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidLength, ErrorCode::Error);
         assert_eq!(ErrorCode::InvalidLength, ErrorCode::InvalidLength);
@@ -355,6 +682,27 @@ mod tests {
         assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidArgon2Context);
         assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidLength,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidLength,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidLength, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidLength);
@@ -376,6 +724,36 @@ mod tests {
         );
         assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidMagicNumber,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidMagicNumber,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidMagicNumber,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidMagicNumber,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidMagicNumber,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidMagicNumber, ErrorCode::Expired);
         assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::Ok);
         assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::Error);
         assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidLength);
@@ -397,6 +775,36 @@ mod tests {
         );
         assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::UnsupportedVersion,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::UnsupportedVersion,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::UnsupportedVersion,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::UnsupportedVersion,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::UnsupportedVersion,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnsupportedVersion, ErrorCode::Expired);
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::Ok);
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::Error);
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidLength);
@@ -409,6 +817,27 @@ mod tests {
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidArgon2Context);
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::UnknownVersion,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::UnknownVersion,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnknownVersion, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidLength);
@@ -427,6 +856,30 @@ mod tests {
         );
         assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Type,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Type,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Type,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Type, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidLength);
@@ -457,6 +910,36 @@ mod tests {
         );
         assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Version,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Version,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Version,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Version,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Version,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Version, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidLength);
@@ -484,6 +967,36 @@ mod tests {
         );
         assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Params,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Params,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Params,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Params,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Params,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Params, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidLength);
@@ -514,6 +1027,36 @@ mod tests {
         );
         assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Context,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Context,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Context,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Context,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidArgon2Context,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidArgon2Context, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidLength);
@@ -526,6 +1069,27 @@ mod tests {
         assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidArgon2Context);
         assert_eq!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidHeaderMac);
         assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidHeaderMac,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidHeaderMac,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidHeaderMac, ErrorCode::Expired);
         assert_ne!(ErrorCode::InvalidMac, ErrorCode::Ok);
         assert_ne!(ErrorCode::InvalidMac, ErrorCode::Error);
         assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidLength);
@@ -538,21 +1102,714 @@ mod tests {
         assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidArgon2Context);
         assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidHeaderMac);
         assert_eq!(ErrorCode::InvalidMac, ErrorCode::InvalidMac);
-    }
-
-    #[test]
-    fn display() {
-        assert_eq!(format!("{}", ErrorCode::Ok), "everything is ok");
-        assert_eq!(format!("{}", ErrorCode::Error), "general error");
-        assert_eq!(
-            format!("{}", ErrorCode::InvalidLength),
-            "encrypted data is shorter than 164 bytes"
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidExtension);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::UnknownCriticalExtension);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::InvalidRecordLog);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::UnsupportedNormalization);
+        assert_ne!(ErrorCode::InvalidMac, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidMac);
+        assert_eq!(ErrorCode::InvalidPadding, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidPadding,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidPadding,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidPadding, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidPadding);
+        assert_eq!(ErrorCode::InvalidArmor, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidExtension);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnknownCriticalExtension);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::InvalidRecordLog);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::UnsupportedNormalization);
+        assert_ne!(ErrorCode::InvalidArmor, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidArmor);
+        assert_eq!(ErrorCode::InvalidMetadata, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidMetadata,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidMetadata,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidMetadata, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidMetadata);
+        assert_eq!(ErrorCode::InvalidExtension, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidExtension,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidExtension,
+            ErrorCode::UnsupportedNormalization
         );
-        assert_eq!(
-            format!("{}", ErrorCode::InvalidMagicNumber),
-            "invalid magic number"
+        assert_ne!(ErrorCode::InvalidExtension, ErrorCode::Expired);
+        assert_ne!(ErrorCode::UnknownCriticalExtension, ErrorCode::Ok);
+        assert_ne!(ErrorCode::UnknownCriticalExtension, ErrorCode::Error);
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidLength
         );
-        assert_eq!(
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidMagicNumber
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnsupportedVersion
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnknownVersion
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidArgon2Type
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidArgon2Version
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidArgon2Params
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidArgon2Context
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidHeaderMac
+        );
+        assert_ne!(ErrorCode::UnknownCriticalExtension, ErrorCode::InvalidMac);
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidPadding
+        );
+        assert_ne!(ErrorCode::UnknownCriticalExtension, ErrorCode::InvalidArmor);
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidMetadata
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidExtension
+        );
+        assert_eq!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnsupportedAead
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidPhcString
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnsupportedKdf
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidSignature
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::InvalidRecordLog
+        );
+        assert_ne!(
+            ErrorCode::UnknownCriticalExtension,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnknownCriticalExtension, ErrorCode::Expired);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::Ok);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::Error);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::UnsupportedAead,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_eq!(ErrorCode::UnsupportedAead, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::UnsupportedAead,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnsupportedAead, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidLength);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidMagicNumber
+        );
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::UnsupportedVersion
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidArgon2Type);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidArgon2Version
+        );
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidArgon2Params
+        );
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidArgon2Context
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::UnsupportedAead);
+        assert_eq!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidScryptParams,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidScryptParams, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidPhcString,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidScryptParams);
+        assert_eq!(ErrorCode::InvalidPhcString, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidPhcString,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidPhcString, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidLength);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidMagicNumber
+        );
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::UnsupportedVersion
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidArgon2Type);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidArgon2Version
+        );
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidArgon2Params
+        );
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidArgon2Context
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidPhcString);
+        assert_eq!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::UnsupportedKdf);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidOutputLength,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidOutputLength, ErrorCode::Expired);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::Ok);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::Error);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::UnsupportedKdf,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidOutputLength);
+        assert_eq!(ErrorCode::UnsupportedKdf, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::UnsupportedKdf,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnsupportedKdf, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidLength);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidMagicNumber
+        );
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::UnsupportedVersion
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::UnknownVersion);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidArgon2Type
+        );
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidArgon2Version
+        );
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidArgon2Params
+        );
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidArgon2Context
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::UnsupportedAead);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidPhcString);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::UnsupportedKdf);
+        assert_eq!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidKeyCommitment,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidKeyCommitment, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidSignature,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidKeyCommitment);
+        assert_eq!(ErrorCode::InvalidSignature, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidSignature,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidSignature, ErrorCode::Expired);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::Ok);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::Error);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidExtension);
+        assert_ne!(
+            ErrorCode::InvalidRecordLog,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidSignature);
+        assert_eq!(ErrorCode::InvalidRecordLog, ErrorCode::InvalidRecordLog);
+        assert_ne!(
+            ErrorCode::InvalidRecordLog,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::InvalidRecordLog, ErrorCode::Expired);
+        assert_ne!(ErrorCode::UnsupportedNormalization, ErrorCode::Ok);
+        assert_ne!(ErrorCode::UnsupportedNormalization, ErrorCode::Error);
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidLength
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidMagicNumber
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnsupportedVersion
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnknownVersion
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidArgon2Type
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidArgon2Version
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidArgon2Params
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidArgon2Context
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidHeaderMac
+        );
+        assert_ne!(ErrorCode::UnsupportedNormalization, ErrorCode::InvalidMac);
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidPadding
+        );
+        assert_ne!(ErrorCode::UnsupportedNormalization, ErrorCode::InvalidArmor);
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidMetadata
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidExtension
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnsupportedAead
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidScryptParams
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidPhcString
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidOutputLength
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnsupportedKdf
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidSignature
+        );
+        assert_ne!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::InvalidRecordLog
+        );
+        assert_eq!(
+            ErrorCode::UnsupportedNormalization,
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_ne!(ErrorCode::UnsupportedNormalization, ErrorCode::Expired);
+        assert_ne!(ErrorCode::Expired, ErrorCode::Ok);
+        assert_ne!(ErrorCode::Expired, ErrorCode::Error);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidLength);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidMagicNumber);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnsupportedVersion);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnknownVersion);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidArgon2Type);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidArgon2Version);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidArgon2Params);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidArgon2Context);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidHeaderMac);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidMac);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidPadding);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidArmor);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidMetadata);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidExtension);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnknownCriticalExtension);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnsupportedAead);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidScryptParams);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidPhcString);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidOutputLength);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnsupportedKdf);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidKeyCommitment);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidSignature);
+        assert_ne!(ErrorCode::Expired, ErrorCode::InvalidRecordLog);
+        assert_ne!(ErrorCode::Expired, ErrorCode::UnsupportedNormalization);
+        assert_eq!(ErrorCode::Expired, ErrorCode::Expired);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", ErrorCode::Ok), "everything is ok");
+        assert_eq!(format!("{}", ErrorCode::Error), "general error");
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidLength),
+            "encrypted data is too short"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidMagicNumber),
+            "invalid magic number"
+        );
+        assert_eq!(
             format!("{}", ErrorCode::UnsupportedVersion),
             "unsupported version number"
         );
@@ -584,39 +1841,184 @@ mod tests {
             format!("{}", ErrorCode::InvalidMac),
             "invalid ciphertext MAC"
         );
+        assert_eq!(format!("{}", ErrorCode::InvalidPadding), "invalid padding");
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidArmor),
+            "invalid ASCII armor"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidMetadata),
+            "invalid metadata"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidExtension),
+            "invalid header extensions"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::UnknownCriticalExtension),
+            "unknown critical extension"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::UnsupportedAead),
+            "unsupported AEAD"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidScryptParams),
+            "invalid scrypt parameters"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidPhcString),
+            "invalid PHC string"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidOutputLength),
+            "invalid output length"
+        );
+        assert_eq!(format!("{}", ErrorCode::UnsupportedKdf), "unsupported KDF");
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidKeyCommitment),
+            "invalid key commitment"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidSignature),
+            "invalid signature"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::InvalidRecordLog),
+            "invalid record log"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::UnsupportedNormalization),
+            "unsupported passphrase normalization form"
+        );
+        assert_eq!(
+            format!("{}", ErrorCode::Expired),
+            "encrypted data is outside its validity period"
+        );
     }
 
-    #[test]
-    fn error_message() {
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn error_message() {
+        {
+            let expected = CString::new("everything is ok").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(ErrorCode::Ok, NonNull::new(buf.as_mut_ptr()), buf.len())
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("general error").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(ErrorCode::Error, NonNull::new(buf.as_mut_ptr()), buf.len())
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("encrypted data is too short").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidLength,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid magic number").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidMagicNumber,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("unsupported version number").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::UnsupportedVersion,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("unknown version number").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::UnknownVersion,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
         {
-            let expected = CString::new("everything is ok").unwrap();
+            let expected = CString::new("invalid Argon2 type").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
-                abcrypt_error_message(ErrorCode::Ok, NonNull::new(buf.as_mut_ptr()), buf.len())
+                abcrypt_error_message(
+                    ErrorCode::InvalidArgon2Type,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
             };
             assert_eq!(code, ErrorCode::Ok);
             assert_eq!(buf, expected);
         }
 
         {
-            let expected = CString::new("general error").unwrap();
+            let expected = CString::new("invalid Argon2 version").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
-                abcrypt_error_message(ErrorCode::Error, NonNull::new(buf.as_mut_ptr()), buf.len())
+                abcrypt_error_message(
+                    ErrorCode::InvalidArgon2Version,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
             };
             assert_eq!(code, ErrorCode::Ok);
             assert_eq!(buf, expected);
         }
 
         {
-            let expected = CString::new("encrypted data is shorter than 164 bytes").unwrap();
+            let expected = CString::new("invalid Argon2 parameters").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidLength,
+                    ErrorCode::InvalidArgon2Params,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -626,12 +2028,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid magic number").unwrap();
+            let expected = CString::new("invalid Argon2 context").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidMagicNumber,
+                    ErrorCode::InvalidArgon2Context,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -641,12 +2043,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("unsupported version number").unwrap();
+            let expected = CString::new("invalid header MAC").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::UnsupportedVersion,
+                    ErrorCode::InvalidHeaderMac,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -656,12 +2058,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("unknown version number").unwrap();
+            let expected = CString::new("invalid ciphertext MAC").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::UnknownVersion,
+                    ErrorCode::InvalidMac,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -671,12 +2073,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid Argon2 type").unwrap();
+            let expected = CString::new("invalid padding").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidArgon2Type,
+                    ErrorCode::InvalidPadding,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -686,12 +2088,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid Argon2 version").unwrap();
+            let expected = CString::new("invalid ASCII armor").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidArgon2Version,
+                    ErrorCode::InvalidArmor,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -701,12 +2103,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid Argon2 parameters").unwrap();
+            let expected = CString::new("invalid metadata").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidArgon2Params,
+                    ErrorCode::InvalidMetadata,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -716,12 +2118,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid Argon2 context").unwrap();
+            let expected = CString::new("invalid header extensions").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidArgon2Context,
+                    ErrorCode::InvalidExtension,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -731,12 +2133,12 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid header MAC").unwrap();
+            let expected = CString::new("unknown critical extension").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidHeaderMac,
+                    ErrorCode::UnknownCriticalExtension,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -746,12 +2148,155 @@ mod tests {
         }
 
         {
-            let expected = CString::new("invalid ciphertext MAC").unwrap();
+            let expected = CString::new("unsupported AEAD").unwrap();
             let expected = expected.as_bytes_with_nul();
             let mut buf = vec![u8::default(); expected.len()];
             let code = unsafe {
                 abcrypt_error_message(
-                    ErrorCode::InvalidMac,
+                    ErrorCode::UnsupportedAead,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid scrypt parameters").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidScryptParams,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid PHC string").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidPhcString,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid output length").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidOutputLength,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let mut buf = [u8::default(); 1];
+            let code = unsafe {
+                abcrypt_error_message(ErrorCode::Error, NonNull::new(buf.as_mut_ptr()), buf.len())
+            };
+            assert_eq!(code, ErrorCode::InvalidOutputLength);
+        }
+
+        {
+            let expected = CString::new("unsupported KDF").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::UnsupportedKdf,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid key commitment").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidKeyCommitment,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid signature").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidSignature,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("invalid record log").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::InvalidRecordLog,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("unsupported passphrase normalization form").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::UnsupportedNormalization,
+                    NonNull::new(buf.as_mut_ptr()),
+                    buf.len(),
+                )
+            };
+            assert_eq!(code, ErrorCode::Ok);
+            assert_eq!(buf, expected);
+        }
+
+        {
+            let expected = CString::new("encrypted data is outside its validity period").unwrap();
+            let expected = expected.as_bytes_with_nul();
+            let mut buf = vec![u8::default(); expected.len()];
+            let code = unsafe {
+                abcrypt_error_message(
+                    ErrorCode::Expired,
                     NonNull::new(buf.as_mut_ptr()),
                     buf.len(),
                 )
@@ -765,7 +2310,7 @@ mod tests {
     fn error_message_out_len() {
         assert_eq!(abcrypt_error_message_out_len(ErrorCode::Ok), 17);
         assert_eq!(abcrypt_error_message_out_len(ErrorCode::Error), 14);
-        assert_eq!(abcrypt_error_message_out_len(ErrorCode::InvalidLength), 41);
+        assert_eq!(abcrypt_error_message_out_len(ErrorCode::InvalidLength), 28);
         assert_eq!(
             abcrypt_error_message_out_len(ErrorCode::InvalidMagicNumber),
             21
@@ -796,12 +2341,106 @@ mod tests {
             19
         );
         assert_eq!(abcrypt_error_message_out_len(ErrorCode::InvalidMac), 23);
+        assert_eq!(abcrypt_error_message_out_len(ErrorCode::InvalidPadding), 16);
+        assert_eq!(abcrypt_error_message_out_len(ErrorCode::InvalidArmor), 20);
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidMetadata),
+            17
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidExtension),
+            26
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::UnknownCriticalExtension),
+            27
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::UnsupportedAead),
+            17
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidScryptParams),
+            26
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidPhcString),
+            19
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidOutputLength),
+            22
+        );
+        assert_eq!(abcrypt_error_message_out_len(ErrorCode::UnsupportedKdf), 16);
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidKeyCommitment),
+            23
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidSignature),
+            18
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::InvalidRecordLog),
+            19
+        );
+        assert_eq!(
+            abcrypt_error_message_out_len(ErrorCode::UnsupportedNormalization),
+            42
+        );
+        assert_eq!(abcrypt_error_message_out_len(ErrorCode::Expired), 46);
+    }
+
+    #[test]
+    fn from_error_kind_to_code() {
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidLength),
+            ErrorCode::InvalidLength
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidHeaderMac),
+            ErrorCode::InvalidHeaderMac
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidMac),
+            ErrorCode::InvalidMac
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidPhcString),
+            ErrorCode::InvalidPhcString
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidOutputLength),
+            ErrorCode::InvalidOutputLength
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::UnsupportedKdf),
+            ErrorCode::UnsupportedKdf
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidKeyCommitment),
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidSignature),
+            ErrorCode::InvalidSignature
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::InvalidRecordLog),
+            ErrorCode::InvalidRecordLog
+        );
+        assert_eq!(
+            ErrorCode::from(ErrorKind::UnsupportedNormalization),
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_eq!(ErrorCode::from(ErrorKind::Expired), ErrorCode::Expired);
     }
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn from_error_to_code() {
         assert_eq!(
-            ErrorCode::from(Error::InvalidLength),
+            ErrorCode::from(Error::InvalidLength { len: 163, min: 164 }),
             ErrorCode::InvalidLength
         );
         assert_eq!(
@@ -841,8 +2480,76 @@ mod tests {
             ErrorCode::InvalidHeaderMac
         );
         assert_eq!(
-            ErrorCode::from(Error::InvalidMac(abcrypt::chacha20poly1305::Error)),
+            ErrorCode::from(Error::InvalidMac { offset: 148 }),
             ErrorCode::InvalidMac
         );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidPadding),
+            ErrorCode::InvalidPadding
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidArmor),
+            ErrorCode::InvalidArmor
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidMetadata),
+            ErrorCode::InvalidMetadata
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidExtension),
+            ErrorCode::InvalidExtension
+        );
+        assert_eq!(
+            ErrorCode::from(Error::UnknownCriticalExtension(0xc000)),
+            ErrorCode::UnknownCriticalExtension
+        );
+        assert_eq!(
+            ErrorCode::from(Error::UnsupportedAead(1)),
+            ErrorCode::UnsupportedAead
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidScryptParams {
+                log_n: 64,
+                r: 8,
+                p: 1
+            }),
+            ErrorCode::InvalidScryptParams
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidPhcString),
+            ErrorCode::InvalidPhcString
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidOutputLength {
+                expected: 178,
+                actual: 177
+            }),
+            ErrorCode::InvalidOutputLength
+        );
+        assert_eq!(
+            ErrorCode::from(Error::UnsupportedKdf(4)),
+            ErrorCode::UnsupportedKdf
+        );
+        assert_eq!(
+            ErrorCode::from(Error::UnsupportedKdf(4)),
+            ErrorCode::UnsupportedKdf
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidKeyCommitment),
+            ErrorCode::InvalidKeyCommitment
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidSignature),
+            ErrorCode::InvalidSignature
+        );
+        assert_eq!(
+            ErrorCode::from(Error::InvalidRecordLog),
+            ErrorCode::InvalidRecordLog
+        );
+        assert_eq!(
+            ErrorCode::from(Error::UnsupportedNormalization(2)),
+            ErrorCode::UnsupportedNormalization
+        );
+        assert_eq!(ErrorCode::from(Error::Expired), ErrorCode::Expired);
     }
 }
@@ -7,6 +7,8 @@
 #![doc(html_root_url = "https://docs.rs/abcrypt-capi/0.5.0/")]
 // Lint levels of rustc.
 #![deny(missing_docs)]
+// Lint levels of Clippy.
+#![allow(clippy::multiple_crate_versions)]
 
 mod decrypt;
 mod encrypt;
@@ -14,8 +16,11 @@ mod error;
 mod params;
 
 pub use crate::{
-    decrypt::abcrypt_decrypt,
-    encrypt::{abcrypt_encrypt, abcrypt_encrypt_with_context, abcrypt_encrypt_with_params},
+    decrypt::{abcrypt_decrypt, abcrypt_decrypt_out_len},
+    encrypt::{
+        abcrypt_encrypt, abcrypt_encrypt_with_context, abcrypt_encrypt_with_normalization,
+        abcrypt_encrypt_with_normalization_out_len, abcrypt_encrypt_with_params,
+    },
     error::{ErrorCode, abcrypt_error_message, abcrypt_error_message_out_len},
     params::{
         Params, abcrypt_params_free, abcrypt_params_memory_cost, abcrypt_params_new,
@@ -2,15 +2,19 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use std::path::Path;
+use std::{borrow::Cow, path::Path, time::SystemTime};
 
-use abcrypt::{Argon2, Decryptor, argon2};
+use abcrypt::{
+    Argon2, Candidate, Decryptor, Encryptor, Kdf, KeyId, Validity, argon2, armor, scrypt_compat,
+    sharing::FileKey,
+};
 use anyhow::{Context, bail};
 use clap::Parser;
 
 use crate::{
-    cli::{Command, Opt},
-    input, output, params, passphrase,
+    archive,
+    cli::{Command, Log, Opt},
+    diagnostics, input, key, log, metadata, output, params, passphrase, sharing,
 };
 
 /// Ensures that there are no conflicts if reading the passphrase from standard
@@ -48,6 +52,171 @@ pub fn run() -> anyhow::Result<()> {
                 _ => passphrase::read_passphrase_from_tty(),
             }?;
 
+            let (argon2_type, argon2_version, params) = if let Some(argon2) = arg.params {
+                (argon2.variant(), argon2.version(), argon2.params().into())
+            } else {
+                let params =
+                    argon2::Params::new(*arg.memory_cost, *arg.time_cost, *arg.parallelism, None)
+                        .map_err(abcrypt::Error::InvalidArgon2Params)?;
+                (arg.argon2_type.into(), arg.argon2_version.into(), params)
+            };
+
+            if arg.verbose {
+                params::displayln(params.m_cost(), params.t_cost(), params.p_cost());
+            }
+
+            let cipher = match arg.normalize {
+                Some(normalization) => Encryptor::with_normalization(
+                    &input,
+                    &passphrase,
+                    argon2_type,
+                    argon2_version,
+                    params,
+                    normalization.into(),
+                ),
+                None => {
+                    Encryptor::with_context(&input, passphrase, argon2_type, argon2_version, params)
+                }
+            }?;
+            let cipher = match arg.pad {
+                Some(policy) => cipher.with_padding(policy.into()),
+                None => cipher,
+            };
+            let cipher = match arg.input.as_deref() {
+                Some(path) if arg.store_metadata => cipher.with_metadata(&metadata::read(path)?),
+                _ => cipher,
+            };
+            let cipher = match arg.expires {
+                Some(expiry) => {
+                    let not_after = SystemTime::now()
+                        .duration_since(SystemTime::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .checked_add(expiry.into())
+                        .context("the expiration time is too far in the future")?;
+                    cipher.with_validity(Validity::new().with_not_after(not_after))?
+                }
+                None => cipher,
+            };
+            let cipher = match arg.key_id {
+                Some(key_id) => cipher.with_key_id(KeyId::new(key_id))?,
+                None => cipher,
+            };
+            let ciphertext = match arg.sign_key {
+                Some(file) => cipher.sign_with(&key::read_signing_key(&file)?)?,
+                None => cipher,
+            }
+            .encrypt_to_vec();
+            let ciphertext = if arg.armor {
+                armor::encode_with_checksum(ciphertext).into_bytes()
+            } else {
+                ciphertext
+            };
+
+            if let Some(file) = arg.output {
+                output::write_to_file(&file, &ciphertext)?;
+            } else {
+                output::write_to_stdout(&ciphertext)?;
+            }
+        }
+        Command::Decrypt(arg) => {
+            if arg.passphrase_from_stdin {
+                ensure_stdin_does_not_conflict(arg.input.as_deref())?;
+            }
+            let input = input::read(arg.input.as_deref())?;
+            let input = if armor::is_armored(&input) {
+                armor::decode(input).context("the ASCII armor in the encrypted data is invalid")?
+            } else {
+                input
+            };
+
+            let kdf = params::get(&input)?;
+            params::ensure_passphrase(kdf)?;
+            if arg.verbose {
+                params::displayln_kdf(kdf);
+            }
+
+            let passphrases = if let Some(file) = arg.passphrase_list {
+                passphrase::read_passphrase_list(&file)?
+            } else {
+                let passphrase = match (
+                    arg.passphrase_from_tty,
+                    arg.passphrase_from_stdin,
+                    arg.passphrase_from_env,
+                    arg.passphrase_from_file,
+                ) {
+                    (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                    (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                    (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                    _ => passphrase::read_passphrase_from_tty_once(),
+                }?;
+                vec![(None, passphrase)]
+            };
+            let candidates = passphrases
+                .iter()
+                .map(|(key_id, passphrase)| {
+                    let candidate = Candidate::new(passphrase);
+                    key_id
+                        .as_ref()
+                        .map_or(candidate, |id| candidate.with_key_id(KeyId::new(id)))
+                })
+                .collect::<Vec<_>>();
+
+            let cipher = match Decryptor::with_any(&input, &candidates).map(|(_, c)| c) {
+                c @ Err(abcrypt::Error::InvalidHeaderMac(_)) => {
+                    c.context("passphrase is incorrect")
+                }
+                c => c
+                    .map_err(|err| diagnostics::with_problems(&input, err))
+                    .context("the header in the encrypted data is invalid"),
+            }?;
+            if let Some(file) = arg.verify_key {
+                cipher
+                    .verify_signature(&key::read_verifying_key(&file)?)
+                    .context("the signature of the encrypted data is invalid")?;
+            }
+            if arg.restore_metadata {
+                let (plaintext, metadata) = cipher
+                    .decrypt_to_vec_with_metadata()
+                    .context("the encrypted data is corrupted")?;
+
+                if let Some(file) = arg.output {
+                    output::write_to_file(&file, &plaintext)?;
+                    metadata::restore(&file, &metadata)?;
+                } else if let Some(file) = metadata::output_path(&metadata)? {
+                    metadata::write_to_new_file(&file, &plaintext)?;
+                    metadata::restore(&file, &metadata)?;
+                } else {
+                    output::write_to_stdout(&plaintext)?;
+                }
+            } else {
+                let plaintext = cipher
+                    .decrypt_to_vec()
+                    .context("the encrypted data is corrupted")?;
+
+                if let Some(file) = arg.output {
+                    output::write_to_file(&file, &plaintext)?;
+                } else {
+                    output::write_to_stdout(&plaintext)?;
+                }
+            }
+        }
+        Command::Pack(arg) => {
+            let builder = archive::build(&arg.input)?;
+
+            let passphrase = match (
+                arg.passphrase_from_tty,
+                arg.passphrase_from_stdin,
+                arg.passphrase_from_tty_once,
+                arg.passphrase_from_env,
+                arg.passphrase_from_file,
+            ) {
+                (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                (_, _, true, ..) => passphrase::read_passphrase_from_tty_once(),
+                (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                _ => passphrase::read_passphrase_from_tty(),
+            }?;
+
             let params =
                 argon2::Params::new(*arg.memory_cost, *arg.time_cost, *arg.parallelism, None)
                     .map_err(abcrypt::Error::InvalidArgon2Params)?;
@@ -56,13 +225,16 @@ pub fn run() -> anyhow::Result<()> {
                 params::displayln(params.m_cost(), params.t_cost(), params.p_cost());
             }
 
-            let ciphertext = abcrypt::encrypt_with_context(
-                input,
+            let plaintext = builder.build();
+            let ciphertext = Encryptor::with_context(
+                &plaintext,
                 passphrase,
                 arg.argon2_type.into(),
                 arg.argon2_version.into(),
                 params,
-            )?;
+            )?
+            .with_segment_size(abcrypt::archive::SEGMENT_SIZE)?
+            .encrypt_to_vec();
 
             if let Some(file) = arg.output {
                 output::write_to_file(&file, &ciphertext)?;
@@ -70,38 +242,152 @@ pub fn run() -> anyhow::Result<()> {
                 output::write_to_stdout(&ciphertext)?;
             }
         }
-        Command::Decrypt(arg) => {
+        Command::Unpack(arg) => {
+            let input = input::read(Some(&arg.input))?;
+
+            let passphrase = match (
+                arg.passphrase_from_tty,
+                arg.passphrase_from_stdin,
+                arg.passphrase_from_env,
+                arg.passphrase_from_file,
+            ) {
+                (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                _ => passphrase::read_passphrase_from_tty_once(),
+            }?;
+
+            let kdf = params::get(&input)?;
+            if arg.verbose {
+                params::displayln_kdf(kdf);
+            }
+
+            let mut archive = archive::open(input, passphrase)?;
+            if arg.list {
+                archive::list(&archive);
+            } else {
+                archive::extract(&mut archive, &arg.directory, &arg.entries)?;
+            }
+        }
+        Command::Log(Log::Append(arg)) => {
             if arg.passphrase_from_stdin {
                 ensure_stdin_does_not_conflict(arg.input.as_deref())?;
             }
             let input = input::read(arg.input.as_deref())?;
 
+            let is_new = log::is_new(&arg.log);
             let passphrase = match (
                 arg.passphrase_from_tty,
                 arg.passphrase_from_stdin,
+                arg.passphrase_from_tty_once,
                 arg.passphrase_from_env,
                 arg.passphrase_from_file,
             ) {
                 (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                (_, _, true, ..) => passphrase::read_passphrase_from_tty_once(),
                 (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
                 (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                _ if is_new => passphrase::read_passphrase_from_tty(),
                 _ => passphrase::read_passphrase_from_tty_once(),
             }?;
 
-            let params = params::get(&input)?;
-            if arg.verbose {
-                params::displayln(
-                    params.memory_cost(),
-                    params.time_cost(),
-                    params.parallelism(),
-                );
+            if is_new {
+                let params =
+                    argon2::Params::new(*arg.memory_cost, *arg.time_cost, *arg.parallelism, None)
+                        .map_err(abcrypt::Error::InvalidArgon2Params)?;
+
+                if arg.verbose {
+                    params::displayln(params.m_cost(), params.t_cost(), params.p_cost());
+                }
+
+                log::create(&arg.log, &input, passphrase, params, arg.finish)?;
+            } else {
+                log::append(&arg.log, &input, passphrase, arg.finish)?;
             }
+        }
+        Command::Log(Log::Read(arg)) => {
+            let passphrase = match (
+                arg.passphrase_from_tty,
+                arg.passphrase_from_stdin,
+                arg.passphrase_from_env,
+                arg.passphrase_from_file,
+            ) {
+                (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                _ => passphrase::read_passphrase_from_tty_once(),
+            }?;
 
-            let cipher = match Decryptor::new(&input, passphrase) {
+            let (records, finished) = log::read(&arg.log, passphrase)?;
+            if !finished {
+                if arg.require_finished {
+                    return Err(abcrypt::Error::InvalidRecordLog)
+                        .context("the record log is not finished or is truncated");
+                }
+                eprintln!("Warning: the record log is not finished or is truncated");
+            }
+
+            let records = if let Some(n) = arg.record {
+                let count = records.len();
+                records
+                    .into_iter()
+                    .nth(n.get() - 1)
+                    .with_context(|| format!("the record log has only {count} records"))?
+            } else {
+                log::join(&records)
+            };
+            if let Some(file) = arg.output {
+                output::write_to_file(&file, &records)?;
+            } else {
+                output::write_to_stdout(&records)?;
+            }
+        }
+        Command::Split(arg) => {
+            let input = input::read(arg.input.as_deref())?;
+
+            let key = FileKey::generate();
+            let shares = abcrypt::sharing::split(&key, arg.threshold, arg.shares)?;
+            let ciphertext = Encryptor::with_file_key(&input, &key).encrypt_to_vec();
+            let ciphertext = if arg.armor {
+                armor::encode_with_checksum(ciphertext).into_bytes()
+            } else {
+                ciphertext
+            };
+
+            sharing::write_shares(&arg.share_prefix, &shares)?;
+            if let Some(file) = arg.output {
+                output::write_to_file(&file, &ciphertext)?;
+            } else {
+                output::write_to_stdout(&ciphertext)?;
+            }
+        }
+        Command::Combine(arg) => {
+            let input = input::read(arg.input.as_deref())?;
+            let input = if armor::is_armored(&input) {
+                armor::decode(input).context("the ASCII armor in the encrypted data is invalid")?
+            } else {
+                input
+            };
+
+            let shares = arg
+                .shares
+                .iter()
+                .map(|file| sharing::read_share(file))
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let key = abcrypt::sharing::combine(&shares)
+                .context("could not recover the file key from the key shares")?;
+
+            if !matches!(params::get(&input)?, Kdf::FileKey) {
+                bail!("the encrypted data is not encrypted with the key shares");
+            }
+
+            let cipher = match Decryptor::with_file_key(&input, &key) {
                 c @ Err(abcrypt::Error::InvalidHeaderMac(_)) => {
-                    c.context("passphrase is incorrect")
+                    c.context("the key shares do not belong to the encrypted data")
                 }
-                c => c.context("the header in the encrypted data is invalid"),
+                c => c
+                    .map_err(|err| diagnostics::with_problems(&input, err))
+                    .context("the header in the encrypted data is invalid"),
             }?;
             let plaintext = cipher
                 .decrypt_to_vec()
@@ -113,30 +399,135 @@ pub fn run() -> anyhow::Result<()> {
                 output::write_to_stdout(&plaintext)?;
             }
         }
+        Command::ImportScrypt(arg) => {
+            if arg.passphrase_from_stdin {
+                ensure_stdin_does_not_conflict(arg.input.as_deref())?;
+            }
+            let input = input::read(arg.input.as_deref())?;
+            if !scrypt_compat::is_scrypt(&input) {
+                return Err(abcrypt::Error::InvalidMagicNumber)
+                    .context("data is not a valid scrypt encrypted file");
+            }
+
+            let passphrase = match (
+                arg.passphrase_from_tty,
+                arg.passphrase_from_stdin,
+                arg.passphrase_from_env,
+                arg.passphrase_from_file,
+            ) {
+                (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                _ => passphrase::read_passphrase_from_tty_once(),
+            }?;
+
+            let plaintext = match scrypt_compat::decrypt(&input, &passphrase) {
+                p @ Err(abcrypt::Error::InvalidHeaderMac(_)) => {
+                    p.context("passphrase is incorrect")
+                }
+                p @ Err(abcrypt::Error::InvalidMac { .. }) => {
+                    p.context("the encrypted data is corrupted")
+                }
+                p => p.context("data is not a valid scrypt encrypted file"),
+            }?;
+
+            let params =
+                argon2::Params::new(*arg.memory_cost, *arg.time_cost, *arg.parallelism, None)
+                    .map_err(abcrypt::Error::InvalidArgon2Params)?;
+
+            if arg.verbose {
+                params::displayln(params.m_cost(), params.t_cost(), params.p_cost());
+            }
+
+            let ciphertext = Encryptor::with_context(
+                &plaintext,
+                passphrase,
+                arg.argon2_type.into(),
+                arg.argon2_version.into(),
+                params,
+            )?
+            .encrypt_to_vec();
+            let ciphertext = if arg.armor {
+                armor::encode_with_checksum(ciphertext).into_bytes()
+            } else {
+                ciphertext
+            };
+
+            if let Some(file) = arg.output {
+                output::write_to_file(&file, &ciphertext)?;
+            } else {
+                output::write_to_stdout(&ciphertext)?;
+            }
+        }
         Command::Argon2(arg) => {
             let input = input::read(arg.input.as_deref())?;
 
-            let argon2 =
-                Argon2::new(input).context("data is not a valid abcrypt encrypted file")?;
+            let argon2 = Argon2::new(&input)
+                .map_err(|err| diagnostics::with_problems(&input, err))
+                .context("data is not a valid abcrypt encrypted file")?;
             eprintln!("Type: {:?}", argon2.variant());
             eprintln!("Version: {:#x}", u32::from(argon2.version()));
         }
         Command::Information(arg) => {
+            if arg.metadata && arg.passphrase_from_stdin {
+                ensure_stdin_does_not_conflict(arg.input.as_deref())?;
+            }
             let input = input::read(arg.input.as_deref())?;
 
-            let params = params::get(&input)?;
+            let kdf = params::get(&input)?;
+            let metadata = if arg.metadata {
+                params::ensure_passphrase(kdf)?;
+                let input = if armor::is_armored(&input) {
+                    Cow::Owned(
+                        armor::decode(&input)
+                            .context("the ASCII armor in the encrypted data is invalid")?,
+                    )
+                } else {
+                    Cow::Borrowed(input.as_slice())
+                };
+
+                let passphrase = match (
+                    arg.passphrase_from_tty,
+                    arg.passphrase_from_stdin,
+                    arg.passphrase_from_env,
+                    arg.passphrase_from_file,
+                ) {
+                    (_, true, ..) => passphrase::read_passphrase_from_stdin(),
+                    (.., Some(env), _) => passphrase::read_passphrase_from_env(&env),
+                    (.., Some(file)) => passphrase::read_passphrase_from_file(&file),
+                    _ => passphrase::read_passphrase_from_tty_once(),
+                }?;
+
+                let cipher = match Decryptor::new(&input, passphrase) {
+                    c @ Err(abcrypt::Error::InvalidHeaderMac(_)) => {
+                        c.context("passphrase is incorrect")
+                    }
+                    c => c
+                        .map_err(|err| diagnostics::with_problems(&input, err))
+                        .context("the header in the encrypted data is invalid"),
+                }?;
+                let (_, metadata) = cipher
+                    .decrypt_to_vec_with_metadata()
+                    .context("the encrypted data is corrupted")?;
+                Some(metadata)
+            } else {
+                None
+            };
+
             #[cfg(feature = "json")]
             if arg.json {
-                let output =
-                    serde_json::to_string(&params).context("could not serialize as JSON")?;
+                let output = serde_json::to_string(&params::Information {
+                    params: kdf.into(),
+                    metadata,
+                })
+                .context("could not serialize as JSON")?;
                 println!("{output}");
                 return Ok(());
             }
-            params::displayln(
-                params.memory_cost(),
-                params.time_cost(),
-                params.parallelism(),
-            );
+            params::displayln_kdf(kdf);
+            if let Some(metadata) = metadata {
+                metadata::display(&metadata);
+            }
         }
         Command::Completion(arg) => {
             Opt::print_completion(arg.shell);
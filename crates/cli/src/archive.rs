@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::{
+    fs::{self, File},
+    io::Cursor,
+    path::{Path, PathBuf},
+};
+
+use abcrypt::{
+    Metadata,
+    archive::{Archive, Builder},
+};
+use anyhow::Context;
+
+use crate::{metadata, utils::into_abcrypt_error};
+
+/// Adds the files and the directories to a new archive.
+pub fn build(paths: &[PathBuf]) -> anyhow::Result<Builder> {
+    let mut builder = Builder::new();
+    for path in paths {
+        let name = path.file_name().map(|n| {
+            n.to_str()
+                .with_context(|| format!("{} is not valid UTF-8", path.display()))
+        });
+        let name = name.transpose()?.unwrap_or_default();
+        if path.is_dir() {
+            builder.append_dir_all(name, path)
+        } else {
+            builder.append_file(name, path)
+        }
+        .map_err(into_abcrypt_error)
+        .with_context(|| format!("could not add {} to the archive", path.display()))?;
+    }
+    Ok(builder)
+}
+
+/// Decrypts the index of the archive.
+pub fn open(
+    data: Vec<u8>,
+    passphrase: impl AsRef<[u8]>,
+) -> anyhow::Result<Archive<Cursor<Vec<u8>>>> {
+    Archive::new(Cursor::new(data), passphrase).map_err(|err| {
+        let err = into_abcrypt_error(err);
+        let context = match err.downcast_ref::<abcrypt::Error>() {
+            Some(abcrypt::Error::InvalidHeaderMac(_)) => "passphrase is incorrect",
+            Some(abcrypt::Error::InvalidMac { .. }) => "the encrypted data is corrupted",
+            Some(abcrypt::Error::InvalidArchive) => "data is not a valid abcrypt archive",
+            _ => "the header in the encrypted data is invalid",
+        };
+        err.context(context)
+    })
+}
+
+/// Prints the entries of the archive.
+pub fn list(archive: &Archive<Cursor<Vec<u8>>>) {
+    for entry in archive.list() {
+        println!(
+            "{:04o} {:>12} {} {}",
+            entry.mode(),
+            entry.size(),
+            metadata::format_unix_time(entry.modified()),
+            entry.path()
+        );
+    }
+}
+
+/// Extracts the entries of the archive into the directory.
+///
+/// If `entries` is empty, all entries are extracted.
+pub fn extract(
+    archive: &mut Archive<Cursor<Vec<u8>>>,
+    dir: &Path,
+    entries: &[String],
+) -> anyhow::Result<()> {
+    if entries.is_empty() {
+        return archive
+            .extract_all(dir)
+            .map_err(into_abcrypt_error)
+            .with_context(|| format!("could not extract the archive into {}", dir.display()));
+    }
+
+    for path in entries {
+        let entry = archive
+            .list()
+            .iter()
+            .find(|e| e.path() == path)
+            .with_context(|| format!("{path} does not exist in the archive"))?;
+        let metadata = Metadata::new()
+            .with_modified(entry.modified())
+            .with_mode(entry.mode());
+        let dst = path.split('/').fold(dir.to_path_buf(), |p, c| p.join(c));
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("could not create {}", parent.display()))?;
+        }
+        let mut file =
+            File::create(&dst).with_context(|| format!("could not create {}", dst.display()))?;
+        archive
+            .extract_one(path, &mut file)
+            .map_err(into_abcrypt_error)
+            .with_context(|| format!("could not extract {path}"))?;
+        metadata::restore(&dst, &metadata)?;
+    }
+    Ok(())
+}
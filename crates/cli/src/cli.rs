@@ -6,12 +6,17 @@ use std::{
     ffi::OsStr,
     fmt,
     io::{self, Write},
+    num::NonZeroUsize,
     ops::Deref,
     path::PathBuf,
     str::FromStr,
+    time::Duration,
 };
 
-use abcrypt::argon2::{Algorithm, Params, Version};
+use abcrypt::{
+    Padding,
+    argon2::{Algorithm, Params, Version},
+};
 use anyhow::anyhow;
 use byte_unit::{Byte, Unit};
 use clap::{
@@ -21,50 +26,717 @@ use clap::{
 };
 use clap_complete::Generator;
 
-#[derive(Debug, Parser)]
-#[command(
-    name("abcrypt"),
-    version,
-    about,
-    max_term_width(100),
-    propagate_version(true),
-    infer_subcommands(true),
-    arg_required_else_help(false),
-    args_conflicts_with_subcommands(true)
-)]
-pub struct Opt {
-    #[command(subcommand)]
-    pub command: Command,
+#[derive(Debug, Parser)]
+#[command(
+    name("abcrypt"),
+    version,
+    about,
+    max_term_width(100),
+    propagate_version(true),
+    infer_subcommands(true),
+    arg_required_else_help(false),
+    args_conflicts_with_subcommands(true)
+)]
+pub struct Opt {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Encrypt files.
+    ///
+    /// By default, the result will be write to standard output.
+    Encrypt(Encrypt),
+
+    /// Decrypt files.
+    ///
+    /// By default, the result will be write to standard output.
+    Decrypt(Decrypt),
+
+    /// Pack files and directories into an encrypted archive.
+    ///
+    /// The directories are packed recursively. By default, the result will be
+    /// write to standard output.
+    Pack(Pack),
+
+    /// Unpack files from an encrypted archive.
+    ///
+    /// By default, all files are extracted into the current directory.
+    Unpack(Unpack),
+
+    /// Append records to or read records from an encrypted record log.
+    ///
+    /// The key is derived only once for each invocation, and each record is
+    /// encrypted and authenticated independently.
+    #[command(subcommand)]
+    Log(Log),
+
+    /// Encrypt files and split the file key into key shares.
+    ///
+    /// Any <THRESHOLD> of the key shares recover the file key, but fewer reveal
+    /// nothing about it. By default, the result will be write to standard
+    /// output.
+    Split(Split),
+
+    /// Recover the file key from key shares and decrypt files.
+    ///
+    /// By default, the result will be write to standard output.
+    Combine(Combine),
+
+    /// Convert files encrypted by scrypt into abcrypt.
+    ///
+    /// The files in the scrypt encrypted data format are decrypted and
+    /// encrypted again with the same passphrase. By default, the result will
+    /// be write to standard output.
+    ImportScrypt(ImportScrypt),
+
+    /// Provides information about the Argon2 context.
+    Argon2(Argon2),
+
+    /// Provides information about the encryption parameters.
+    #[command(alias("i"))]
+    Information(Information),
+
+    /// Generate shell completion.
+    ///
+    /// The completion is output to standard output.
+    #[command(alias("c"))]
+    Completion(Completion),
+}
+
+#[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct Encrypt {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Set the Argon2 type.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("TYPE"),
+        ignore_case(true)
+    )]
+    pub argon2_type: Argon2Type,
+
+    /// Set the Argon2 version.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("VERSION"),
+        ignore_case(true)
+    )]
+    pub argon2_version: Argon2Version,
+
+    /// Set the memory size in bytes.
+    ///
+    /// <BYTE> can be suffixed with the symbol (B) and the byte prefix (such as
+    /// Ki and M). If only a numeric value is specified for <BYTE>, it is the
+    /// same as specifying the symbol without the byte prefix. Note that <BYTE>
+    /// that is not multiples of 1 KiB is truncated toward zero to the nearest
+    /// it.
+    #[arg(short, long, default_value_t, value_name("BYTE"))]
+    pub memory_cost: MemoryCost,
+
+    /// Set the number of iterations.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub time_cost: TimeCost,
+
+    /// Set the degree of parallelism.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub parallelism: Parallelism,
+
+    /// Set the Argon2 context as the PHC string.
+    ///
+    /// <PHC> consists of the Argon2 type, the Argon2 version and the Argon2
+    /// parameters, such as "$argon2id$v=19$m=19456,t=2,p=1". The memory size in
+    /// it is in KiB. This cannot be used with the other options that set the
+    /// Argon2 context.
+    #[arg(
+        long,
+        value_name("PHC"),
+        conflicts_with_all(["argon2_type", "argon2_version", "memory_cost", "time_cost", "parallelism"])
+    )]
+    pub params: Option<abcrypt::Argon2>,
+
+    /// Pad the plaintext to hide its length.
+    ///
+    /// <POLICY> is either "padme" to use the PADMÉ algorithm, or a size in
+    /// bytes to pad the plaintext to a multiple of it. The size can be suffixed
+    /// with the symbol (B) and the byte prefix (such as Ki and M). Padding
+    /// requires the format version 2.
+    #[arg(long, value_name("POLICY"))]
+    pub pad: Option<PadPolicy>,
+
+    /// Normalize the passphrase to the Unicode normalization form.
+    ///
+    /// The normalization form is stored in the header, and the passphrase is
+    /// normalized in the same way when decrypting, so the same passphrase
+    /// entered in a different form can decrypt the data. Normalization requires
+    /// the format version 2.
+    #[arg(long, value_enum, value_name("FORM"), ignore_case(true))]
+    pub normalize: Option<Normalization>,
+
+    /// Store the key identifier derived from the key ID secret.
+    ///
+    /// <ID> is an arbitrary label of the passphrase, such as "2026-q1", which
+    /// is hashed and stored in the header. It reveals nothing about the
+    /// passphrase, and allows "decrypt --passphrase-list" to skip the
+    /// passphrases with a different key ID secret. Storing the key identifier
+    /// requires the format version 2.
+    #[arg(long, value_name("ID"))]
+    pub key_id: Option<String>,
+
+    /// Make the encrypted data expire after the duration.
+    ///
+    /// <DURATION> is a number optionally suffixed with the time unit, which is
+    /// one of "s" (seconds), "m" (minutes), "h" (hours), "d" (days) and "w"
+    /// (weeks), such as "30d". If the time unit is omitted, it is the same as
+    /// specifying "s". The expiration time is authenticated, and decryption
+    /// after it fails. The expiring data requires the format version 2.
+    #[arg(long, value_name("DURATION"))]
+    pub expires: Option<Expiry>,
+
+    /// Output the result as the ASCII-armored text.
+    ///
+    /// The armored data is encoded with Base64 and enclosed between the
+    /// "-----BEGIN ABCRYPT ENCRYPTED DATA-----" and "-----END ABCRYPT ENCRYPTED
+    /// DATA-----" lines.
+    #[arg(short, long)]
+    pub armor: bool,
+
+    /// Store the metadata of the input file in the encrypted data.
+    ///
+    /// The file name, the mode and the modification time of [FILE] are
+    /// encrypted together with the data. The setuid, setgid and sticky bits of
+    /// the mode are not stored. Storing the metadata requires the format
+    /// version 2.
+    #[arg(long, requires("input"))]
+    pub store_metadata: bool,
+
+    /// Sign the encrypted data with the Ed25519 private key in the file.
+    ///
+    /// <FILE> is the private key in the PKCS #8 PEM format, such as the one
+    /// generated by "openssl genpkey -algorithm ed25519". The public key is
+    /// stored in the header, and the signature is appended to the encrypted
+    /// data. Signing requires the format version 2.
+    #[arg(long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub sign_key: Option<PathBuf>,
+
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
+
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
+
+    /// Read the passphrase from /dev/tty only once.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty_once: bool,
+
+    /// Read the passphrase from the environment variable.
+    ///
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// Print the encryption parameters.
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct Decrypt {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Restore the metadata stored in the encrypted data.
+    ///
+    /// The mode and the modification time of the output file are restored. The
+    /// setuid, setgid and sticky bits of the stored mode are ignored. If
+    /// --output is not specified, the result will be written to a new file
+    /// with the stored file name in the current directory.
+    #[arg(long)]
+    pub restore_metadata: bool,
+
+    /// Verify the signature with the Ed25519 public key in the file before
+    /// decrypting.
+    ///
+    /// <FILE> is the public key in the SPKI PEM format, such as the one
+    /// generated by "openssl pkey -pubout". If the encrypted data is not signed
+    /// with the private key corresponding to <FILE>, decryption fails.
+    #[arg(long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub verify_key: Option<PathBuf>,
+
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
+
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
+
+    /// Read the passphrase from the environment variable.
+    ///
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// Try each passphrase in the file until one matches.
+    ///
+    /// Each line of <FILE> is either "<PASSPHRASE>" or "<ID><TAB><PASSPHRASE>".
+    /// A line which contains a tab is always "<ID><TAB><PASSPHRASE>", which is
+    /// split at the first tab, so <ID> can not contain a tab but <PASSPHRASE>
+    /// can. An empty <ID> means no key ID secret, so a passphrase which
+    /// contains a tab and has no key ID secret is written as
+    /// "<TAB><PASSPHRASE>". If the encrypted data has the key identifier, the
+    /// passphrases with a different key ID secret are skipped without deriving
+    /// the key. Empty lines are ignored. Note that storing a passphrase in a
+    /// file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_list: Option<PathBuf>,
+
+    /// Print the encryption parameters.
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct Pack {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Set the Argon2 type.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("TYPE"),
+        ignore_case(true)
+    )]
+    pub argon2_type: Argon2Type,
+
+    /// Set the Argon2 version.
+    #[arg(
+        long,
+        value_enum,
+        default_value_t,
+        value_name("VERSION"),
+        ignore_case(true)
+    )]
+    pub argon2_version: Argon2Version,
+
+    /// Set the memory size in bytes.
+    ///
+    /// <BYTE> can be suffixed with the symbol (B) and the byte prefix (such as
+    /// Ki and M). If only a numeric value is specified for <BYTE>, it is the
+    /// same as specifying the symbol without the byte prefix. Note that <BYTE>
+    /// that is not multiples of 1 KiB is truncated toward zero to the nearest
+    /// it.
+    #[arg(short, long, default_value_t, value_name("BYTE"))]
+    pub memory_cost: MemoryCost,
+
+    /// Set the number of iterations.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub time_cost: TimeCost,
+
+    /// Set the degree of parallelism.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub parallelism: Parallelism,
+
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
+
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
+
+    /// Read the passphrase from /dev/tty only once.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty_once: bool,
+
+    /// Read the passphrase from the environment variable.
+    ///
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// Print the encryption parameters.
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Files and directories to pack.
+    ///
+    /// Each file is stored with its file name, and each directory is stored
+    /// recursively under its name. The contents of a directory without a name,
+    /// such as ".", are stored at the top level of the archive.
+    #[arg(required(true), value_name("PATH"), value_hint(ValueHint::AnyPath))]
+    pub input: Vec<PathBuf>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct Unpack {
+    /// Extract files into the directory.
+    #[arg(
+        short('C'),
+        long,
+        default_value("."),
+        value_name("DIRECTORY"),
+        value_hint(ValueHint::DirPath)
+    )]
+    pub directory: PathBuf,
+
+    /// List the files in the archive instead of extracting them.
+    ///
+    /// The mode, the size in bytes, the modification time and the path of each
+    /// file are printed to standard output.
+    #[arg(short, long)]
+    pub list: bool,
+
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
+
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
+
+    /// Read the passphrase from the environment variable.
+    ///
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// Print the encryption parameters.
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Input file.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: PathBuf,
+
+    /// Paths of the files to extract.
+    ///
+    /// If [ENTRY] is not specified, all files are extracted.
+    #[arg(value_name("ENTRY"), conflicts_with("list"))]
+    pub entries: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Log {
+    /// Append a record to an encrypted record log.
+    ///
+    /// If the log does not exist, it is created with the specified parameters.
+    Append(LogAppend),
+
+    /// Read the records from an encrypted record log.
+    ///
+    /// The records are written in order without separators. By default, the
+    /// result will be write to standard output.
+    Read(LogRead),
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct LogAppend {
+    /// Append the final record after the record.
+    ///
+    /// No records can be appended to the log after this, and truncating the
+    /// log is detected when reading it.
+    #[arg(long)]
+    pub finish: bool,
+
+    /// Set the memory size in bytes.
+    ///
+    /// This is only used when creating the log. <BYTE> can be suffixed with
+    /// the symbol (B) and the byte prefix (such as Ki and M). If only a numeric
+    /// value is specified for <BYTE>, it is the same as specifying the symbol
+    /// without the byte prefix. Note that <BYTE> that is not multiples of 1
+    /// KiB is truncated toward zero to the nearest it.
+    #[arg(short, long, default_value_t, value_name("BYTE"))]
+    pub memory_cost: MemoryCost,
+
+    /// Set the number of iterations.
+    ///
+    /// This is only used when creating the log.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub time_cost: TimeCost,
+
+    /// Set the degree of parallelism.
+    ///
+    /// This is only used when creating the log.
+    #[arg(short, long, default_value_t, value_name("NUM"))]
+    pub parallelism: Parallelism,
+
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
+
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
+
+    /// Read the passphrase from /dev/tty only once.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty_once: bool,
+
+    /// Read the passphrase from the environment variable.
+    ///
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// Print the encryption parameters when creating the log.
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// The encrypted record log.
+    #[arg(value_name("LOG"), value_hint(ValueHint::FilePath))]
+    pub log: PathBuf,
+
+    /// Input file of the record.
+    ///
+    /// If [FILE] is not specified, the record will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
 }
 
-#[derive(Debug, Subcommand)]
-pub enum Command {
-    /// Encrypt files.
+#[derive(Args, Debug)]
+#[command(group(ArgGroup::new("passphrase")))]
+pub struct LogRead {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Output only the record at the position.
     ///
-    /// By default, the result will be write to standard output.
-    Encrypt(Encrypt),
+    /// The records are numbered from 1 in the order they were appended. The
+    /// record is output exactly as it was appended.
+    #[arg(long, value_name("N"))]
+    pub record: Option<NonZeroUsize>,
 
-    /// Decrypt files.
+    /// Fail if the log does not end with the final record.
     ///
-    /// By default, the result will be write to standard output.
-    Decrypt(Decrypt),
+    /// By default, only a warning is printed because the log which is still
+    /// being appended to does not have the final record.
+    #[arg(long)]
+    pub require_finished: bool,
 
-    /// Provides information about the Argon2 context.
-    Argon2(Argon2),
+    /// Read the passphrase from /dev/tty.
+    ///
+    /// This is the default behavior.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_tty: bool,
 
-    /// Provides information about the encryption parameters.
-    Information(Information),
+    /// Read the passphrase from standard input.
+    #[arg(long, group("passphrase"))]
+    pub passphrase_from_stdin: bool,
 
-    /// Generate shell completion.
+    /// Read the passphrase from the environment variable.
     ///
-    /// The completion is output to standard output.
-    Completion(Completion),
+    /// Note that storing a passphrase in an environment variable can be a
+    /// security risk.
+    #[arg(long, value_name("VAR"), group("passphrase"))]
+    pub passphrase_from_env: Option<String>,
+
+    /// Read the passphrase from the file.
+    ///
+    /// Note that storing a passphrase in a file can be a security risk.
+    #[arg(
+        long,
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath),
+        group("passphrase")
+    )]
+    pub passphrase_from_file: Option<PathBuf>,
+
+    /// The encrypted record log.
+    #[arg(value_name("LOG"), value_hint(ValueHint::FilePath))]
+    pub log: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct Split {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Set the number of the key shares required to recover the file key.
+    #[arg(
+        short('k'),
+        long,
+        value_parser(clap::value_parser!(u8).range(1..)),
+        value_name("THRESHOLD")
+    )]
+    pub threshold: u8,
+
+    /// Set the number of the key shares.
+    ///
+    /// <COUNT> must be greater than or equal to <THRESHOLD>.
+    #[arg(
+        short('n'),
+        long,
+        value_parser(clap::value_parser!(u8).range(1..)),
+        value_name("COUNT")
+    )]
+    pub shares: u8,
+
+    /// Write the key shares to the files with the prefix.
+    ///
+    /// Each key share is written as ASCII-armored text to
+    /// "<PREFIX>-<INDEX>.asc", where <INDEX> starts at 1.
+    #[arg(
+        long,
+        default_value("share"),
+        value_name("PREFIX"),
+        value_hint(ValueHint::FilePath)
+    )]
+    pub share_prefix: PathBuf,
+
+    /// Output the encrypted data as ASCII-armored text.
+    #[arg(short, long)]
+    pub armor: bool,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
 }
 
 #[derive(Args, Debug)]
+pub struct Combine {
+    /// Output the result to a file.
+    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub output: Option<PathBuf>,
+
+    /// Read the ASCII-armored key share from the file.
+    ///
+    /// This option can be specified multiple times. At least as many key
+    /// shares as the threshold are required.
+    #[arg(
+        short,
+        long("share"),
+        required(true),
+        value_name("FILE"),
+        value_hint(ValueHint::FilePath)
+    )]
+    pub shares: Vec<PathBuf>,
+
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
+}
+
 #[allow(clippy::struct_excessive_bools)]
+#[derive(Args, Debug)]
 #[command(group(ArgGroup::new("passphrase")))]
-pub struct Encrypt {
+pub struct ImportScrypt {
     /// Output the result to a file.
     #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
     pub output: Option<PathBuf>,
@@ -107,6 +779,10 @@ pub struct Encrypt {
     #[arg(short, long, default_value_t, value_name("NUM"))]
     pub parallelism: Parallelism,
 
+    /// Output the result as the ASCII-armored text.
+    #[arg(short, long)]
+    pub armor: bool,
+
     /// Read the passphrase from /dev/tty.
     ///
     /// This is the default behavior.
@@ -117,10 +793,6 @@ pub struct Encrypt {
     #[arg(long, group("passphrase"))]
     pub passphrase_from_stdin: bool,
 
-    /// Read the passphrase from /dev/tty only once.
-    #[arg(long, group("passphrase"))]
-    pub passphrase_from_tty_once: bool,
-
     /// Read the passphrase from the environment variable.
     ///
     /// Note that storing a passphrase in an environment variable can be a
@@ -150,28 +822,45 @@ pub struct Encrypt {
     pub input: Option<PathBuf>,
 }
 
+#[derive(Args, Debug)]
+pub struct Argon2 {
+    /// Input file.
+    ///
+    /// If [FILE] is not specified, data will be read from standard input.
+    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
+    pub input: Option<PathBuf>,
+}
+
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Args, Debug)]
 #[command(group(ArgGroup::new("passphrase")))]
-pub struct Decrypt {
-    /// Output the result to a file.
-    #[arg(short, long, value_name("FILE"), value_hint(ValueHint::FilePath))]
-    pub output: Option<PathBuf>,
+pub struct Information {
+    /// Output the encryption parameters as JSON.
+    #[cfg(feature = "json")]
+    #[arg(short, long)]
+    pub json: bool,
+
+    /// Decrypt the data and print the metadata stored in it.
+    ///
+    /// This requires the passphrase.
+    #[arg(long)]
+    pub metadata: bool,
 
     /// Read the passphrase from /dev/tty.
     ///
     /// This is the default behavior.
-    #[arg(long, group("passphrase"))]
+    #[arg(long, group("passphrase"), requires("metadata"))]
     pub passphrase_from_tty: bool,
 
     /// Read the passphrase from standard input.
-    #[arg(long, group("passphrase"))]
+    #[arg(long, group("passphrase"), requires("metadata"))]
     pub passphrase_from_stdin: bool,
 
     /// Read the passphrase from the environment variable.
     ///
     /// Note that storing a passphrase in an environment variable can be a
     /// security risk.
-    #[arg(long, value_name("VAR"), group("passphrase"))]
+    #[arg(long, value_name("VAR"), group("passphrase"), requires("metadata"))]
     pub passphrase_from_env: Option<String>,
 
     /// Read the passphrase from the file.
@@ -181,37 +870,11 @@ pub struct Decrypt {
         long,
         value_name("FILE"),
         value_hint(ValueHint::FilePath),
-        group("passphrase")
+        group("passphrase"),
+        requires("metadata")
     )]
     pub passphrase_from_file: Option<PathBuf>,
 
-    /// Print the encryption parameters.
-    #[arg(short, long)]
-    pub verbose: bool,
-
-    /// Input file.
-    ///
-    /// If [FILE] is not specified, data will be read from standard input.
-    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
-    pub input: Option<PathBuf>,
-}
-
-#[derive(Args, Debug)]
-pub struct Argon2 {
-    /// Input file.
-    ///
-    /// If [FILE] is not specified, data will be read from standard input.
-    #[arg(value_name("FILE"), value_hint(ValueHint::FilePath))]
-    pub input: Option<PathBuf>,
-}
-
-#[derive(Args, Debug)]
-pub struct Information {
-    /// Output the encryption parameters as JSON.
-    #[cfg(feature = "json")]
-    #[arg(short, long)]
-    pub json: bool,
-
     /// Input file.
     ///
     /// If [FILE] is not specified, data will be read from standard input.
@@ -330,6 +993,24 @@ impl From<Argon2Version> for Version {
     }
 }
 
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Normalization {
+    /// Normalization Form C.
+    Nfc,
+
+    /// Normalization Form KC.
+    Nfkc,
+}
+
+impl From<Normalization> for abcrypt::Normalization {
+    fn from(normalization: Normalization) -> Self {
+        match normalization {
+            Normalization::Nfc => Self::Nfc,
+            Normalization::Nfkc => Self::Nfkc,
+        }
+    }
+}
+
 /// Memory size in 1 KiB memory blocks.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct MemoryCost(u32);
@@ -487,6 +1168,68 @@ impl TypedValueParser for ParallelismValueParser {
     }
 }
 
+/// Padding policy for the plaintext.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PadPolicy(Padding);
+
+impl From<PadPolicy> for Padding {
+    fn from(policy: PadPolicy) -> Self {
+        policy.0
+    }
+}
+
+impl FromStr for PadPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> anyhow::Result<Self> {
+        if policy.eq_ignore_ascii_case("padme") {
+            return Ok(Self(Padding::Padme));
+        }
+        let byte = Byte::from_str(policy)
+            .map(u64::from)
+            .map_err(anyhow::Error::from)?;
+        usize::try_from(byte)
+            .ok()
+            .and_then(NonZeroUsize::new)
+            .map(|n| Self(Padding::Multiple(n)))
+            .ok_or_else(|| anyhow!("{byte} B is not a valid padding size"))
+    }
+}
+
+/// Duration after which the encrypted data expires.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Expiry(Duration);
+
+impl From<Expiry> for Duration {
+    fn from(expiry: Expiry) -> Self {
+        expiry.0
+    }
+}
+
+impl FromStr for Expiry {
+    type Err = anyhow::Error;
+
+    fn from_str(duration: &str) -> anyhow::Result<Self> {
+        let (num, unit) = duration.split_at(
+            duration
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(duration.len()),
+        );
+        let num = u64::from_str(num).map_err(anyhow::Error::from)?;
+        let secs = match unit.trim() {
+            "" | "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            "w" => 7 * 24 * 60 * 60,
+            unit => return Err(anyhow!("unknown time unit `{unit}`")),
+        };
+        num.checked_mul(secs)
+            .map(|secs| Self(Duration::from_secs(secs)))
+            .ok_or_else(|| anyhow!("{duration} is too long"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -525,6 +1268,89 @@ mod tests {
         assert_eq!(format!("{}", MemoryCost::MAX), "4294967295 KiB");
     }
 
+    #[test]
+    fn from_str_pad_policy() {
+        assert_eq!(
+            PadPolicy::from_str("padme").unwrap(),
+            PadPolicy(Padding::Padme)
+        );
+        assert_eq!(
+            PadPolicy::from_str("PADME").unwrap(),
+            PadPolicy(Padding::Padme)
+        );
+        assert_eq!(
+            PadPolicy::from_str("512").unwrap(),
+            PadPolicy(Padding::Multiple(NonZeroUsize::new(512).unwrap()))
+        );
+        assert_eq!(
+            PadPolicy::from_str("4 KiB").unwrap(),
+            PadPolicy(Padding::Multiple(NonZeroUsize::new(4096).unwrap()))
+        );
+        assert_eq!(
+            PadPolicy::from_str("1MB").unwrap(),
+            PadPolicy(Padding::Multiple(NonZeroUsize::new(1_000_000).unwrap()))
+        );
+    }
+
+    #[test]
+    fn from_str_pad_policy_with_invalid_value() {
+        assert!(PadPolicy::from_str("padmé").is_err());
+        assert!(PadPolicy::from_str("n").is_err());
+        assert_eq!(
+            PadPolicy::from_str("0").unwrap_err().to_string(),
+            "0 B is not a valid padding size"
+        );
+    }
+
+    #[test]
+    fn from_str_expiry() {
+        assert_eq!(
+            Expiry::from_str("30").unwrap(),
+            Expiry(Duration::from_secs(30))
+        );
+        assert_eq!(
+            Expiry::from_str("30s").unwrap(),
+            Expiry(Duration::from_secs(30))
+        );
+        assert_eq!(
+            Expiry::from_str("90m").unwrap(),
+            Expiry(Duration::from_secs(5400))
+        );
+        assert_eq!(
+            Expiry::from_str("12h").unwrap(),
+            Expiry(Duration::from_secs(43200))
+        );
+        assert_eq!(
+            Expiry::from_str("30d").unwrap(),
+            Expiry(Duration::from_secs(2_592_000))
+        );
+        assert_eq!(
+            Expiry::from_str("30 d").unwrap(),
+            Expiry(Duration::from_secs(2_592_000))
+        );
+        assert_eq!(
+            Expiry::from_str("2w").unwrap(),
+            Expiry(Duration::from_secs(1_209_600))
+        );
+    }
+
+    #[test]
+    fn from_str_expiry_with_invalid_value() {
+        assert!(Expiry::from_str("").is_err());
+        assert!(Expiry::from_str("d").is_err());
+        assert!(Expiry::from_str("-1d").is_err());
+        assert_eq!(
+            Expiry::from_str("30y").unwrap_err().to_string(),
+            "unknown time unit `y`"
+        );
+        assert_eq!(
+            Expiry::from_str("18446744073709551615w")
+                .unwrap_err()
+                .to_string(),
+            "18446744073709551615w is too long"
+        );
+    }
+
     #[test]
     fn from_str_memory_cost() {
         assert_eq!(
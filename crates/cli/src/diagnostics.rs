@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/// Adds every structural problem found in `data` to `err`.
+///
+/// `err` is kept as the root cause so that the exit code does not change. If
+/// `data` has at most one structural problem, `err` is returned as is.
+pub fn with_problems(data: &[u8], err: abcrypt::Error) -> anyhow::Error {
+    let report = abcrypt::inspect(data);
+    let problems = report.problems();
+    if problems.len() < 2 {
+        return err.into();
+    }
+    let problems = problems
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let len = report.problems().len();
+    anyhow::Error::from(err).context(format!("found {len} problems: {problems}"))
+}
@@ -0,0 +1,27 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use ed25519_dalek::{
+    SigningKey, VerifyingKey,
+    pkcs8::{DecodePrivateKey, DecodePublicKey},
+};
+
+/// Reads the Ed25519 private key from the PKCS #8 PEM file.
+pub fn read_signing_key(path: &Path) -> anyhow::Result<SigningKey> {
+    let pem = fs::read_to_string(path)
+        .with_context(|| format!("could not read private key from {}", path.display()))?;
+    SigningKey::from_pkcs8_pem(&pem)
+        .with_context(|| format!("{} is not a valid Ed25519 private key", path.display()))
+}
+
+/// Reads the Ed25519 public key from the SPKI PEM file.
+pub fn read_verifying_key(path: &Path) -> anyhow::Result<VerifyingKey> {
+    let pem = fs::read_to_string(path)
+        .with_context(|| format!("could not read public key from {}", path.display()))?;
+    VerifyingKey::from_public_key_pem(&pem)
+        .with_context(|| format!("{} is not a valid Ed25519 public key", path.display()))
+}
@@ -0,0 +1,119 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+};
+
+use abcrypt::{
+    argon2::Params,
+    log::{LogReader, LogWriter},
+};
+use anyhow::Context;
+
+use crate::utils::into_abcrypt_error;
+
+/// Adds the context to the error of reading the record log.
+fn read_error(err: io::Error, path: &Path) -> anyhow::Error {
+    let err = into_abcrypt_error(err);
+    match err.downcast_ref::<abcrypt::Error>() {
+        Some(abcrypt::Error::InvalidHeaderMac(_)) => err.context("passphrase is incorrect"),
+        Some(abcrypt::Error::InvalidMac { .. } | abcrypt::Error::InvalidLength { .. }) => {
+            err.context("the record log is corrupted")
+        }
+        Some(_) => err.context("data is not a valid abcrypt record log"),
+        None => err.context(format!("could not read {}", path.display())),
+    }
+}
+
+/// Returns `true` if the record log at `path` does not exist or is empty.
+pub fn is_new(path: &Path) -> bool {
+    path.metadata().map_or(true, |m| m.len() == u64::MIN)
+}
+
+/// Creates a new record log, and appends `record` to it.
+pub fn create(
+    path: &Path,
+    record: &[u8],
+    passphrase: impl AsRef<[u8]>,
+    params: Params,
+    finish: bool,
+) -> anyhow::Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("could not create {}", path.display()))?;
+    let writer = LogWriter::new(file, passphrase, params).map_err(into_abcrypt_error)?;
+    write(writer, path, record, finish)
+}
+
+/// Opens the existing record log, and appends `record` to it.
+pub fn append(
+    path: &Path,
+    record: &[u8],
+    passphrase: impl AsRef<[u8]>,
+    finish: bool,
+) -> anyhow::Result<()> {
+    let file = File::options()
+        .read(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("could not open {}", path.display()))?;
+    let writer = LogWriter::open(file, passphrase).map_err(|err| {
+        if err.kind() == io::ErrorKind::InvalidInput {
+            anyhow::Error::from(err).context("the record log is already finished")
+        } else {
+            read_error(err, path)
+        }
+    })?;
+    write(writer, path, record, finish)
+}
+
+/// Appends `record`, and the final record if `finish` is `true`.
+fn write(
+    mut writer: LogWriter<File>,
+    path: &Path,
+    record: &[u8],
+    finish: bool,
+) -> anyhow::Result<()> {
+    writer
+        .append(record)
+        .and_then(|()| {
+            if finish {
+                writer.finish().map(drop)
+            } else {
+                Ok(())
+            }
+        })
+        .with_context(|| format!("could not append the record to {}", path.display()))
+}
+
+/// Reads all records of the record log.
+///
+/// Returns the records and whether the log is finished.
+pub fn read(path: &Path, passphrase: impl AsRef<[u8]>) -> anyhow::Result<(Vec<Vec<u8>>, bool)> {
+    let file = File::open(path).with_context(|| format!("could not open {}", path.display()))?;
+    let mut reader =
+        LogReader::new(BufReader::new(file), passphrase).map_err(|err| read_error(err, path))?;
+    let records = reader
+        .by_ref()
+        .map(|record| record.map_err(|err| read_error(err, path)))
+        .collect::<anyhow::Result<_>>()?;
+    Ok((records, reader.is_finished()))
+}
+
+/// Joins `records` into the output of the record log.
+///
+/// Each record is terminated by a newline. A newline is not appended to the
+/// record which already ends with it.
+pub fn join(records: &[Vec<u8>]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(records.iter().map(|r| r.len() + 1).sum());
+    for record in records {
+        buf.extend(record);
+        if record.last() != Some(&b'\n') {
+            buf.push(b'\n');
+        }
+    }
+    buf
+}
@@ -8,11 +8,17 @@
 #![allow(clippy::multiple_crate_versions)]
 
 mod app;
+mod archive;
 mod cli;
+mod diagnostics;
 mod input;
+mod key;
+mod log;
+mod metadata;
 mod output;
 mod params;
 mod passphrase;
+mod sharing;
 mod utils;
 
 use std::{io, process::ExitCode};
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use abcrypt::Metadata;
+use anyhow::{Context, bail};
+
+/// The bits of the mode which are stored and restored.
+///
+/// The setuid, setgid and sticky bits are excluded so that decrypting the
+/// data does not create a file with the special bits chosen by whoever
+/// encrypted it.
+#[cfg(unix)]
+const MODE_MASK: u32 = 0o777;
+
+/// Reads the metadata of the file.
+pub fn read(path: &Path) -> anyhow::Result<Metadata> {
+    let metadata = fs::metadata(path)
+        .with_context(|| format!("could not read metadata of {}", path.display()))?;
+
+    let mut result = Metadata::new();
+    if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
+        result = result.with_file_name(file_name);
+    }
+    if let Some(modified) = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+    {
+        result = result.with_modified(modified);
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        result = result.with_mode(metadata.permissions().mode() & MODE_MASK);
+    }
+    Ok(result)
+}
+
+/// Returns the path of the file with the stored file name in the current
+/// directory.
+pub fn output_path(metadata: &Metadata) -> anyhow::Result<Option<PathBuf>> {
+    let Some(file_name) = metadata.file_name() else {
+        return Ok(None);
+    };
+    let path = PathBuf::from(file_name);
+    if path.file_name() != Some(path.as_os_str()) {
+        bail!("the stored file name `{file_name}` is not a valid file name");
+    }
+    Ok(Some(path))
+}
+
+/// Writes the result to a new file.
+///
+/// Unlike [`crate::output::write_to_file`], this fails if the file already
+/// exists.
+pub fn write_to_new_file(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    File::create_new(path)
+        .and_then(|mut f| f.write_all(data))
+        .with_context(|| format!("could not write data to {}", path.display()))
+}
+
+/// Restores the mode and the modification time of the file.
+///
+/// The setuid, setgid and sticky bits of the stored mode are ignored.
+pub fn restore(path: &Path, metadata: &Metadata) -> anyhow::Result<()> {
+    if let Some(modified) = metadata.modified() {
+        File::options()
+            .write(true)
+            .open(path)
+            .and_then(|f| f.set_modified(SystemTime::UNIX_EPOCH + modified))
+            .with_context(|| format!("could not set modification time of {}", path.display()))?;
+    }
+    // The mode is restored last because it may make the file read-only.
+    #[cfg(unix)]
+    if let Some(mode) = metadata.mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::set_permissions(path, fs::Permissions::from_mode(mode & MODE_MASK))
+            .with_context(|| format!("could not set mode of {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Prints the metadata.
+pub fn display(metadata: &Metadata) {
+    if let Some(file_name) = metadata.file_name() {
+        eprintln!("File name: {file_name}");
+    }
+    if let Some(modified) = metadata.modified() {
+        eprintln!("Modified: {}", format_unix_time(modified));
+    }
+    if let Some(mode) = metadata.mode() {
+        eprintln!("Mode: {mode:04o}");
+    }
+    if let Some(content_type) = metadata.content_type() {
+        eprintln!("Content type: {content_type}");
+    }
+}
+
+/// Formats the duration since the Unix epoch as an RFC 3339 date and time in
+/// UTC.
+pub fn format_unix_time(time: Duration) -> String {
+    const SECS_PER_DAY: u64 = 86400;
+
+    let (days, secs) = (time.as_secs() / SECS_PER_DAY, time.as_secs() % SECS_PER_DAY);
+    let (hour, minute, second) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+    // Converts the days since the Unix epoch to the civil date. See
+    // <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    let nanos = time.subsec_nanos();
+    if nanos == 0 {
+        format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}Z")
+    } else {
+        format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{nanos:09}Z")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_unix_time() {
+        assert_eq!(
+            super::format_unix_time(Duration::ZERO),
+            "1970-01-01T00:00:00Z"
+        );
+        assert_eq!(
+            super::format_unix_time(Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+        assert_eq!(
+            super::format_unix_time(Duration::new(1_767_225_599, 500_000_000)),
+            "2025-12-31T23:59:59.500000000Z"
+        );
+        assert_eq!(
+            super::format_unix_time(Duration::from_secs(1_767_225_600)),
+            "2026-01-01T00:00:00Z"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_and_restore_without_special_mode_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!(
+            "abcrypt-cli-metadata-special-mode-bits-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("data.txt");
+        fs::write(&path, "Hello, world!\n").unwrap();
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o4755)).unwrap();
+        assert_eq!(read(&path).unwrap().mode(), Some(0o755));
+
+        restore(&path, &Metadata::new().with_mode(0o6744)).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o7777, 0o744);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn output_path() {
+        assert_eq!(super::output_path(&Metadata::new()).unwrap(), None);
+        assert_eq!(
+            super::output_path(&Metadata::new().with_file_name("data.txt")).unwrap(),
+            Some(PathBuf::from("data.txt"))
+        );
+    }
+
+    #[test]
+    fn output_path_with_invalid_file_name() {
+        for file_name in ["", ".", "..", "../data.txt", "/data.txt", "data/data.txt"] {
+            assert!(super::output_path(&Metadata::new().with_file_name(file_name)).is_err());
+        }
+    }
+}
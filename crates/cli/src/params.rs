@@ -2,11 +2,88 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use anyhow::Context;
+use abcrypt::Kdf;
+use anyhow::{Context, bail};
 
-/// Gets the encryption parameters.
-pub fn get(data: &[u8]) -> anyhow::Result<abcrypt::Params> {
-    abcrypt::Params::new(data).context("data is not a valid abcrypt encrypted file")
+use crate::diagnostics;
+
+/// The information of the encrypted data.
+#[cfg(feature = "json")]
+#[derive(Debug, serde::Serialize)]
+pub struct Information {
+    #[serde(flatten)]
+    pub params: Params,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<abcrypt::Metadata>,
+}
+
+/// The parameters of the key derivation function.
+#[cfg(feature = "json")]
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum Params {
+    /// The Argon2 parameters.
+    Argon2(abcrypt::Params),
+
+    /// The scrypt parameters.
+    Scrypt(abcrypt::ScryptParams),
+
+    /// The file key, which has no parameters.
+    #[serde(rename_all = "camelCase")]
+    FileKey { file_key: bool },
+}
+
+#[cfg(feature = "json")]
+impl From<Kdf> for Params {
+    fn from(kdf: Kdf) -> Self {
+        match kdf {
+            Kdf::Argon2(argon2) => Self::Argon2(argon2.params()),
+            Kdf::Scrypt(params) => Self::Scrypt(params),
+            Kdf::FileKey => Self::FileKey { file_key: true },
+            _ => unreachable!("all key derivation functions should be enabled"),
+        }
+    }
+}
+
+/// Gets the key derivation function and its parameters.
+pub fn get(data: &[u8]) -> anyhow::Result<Kdf> {
+    Kdf::new(data)
+        .map_err(|err| diagnostics::with_problems(data, err))
+        .context("data is not a valid abcrypt encrypted file")
+}
+
+/// Returns [`Err`] if the encrypted data is encrypted with the file key, which
+/// is recovered from the key shares instead of the passphrase.
+pub fn ensure_passphrase(kdf: Kdf) -> anyhow::Result<()> {
+    if matches!(kdf, Kdf::FileKey) {
+        bail!(
+            "the encrypted data is encrypted with the key shares, use `abcrypt combine` to decrypt it"
+        );
+    }
+    Ok(())
+}
+
+/// Prints the parameters of the key derivation function with a newline.
+pub fn displayln_kdf(kdf: Kdf) {
+    match kdf {
+        Kdf::Argon2(argon2) => {
+            let params = argon2.params();
+            displayln(
+                params.memory_cost(),
+                params.time_cost(),
+                params.parallelism(),
+            );
+        }
+        Kdf::Scrypt(params) => eprintln!(
+            "Parameters used: logN = {}; r = {}; p = {};",
+            params.log_n(),
+            params.r(),
+            params.p()
+        ),
+        Kdf::FileKey => eprintln!("Encrypted with the file key split into the key shares"),
+        _ => unreachable!("all key derivation functions should be enabled"),
+    }
 }
 
 /// Prints the encryption parameters.
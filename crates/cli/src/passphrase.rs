@@ -4,7 +4,7 @@
 
 use std::{
     env,
-    fs::File,
+    fs::{self, File},
     io::{self, BufRead, BufReader},
     path::Path,
 };
@@ -60,3 +60,26 @@ pub fn read_passphrase_from_file(path: &Path) -> anyhow::Result<String> {
     buf.remove_newline();
     Ok(buf)
 }
+
+/// Reads the list of the candidate passphrases from the file.
+///
+/// A line which contains a tab is always split at the first tab into the key
+/// ID secret and the passphrase, and the key ID secret is ignored if it is
+/// empty. Any other line is a passphrase without the key ID secret. Empty
+/// lines are ignored.
+pub fn read_passphrase_list(path: &Path) -> anyhow::Result<Vec<(Option<String>, String)>> {
+    let list = fs::read_to_string(path)
+        .with_context(|| format!("could not read passphrases from {}", path.display()))?;
+    let list = list
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match line.split_once('\t') {
+            Some((key_id, passphrase)) => (
+                Some(key_id).filter(|id| !id.is_empty()).map(String::from),
+                passphrase.into(),
+            ),
+            None => (None, line.into()),
+        })
+        .collect();
+    Ok(list)
+}
@@ -0,0 +1,54 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use abcrypt::sharing::Share;
+use anyhow::Context;
+
+/// Returns the path of the key share with `index`.
+fn share_path(prefix: &Path, index: u8) -> PathBuf {
+    let mut path = prefix.as_os_str().to_owned();
+    path.push(format!("-{index}.asc"));
+    path.into()
+}
+
+/// Writes each armored key share to the file named after `prefix` and its
+/// index.
+pub fn write_shares(prefix: &Path, shares: &[Share]) -> anyhow::Result<()> {
+    for share in shares {
+        let path = share_path(prefix, share.index());
+        fs::write(&path, share.to_armored())
+            .with_context(|| format!("could not write key share to {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Reads the armored key share from the file.
+pub fn read_share(path: &Path) -> anyhow::Result<Share> {
+    let armored = fs::read(path)
+        .with_context(|| format!("could not read key share from {}", path.display()))?;
+    Share::from_armored(armored)
+        .with_context(|| format!("{} is not a valid key share", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_path() {
+        assert_eq!(
+            super::share_path(Path::new("share"), 1),
+            Path::new("share-1.asc")
+        );
+        assert_eq!(
+            super::share_path(Path::new("backup/officer"), 255),
+            Path::new("backup/officer-255.asc")
+        );
+    }
+}
@@ -2,6 +2,20 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use std::io;
+
+/// Converts the I/O error into [`abcrypt::Error`] if it is caused by the
+/// abcrypt encrypted data format.
+pub fn into_abcrypt_error(err: io::Error) -> anyhow::Error {
+    if let Some(e) = err
+        .get_ref()
+        .and_then(|e| e.downcast_ref::<abcrypt::Error>())
+    {
+        return (*e).into();
+    }
+    err.into()
+}
+
 pub trait StringExt {
     /// Removes trailing newline.
     fn remove_newline(&mut self);
@@ -52,6 +52,17 @@ fn basic_argon2() {
         .stderr(predicate::str::contains("Version: 0x13"));
 }
 
+#[test]
+fn argon2_with_armor() {
+    utils::command::command()
+        .arg("argon2")
+        .arg("data/armor/data.txt.abcrypt.asc")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Type: Argon2id"))
+        .stderr(predicate::str::contains("Version: 0x13"));
+}
+
 #[test]
 fn infer_subcommand_name_for_argon2_command() {
     utils::command::command()
@@ -99,3 +110,22 @@ fn argon2_if_input_file_is_invalid() {
             "encrypted data is shorter than 164 bytes",
         ));
 }
+
+#[test]
+fn argon2_if_input_file_has_multiple_problems() {
+    let mut data = *include_bytes!("data/v1/argon2id/v0x13/data.txt.abcrypt");
+    data[8..12].copy_from_slice(&u32::to_le_bytes(3));
+    data[24..28].copy_from_slice(&u32::to_le_bytes(u32::MIN));
+    utils::command::command()
+        .arg("argon2")
+        .write_stdin(data)
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data is not a valid abcrypt encrypted file",
+        ))
+        .stderr(predicate::str::contains(
+            "found 2 problems: invalid Argon2 type `3`, invalid Argon2 parameters",
+        ));
+}
@@ -58,6 +58,209 @@ fn basic_decrypt() {
         .stdout(predicate::eq("Hello, world!\n"));
 }
 
+#[test]
+fn decrypt_with_padding() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_scrypt() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v3/scrypt/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_verbose_with_scrypt() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("-v")
+        .arg("data/v3/scrypt/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"))
+        .stderr(predicate::str::starts_with(
+            "Parameters used: logN = 10; r = 8; p = 1;",
+        ));
+}
+
+#[test]
+fn decrypt_with_restore_metadata() {
+    let dir = std::env::temp_dir().join(format!(
+        "abcrypt-cli-decrypt-with-restore-metadata-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/v2/data.txt.abcrypt");
+
+    let mut command = utils::command::command();
+    command
+        .current_dir(&dir)
+        .arg("decrypt")
+        .arg("--restore-metadata")
+        .arg("--passphrase-from-stdin")
+        .arg(&input)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    let output = dir.join("data.txt");
+    assert_eq!(std::fs::read(&output).unwrap(), b"Hello, world!\n");
+    let metadata = std::fs::metadata(&output).unwrap();
+    assert_eq!(
+        metadata.modified().unwrap(),
+        std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_767_225_600)
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        assert_eq!(metadata.permissions().mode() & 0o7777, 0o644);
+    }
+
+    // Does not overwrite the existing file.
+    command
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("could not write data to data.txt"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn decrypt_without_restore_metadata() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("-o")
+        .arg("/dev/null")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success();
+}
+
+#[test]
+fn decrypt_with_armor() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/armor/data.txt.abcrypt.asc")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_invalid_armor() {
+    let armored = std::fs::read_to_string("tests/data/armor/data.txt.abcrypt.asc").unwrap();
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .write_stdin(armored.replace("=pB5i", "=AAAA"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "the ASCII armor in the encrypted data is invalid",
+        ));
+}
+
+#[test]
+fn decrypt_with_verify_key() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--verify-key")
+        .arg("data/ed25519/key.pub.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/signed/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_signed_data_without_verify_key() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/signed/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_wrong_verify_key() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--verify-key")
+        .arg("data/ed25519/other.pub.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/signed/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "the signature of the encrypted data is invalid",
+        ))
+        .stderr(predicate::str::contains("invalid signature"));
+}
+
+#[test]
+fn decrypt_unsigned_data_with_verify_key() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--verify-key")
+        .arg("data/ed25519/key.pub.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "the signature of the encrypted data is invalid",
+        ));
+}
+
+#[test]
+fn decrypt_with_invalid_verify_key() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--verify-key")
+        .arg("data/ed25519/key.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/signed/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "data/ed25519/key.pem is not a valid Ed25519 public key",
+        ));
+}
+
 #[test]
 fn infer_subcommand_name_for_decrypt_command() {
     utils::command::command()
@@ -150,6 +353,119 @@ fn decrypt_if_input_file_is_invalid() {
         ));
 }
 
+#[test]
+fn decrypt_with_passphrase_list() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases.txt")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_passphrase_list_and_different_key_id() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--key-id")
+        .arg("2026-q2")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+
+    // The passphrases with a different key ID secret are skipped.
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases.txt")
+        .write_stdin(output.stdout)
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("passphrase is incorrect"));
+}
+
+#[test]
+fn decrypt_with_passphrase_list_and_key_id() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--key-id")
+        .arg("pass")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("phrase")
+        .output()
+        .unwrap();
+
+    // A line with a tab is split into the key ID secret and the passphrase.
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases-with-tab.txt")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_passphrase_list_and_passphrase_with_tab() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("pass\tphrase")
+        .output()
+        .unwrap();
+
+    // A line with a leading tab is the passphrase without the key ID secret.
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases-with-tab.txt")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn decrypt_with_passphrase_list_and_passphrase_from_stdin() {
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases.txt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
 #[test]
 fn decrypt_if_passphrase_is_incorrect() {
     utils::command::command()
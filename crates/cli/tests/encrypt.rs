@@ -236,6 +236,61 @@ fn encrypt_with_invalid_argon2_version() {
         ));
 }
 
+#[test]
+fn encrypt_with_params() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("--params")
+        .arg("$argon2i$v=16$m=64,t=2,p=3")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(&output.stdout[8..12], u32::to_le_bytes(1));
+    assert_eq!(&output.stdout[12..16], u32::to_le_bytes(0x10));
+    assert_eq!(&output.stdout[16..20], u32::to_le_bytes(64));
+    assert_eq!(&output.stdout[20..24], u32::to_le_bytes(2));
+    assert_eq!(&output.stdout[24..28], u32::to_le_bytes(3));
+}
+
+#[test]
+fn encrypt_with_invalid_params() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--params")
+        .arg("m=64,t=2,p=3")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value 'm=64,t=2,p=3' for '--params <PHC>': invalid PHC string",
+        ));
+}
+
+#[test]
+fn encrypt_with_params_and_argon2_options() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--params")
+        .arg("$argon2id$v=19$m=64,t=2,p=3")
+        .arg("-t")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the argument '--params <PHC>' cannot be used with '--time-cost <NUM>'",
+        ));
+}
+
 #[test]
 fn validate_memory_cost_with_unit_for_encrypt_command() {
     utils::command::command()
@@ -542,6 +597,331 @@ fn validate_parallelism_ranges_for_encrypt_command() {
         .stderr(predicate::str::contains("16777216 is not in 1..=16777215"));
 }
 
+#[test]
+fn encrypt_with_pad() {
+    {
+        let output = utils::command::command()
+            .arg("encrypt")
+            .arg("-m")
+            .arg("32KiB")
+            .arg("-t")
+            .arg("3")
+            .arg("-p")
+            .arg("4")
+            .arg("--pad")
+            .arg("padme")
+            .arg("--passphrase-from-stdin")
+            .arg("data/data.txt")
+            .write_stdin("passphrase")
+            .output()
+            .unwrap();
+        assert_eq!(output.stdout[7], 2);
+        assert_eq!(output.stdout.len(), 24 + 164);
+    }
+    {
+        let output = utils::command::command()
+            .arg("encrypt")
+            .arg("-m")
+            .arg("32KiB")
+            .arg("-t")
+            .arg("3")
+            .arg("-p")
+            .arg("4")
+            .arg("--pad")
+            .arg("1KiB")
+            .arg("--passphrase-from-stdin")
+            .arg("data/data.txt")
+            .write_stdin("passphrase")
+            .output()
+            .unwrap();
+        assert_eq!(output.stdout[7], 2);
+        assert_eq!(output.stdout.len(), 1028 + 164);
+    }
+}
+
+#[test]
+fn encrypt_without_pad() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 1);
+    assert_eq!(output.stdout.len(), 14 + 164);
+}
+
+#[test]
+fn encrypt_with_invalid_pad() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--pad")
+        .arg("0")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '0' for '--pad <POLICY>': 0 B is not a valid padding size",
+        ));
+}
+
+#[test]
+fn encrypt_with_normalize() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--normalize")
+        .arg("nfc")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("cafe\u{301}")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 2);
+    assert_eq!(output.stdout.len(), 14 + 164 + 14);
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "caf\u{e9}")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn encrypt_with_invalid_normalize() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--normalize")
+        .arg("nfd")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value 'nfd' for '--normalize <FORM>'",
+        ));
+}
+
+#[test]
+fn encrypt_with_key_id() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--key-id")
+        .arg("2026-q1")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 2);
+    assert_eq!(output.stdout.len(), 14 + 164 + 21);
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases.txt")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn encrypt_with_expires() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--expires")
+        .arg("30d")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 2);
+    assert_eq!(output.stdout.len(), 14 + 164 + 29);
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-list")
+        .arg("data/passphrases.txt")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn encrypt_with_invalid_expires() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--expires")
+        .arg("30y")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '30y' for '--expires <DURATION>': unknown time unit `y`",
+        ));
+}
+
+#[test]
+fn encrypt_with_armor() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--armor")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "-----BEGIN ABCRYPT ENCRYPTED DATA-----\nYWJjcnlwdAE",
+        ))
+        .stdout(predicate::str::ends_with(
+            "-----END ABCRYPT ENCRYPTED DATA-----\n",
+        ));
+}
+
+#[test]
+fn encrypt_with_store_metadata() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--store-metadata")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 2);
+
+    utils::command::command()
+        .arg("information")
+        .arg("--metadata")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("File name: data.txt\n"))
+        .stderr(predicate::str::contains("Modified: "));
+}
+
+#[test]
+fn encrypt_with_store_metadata_from_stdin() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--store-metadata")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .write_stdin("Hello, world!\n")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
+
+#[test]
+fn encrypt_with_sign_key() {
+    let output = utils::command::command()
+        .arg("encrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--sign-key")
+        .arg("data/ed25519/key.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .output()
+        .unwrap();
+    assert_eq!(output.stdout[7], 2);
+    assert_eq!(output.stdout.len(), 19 + 36 + 168 + 64);
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--verify-key")
+        .arg("data/ed25519/key.pub.pem")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+}
+
+#[test]
+fn encrypt_with_invalid_sign_key() {
+    utils::command::command()
+        .arg("encrypt")
+        .arg("--sign-key")
+        .arg("data/ed25519/key.pub.pem")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "data/ed25519/key.pub.pem is not a valid Ed25519 private key",
+        ));
+}
+
 #[test]
 fn validate_conflicts_if_reading_from_stdin_for_encrypt_command() {
     utils::command::command()
@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn basic_import_scrypt() {
+    let dir = std::env::temp_dir().join(format!(
+        "abcrypt-cli-basic-import-scrypt-{}",
+        std::process::id()
+    ));
+    std::fs::create_dir_all(&dir).unwrap();
+    let output = dir.join("data.txt.abcrypt");
+
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("-o")
+        .arg(&output)
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(std::fs::read(&output).unwrap().starts_with(b"abcrypt\x01"));
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-stdin")
+        .arg(&output)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn import_scrypt_to_stdout() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &[u8]| {
+            output.starts_with(b"abcrypt\x01") && output.len() == 14 + 164
+        }));
+}
+
+#[test]
+fn import_scrypt_with_argon2_type() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--argon2-type")
+        .arg("argon2i")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &[u8]| {
+            output[8..12] == 1_u32.to_le_bytes()
+        }));
+}
+
+#[test]
+fn import_scrypt_with_armor() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--armor")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "-----BEGIN ABCRYPT ENCRYPTED DATA-----\n",
+        ))
+        .stdout(predicate::str::ends_with(
+            "-----END ABCRYPT ENCRYPTED DATA-----\n",
+        ));
+}
+
+#[test]
+fn import_scrypt_verbose() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-v")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stderr(predicate::str::starts_with(
+            "Parameters used: memoryCost = 32; timeCost = 2; parallelism = 1;",
+        ));
+}
+
+#[test]
+fn validate_conflicts_if_reading_from_stdin_for_import_scrypt_command() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("--passphrase-from-stdin")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .stderr(predicate::str::ends_with(
+            "cannot read both passphrase and input data from standard input\n",
+        ));
+}
+
+#[test]
+fn import_scrypt_if_input_file_is_invalid() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data is not a valid scrypt encrypted file",
+        ))
+        .stderr(predicate::str::contains("invalid magic number"));
+}
+
+#[test]
+fn import_scrypt_if_passphrase_is_incorrect() {
+    utils::command::command()
+        .arg("import-scrypt")
+        .arg("--passphrase-from-stdin")
+        .arg("data/scrypt/data.txt.scrypt")
+        .write_stdin("password")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("passphrase is incorrect"))
+        .stderr(predicate::str::contains("invalid header MAC"));
+}
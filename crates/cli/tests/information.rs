@@ -58,6 +58,80 @@ fn basic_information() {
         ));
 }
 
+#[test]
+fn information_with_scrypt() {
+    utils::command::command()
+        .arg("information")
+        .arg("data/v3/scrypt/data.txt.abcrypt")
+        .assert()
+        .success()
+        .stderr(predicate::str::starts_with(
+            "Parameters used: logN = 10; r = 8; p = 1;",
+        ));
+}
+
+#[test]
+fn information_with_armor() {
+    utils::command::command()
+        .arg("information")
+        .arg("data/armor/data.txt.abcrypt.asc")
+        .assert()
+        .success()
+        .stderr(predicate::str::starts_with(
+            "Parameters used: memoryCost = 32; timeCost = 3; parallelism = 4;",
+        ));
+}
+
+#[test]
+fn information_with_metadata() {
+    utils::command::command()
+        .arg("information")
+        .arg("--metadata")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .arg("data/v2/data.txt.abcrypt")
+        .assert()
+        .success()
+        .stderr(predicate::eq(concat!(
+            "Parameters used: memoryCost = 32; timeCost = 3; parallelism = 4;\n",
+            "File name: data.txt\n",
+            "Modified: 2026-01-01T00:00:00Z\n",
+            "Mode: 0644\n",
+            "Content type: text/plain\n"
+        )));
+}
+
+#[test]
+fn information_with_metadata_if_passphrase_is_incorrect() {
+    utils::command::command()
+        .arg("information")
+        .arg("--metadata")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "password")
+        .arg("data/v2/data.txt.abcrypt")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("passphrase is incorrect"));
+}
+
+#[test]
+fn information_without_metadata_with_passphrase() {
+    utils::command::command()
+        .arg("information")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v2/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
+
 #[test]
 fn infer_subcommand_name_for_information_command() {
     utils::command::command()
@@ -124,6 +198,37 @@ fn information_as_json() {
         )));
 }
 
+#[cfg(feature = "json")]
+#[test]
+fn information_with_scrypt_as_json() {
+    utils::command::command()
+        .arg("information")
+        .arg("-j")
+        .arg("data/v3/scrypt/data.txt.abcrypt")
+        .assert()
+        .success()
+        .stdout(predicate::eq(concat!(r#"{"logN":10,"r":8,"p":1}"#, '\n')));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn information_with_metadata_as_json() {
+    utils::command::command()
+        .arg("information")
+        .arg("-j")
+        .arg("--metadata")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .env("PASSPHRASE", "passphrase")
+        .arg("data/v2/data.txt.abcrypt")
+        .assert()
+        .success()
+        .stdout(predicate::eq(concat!(
+            r#"{"memoryCost":32,"timeCost":3,"parallelism":4,"metadata":{"fileName":"data.txt","modified":{"secs":1767225600,"nanos":0},"mode":420,"contentType":"text/plain"}}"#,
+            '\n'
+        )));
+}
+
 #[test]
 fn information_if_input_file_is_invalid() {
     utils::command::command()
@@ -139,3 +244,17 @@ fn information_if_input_file_is_invalid() {
             "encrypted data is shorter than 164 bytes",
         ));
 }
+
+#[test]
+fn information_if_input_file_is_not_abcrypt_encrypted_file() {
+    utils::command::command()
+        .arg("information")
+        .arg("data/data.txt")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "found 2 problems: encrypted data is shorter than 164 bytes (got 14 bytes), invalid \
+             magic number",
+        ));
+}
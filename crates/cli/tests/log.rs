@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::predicate;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("abcrypt-cli-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn append(log: &Path, record: &str, finish: bool) -> assert_cmd::assert::Assert {
+    let mut command = utils::command::command();
+    command
+        .arg("log")
+        .arg("append")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .arg(log)
+        .env("PASSPHRASE", "passphrase")
+        .write_stdin(record);
+    if finish {
+        command.arg("--finish");
+    }
+    command.assert()
+}
+
+#[test]
+fn basic_log() {
+    let dir = temp_dir("basic-log");
+    let log = dir.join("audit.abcrypt");
+
+    append(&log, "first\n", false)
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(std::fs::read(&log).unwrap().starts_with(b"abcrypt\x02"));
+    append(&log, "second\n", false).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout("first\nsecond\n")
+        .stderr(predicate::str::contains(
+            "the record log is not finished or is truncated",
+        ));
+
+    append(&log, "third\n", true).success();
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--require-finished")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout("first\nsecond\nthird\n")
+        .stderr(predicate::str::is_empty());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_to_file() {
+    let dir = temp_dir("log-read-to-file");
+    let log = dir.join("audit.abcrypt");
+    let output = dir.join("audit.txt");
+    append(&log, "first\n", true).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("-o")
+        .arg(&output)
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert_eq!(std::fs::read_to_string(&output).unwrap(), "first\n");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_record_boundaries() {
+    let dir = temp_dir("log-read-record-boundaries");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first", false).success();
+    append(&log, "second\n", false).success();
+    append(&log, "third\nline", true).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout("first\nsecond\nthird\nline\n");
+
+    for (n, record) in [(1, "first"), (2, "second\n"), (3, "third\nline")] {
+        utils::command::command()
+            .arg("log")
+            .arg("read")
+            .arg("--record")
+            .arg(n.to_string())
+            .arg("--passphrase-from-stdin")
+            .arg(&log)
+            .write_stdin("passphrase")
+            .assert()
+            .success()
+            .stdout(record);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_record_out_of_range() {
+    let dir = temp_dir("log-read-record-out-of-range");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first\n", false).success();
+    append(&log, "second\n", true).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--record")
+        .arg("3")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "the record log has only 2 records",
+        ));
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--record")
+        .arg("0")
+        .arg(&log)
+        .assert()
+        .failure()
+        .code(2);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_append_after_finish() {
+    let dir = temp_dir("log-append-after-finish");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first\n", true).success();
+
+    append(&log, "second\n", false)
+        .failure()
+        .stderr(predicate::str::contains(
+            "the record log is already finished",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_require_finished() {
+    let dir = temp_dir("log-read-require-finished");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first\n", false).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--require-finished")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains(
+            "the record log is not finished or is truncated",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_with_incorrect_passphrase() {
+    let dir = temp_dir("log-read-with-incorrect-passphrase");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first\n", true).success();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("password")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("passphrase is incorrect"));
+    append(&log, "second\n", false).failure().code(65);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_corrupted() {
+    let dir = temp_dir("log-read-corrupted");
+    let log = dir.join("audit.abcrypt");
+    append(&log, "first\n", true).success();
+    let mut data = std::fs::read(&log).unwrap();
+    data.pop();
+    std::fs::write(&log, data).unwrap();
+
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg(&log)
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("the record log is corrupted"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_read_from_non_log() {
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data is not a valid abcrypt record log",
+        ));
+}
+
+#[test]
+fn log_read_if_non_existent_log() {
+    utils::command::command()
+        .arg("log")
+        .arg("read")
+        .arg("--passphrase-from-stdin")
+        .arg("non_existent.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(66)
+        .stderr(predicate::str::contains(
+            "could not open non_existent.abcrypt",
+        ));
+}
+
+#[test]
+fn log_append_verbose() {
+    let dir = temp_dir("log-append-verbose");
+
+    utils::command::command()
+        .arg("log")
+        .arg("append")
+        .arg("-v")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--passphrase-from-stdin")
+        .arg(dir.join("new.abcrypt"))
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stderr(predicate::str::starts_with(
+            "Parameters used: memoryCost = 32; timeCost = 2; parallelism = 1;",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn log_append_with_passphrase_and_record_from_stdin() {
+    utils::command::command()
+        .arg("log")
+        .arg("append")
+        .arg("--passphrase-from-stdin")
+        .arg("non_existent.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "cannot read both passphrase and input data from standard input",
+        ));
+}
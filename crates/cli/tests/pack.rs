@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use predicates::prelude::predicate;
+
+#[test]
+fn basic_pack() {
+    let dir = std::env::temp_dir().join(format!("abcrypt-cli-basic-pack-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let output = dir.join("archive.abcrypt");
+
+    utils::command::command()
+        .arg("pack")
+        .arg("-o")
+        .arg(&output)
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .arg("data/armor")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert!(std::fs::read(&output).unwrap().starts_with(b"abcrypt\x02"));
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("-l")
+        .arg("--passphrase-from-stdin")
+        .arg(&output)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(" data.txt\n"))
+        .stdout(predicate::str::contains(" armor/data.txt.abcrypt.asc\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn pack_to_stdout() {
+    utils::command::command()
+        .arg("pack")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::function(|output: &[u8]| {
+            output.starts_with(b"abcrypt\x02")
+        }));
+}
+
+#[test]
+fn pack_without_input() {
+    utils::command::command()
+        .arg("pack")
+        .arg("--passphrase-from-stdin")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
+
+#[test]
+fn pack_if_non_existent_input_file() {
+    utils::command::command()
+        .arg("pack")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--passphrase-from-stdin")
+        .arg("non_existent.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(66)
+        .stderr(predicate::str::contains(
+            "could not add non_existent.txt to the archive",
+        ));
+}
+
+#[test]
+fn pack_verbose() {
+    utils::command::command()
+        .arg("pack")
+        .arg("-m")
+        .arg("32KiB")
+        .arg("-t")
+        .arg("3")
+        .arg("-p")
+        .arg("4")
+        .arg("--passphrase-from-stdin")
+        .arg("-v")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stderr(predicate::str::starts_with(
+            "Parameters used: memoryCost = 32; timeCost = 3; parallelism = 4;",
+        ));
+}
@@ -0,0 +1,291 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::predicate;
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("abcrypt-cli-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Splits `data/data.txt` into 5 key shares, any 3 of which recover the file
+/// key, and returns the path of the encrypted data.
+fn split(dir: &Path) -> PathBuf {
+    let output = dir.join("data.txt.abcrypt");
+    utils::command::command()
+        .arg("split")
+        .arg("-o")
+        .arg(&output)
+        .arg("-k")
+        .arg("3")
+        .arg("-n")
+        .arg("5")
+        .arg("--share-prefix")
+        .arg(dir.join("share"))
+        .arg("data/data.txt")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    output
+}
+
+#[test]
+fn split_and_combine() {
+    let dir = temp_dir("split-and-combine");
+    let ciphertext = split(&dir);
+    assert_eq!(std::fs::read(&ciphertext).unwrap().len(), 14 + 5 + 152 + 16);
+    for index in 1..=5 {
+        let share = std::fs::read_to_string(dir.join(format!("share-{index}.asc"))).unwrap();
+        assert!(share.starts_with("-----BEGIN ABCRYPT KEY SHARE-----\n"));
+        assert!(share.ends_with("-----END ABCRYPT KEY SHARE-----\n"));
+    }
+    assert!(!dir.join("share-6.asc").exists());
+
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg(dir.join("share-5.asc"))
+        .arg("-s")
+        .arg(dir.join("share-1.asc"))
+        .arg("-s")
+        .arg(dir.join("share-3.asc"))
+        .arg(&ciphertext)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn split_with_armor() {
+    let dir = temp_dir("split-with-armor");
+    let output = utils::command::command()
+        .arg("split")
+        .arg("-k")
+        .arg("2")
+        .arg("-n")
+        .arg("2")
+        .arg("--share-prefix")
+        .arg(dir.join("share"))
+        .arg("--armor")
+        .arg("data/data.txt")
+        .output()
+        .unwrap();
+    assert!(
+        output
+            .stdout
+            .starts_with(b"-----BEGIN ABCRYPT ENCRYPTED DATA-----\n")
+    );
+
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg(dir.join("share-1.asc"))
+        .arg("-s")
+        .arg(dir.join("share-2.asc"))
+        .write_stdin(output.stdout)
+        .assert()
+        .success()
+        .stdout(predicate::eq("Hello, world!\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn split_with_invalid_params() {
+    utils::command::command()
+        .arg("split")
+        .arg("-k")
+        .arg("6")
+        .arg("-n")
+        .arg("5")
+        .arg("data/data.txt")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "invalid key sharing parameters: 6 of 5 key shares",
+        ));
+    utils::command::command()
+        .arg("split")
+        .arg("-k")
+        .arg("0")
+        .arg("-n")
+        .arg("5")
+        .arg("data/data.txt")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "invalid value '0' for '--threshold <THRESHOLD>'",
+        ));
+}
+
+#[test]
+fn combine_with_insufficient_shares() {
+    let dir = temp_dir("combine-with-insufficient-shares");
+    let ciphertext = split(&dir);
+
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg(dir.join("share-1.asc"))
+        .arg("-s")
+        .arg(dir.join("share-2.asc"))
+        .arg("-s")
+        .arg(dir.join("share-2.asc"))
+        .arg(&ciphertext)
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "could not recover the file key from the key shares",
+        ))
+        .stderr(predicate::str::contains(
+            "at least 3 key shares are required, but 2 were given",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn combine_with_shares_of_other_data() {
+    let dir = temp_dir("combine-with-shares-of-other-data");
+    split(&dir);
+    let other = dir.join("other");
+    std::fs::create_dir_all(&other).unwrap();
+    let ciphertext = split(&other);
+
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg(dir.join("share-1.asc"))
+        .arg("-s")
+        .arg(dir.join("share-2.asc"))
+        .arg("-s")
+        .arg(dir.join("share-3.asc"))
+        .arg(&ciphertext)
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "the key shares do not belong to the encrypted data",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn combine_with_passphrase_encrypted_data() {
+    let dir = temp_dir("combine-with-passphrase-encrypted-data");
+    split(&dir);
+
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg(dir.join("share-1.asc"))
+        .arg("-s")
+        .arg(dir.join("share-2.asc"))
+        .arg("-s")
+        .arg(dir.join("share-3.asc"))
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the encrypted data is not encrypted with the key shares",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn decrypt_split_data() {
+    let dir = temp_dir("decrypt-split-data");
+    let ciphertext = split(&dir);
+
+    utils::command::command()
+        .arg("decrypt")
+        .arg("--passphrase-from-env")
+        .arg("PASSPHRASE")
+        .arg(&ciphertext)
+        .env("PASSPHRASE", "passphrase")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "the encrypted data is encrypted with the key shares, use `abcrypt combine` to decrypt it",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn information_of_split_data() {
+    let dir = temp_dir("information-of-split-data");
+    let ciphertext = split(&dir);
+
+    utils::command::command()
+        .arg("information")
+        .arg(&ciphertext)
+        .assert()
+        .success()
+        .stderr(predicate::eq(
+            "Encrypted with the file key split into the key shares\n",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn information_of_split_data_as_json() {
+    let dir = temp_dir("information-of-split-data-as-json");
+    let ciphertext = split(&dir);
+
+    utils::command::command()
+        .arg("information")
+        .arg("-j")
+        .arg(&ciphertext)
+        .assert()
+        .success()
+        .stdout(predicate::eq(concat!(r#"{"fileKey":true}"#, '\n')));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn combine_with_invalid_share() {
+    utils::command::command()
+        .arg("combine")
+        .arg("-s")
+        .arg("data/data.txt")
+        .arg("data/data.txt")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data/data.txt is not a valid key share",
+        ));
+}
+
+#[test]
+fn combine_without_shares() {
+    utils::command::command()
+        .arg("combine")
+        .arg("data/data.txt")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "the following required arguments were not provided",
+        ));
+}
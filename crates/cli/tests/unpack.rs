@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod utils;
+
+use std::path::{Path, PathBuf};
+
+use predicates::prelude::predicate;
+
+/// Creates a new directory which contains an archive of `data/data.txt` and
+/// `data/armor`.
+fn pack(name: &str) -> (PathBuf, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("abcrypt-cli-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let archive = dir.join("archive.abcrypt");
+
+    utils::command::command()
+        .arg("pack")
+        .arg("-o")
+        .arg(&archive)
+        .arg("-m")
+        .arg("32KiB")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .arg("data/armor")
+        .write_stdin("passphrase")
+        .assert()
+        .success();
+    (dir, archive)
+}
+
+fn read(path: &Path) -> Vec<u8> {
+    std::fs::read(path).unwrap()
+}
+
+#[test]
+fn basic_unpack() {
+    let (dir, archive) = pack("basic-unpack");
+    let output = dir.join("output");
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("-C")
+        .arg(&output)
+        .arg("--passphrase-from-stdin")
+        .arg(&archive)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    assert_eq!(read(&output.join("data.txt")), b"Hello, world!\n");
+    assert_eq!(
+        read(&output.join("armor/data.txt.abcrypt.asc")),
+        read(Path::new("tests/data/armor/data.txt.abcrypt.asc"))
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unpack_selected_entries() {
+    let (dir, archive) = pack("unpack-selected-entries");
+    let output = dir.join("output");
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("-C")
+        .arg(&output)
+        .arg("--passphrase-from-stdin")
+        .arg(&archive)
+        .arg("data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .success();
+    assert_eq!(read(&output.join("data.txt")), b"Hello, world!\n");
+    assert!(!output.join("armor").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unpack_list() {
+    let (dir, archive) = pack("unpack-list");
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("-l")
+        .arg("--passphrase-from-stdin")
+        .arg(&archive)
+        .write_stdin("passphrase")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"^\d{4} +14 \S+Z data.txt\n").unwrap())
+        .stdout(predicate::str::contains(" armor/data.txt.abcrypt.asc\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unpack_non_existent_entry() {
+    let (dir, archive) = pack("unpack-non-existent-entry");
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("-C")
+        .arg(dir.join("output"))
+        .arg("--passphrase-from-stdin")
+        .arg(&archive)
+        .arg("non_existent.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "non_existent.txt does not exist in the archive",
+        ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unpack_if_passphrase_is_incorrect() {
+    let (dir, archive) = pack("unpack-if-passphrase-is-incorrect");
+
+    utils::command::command()
+        .arg("unpack")
+        .arg("--passphrase-from-stdin")
+        .arg(&archive)
+        .write_stdin("password")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains("passphrase is incorrect"))
+        .stderr(predicate::str::contains("invalid header MAC"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unpack_if_input_file_is_not_archive() {
+    utils::command::command()
+        .arg("unpack")
+        .arg("-l")
+        .arg("--passphrase-from-stdin")
+        .arg("data/v1/argon2id/v0x13/data.txt.abcrypt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data is not a valid abcrypt archive",
+        ))
+        .stderr(predicate::str::contains("invalid archive"));
+}
+
+#[test]
+fn unpack_if_input_file_is_invalid() {
+    utils::command::command()
+        .arg("unpack")
+        .arg("--passphrase-from-stdin")
+        .arg("data/data.txt")
+        .write_stdin("passphrase")
+        .assert()
+        .failure()
+        .code(65)
+        .stderr(predicate::str::contains(
+            "data is not a valid abcrypt encrypted file",
+        ));
+}
+
+#[test]
+fn validate_conflicts_between_list_and_entries() {
+    utils::command::command()
+        .arg("unpack")
+        .arg("-l")
+        .arg("data/data.txt")
+        .arg("data.txt")
+        .assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
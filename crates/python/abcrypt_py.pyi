@@ -21,6 +21,14 @@ def encrypt_with_context(
     time_cost: int,
     parallelism: int,
 ) -> bytes: ...
+def encrypt_with_normalization(
+    plaintext: bytes,
+    passphrase: str,
+    memory_cost: int,
+    time_cost: int,
+    parallelism: int,
+    normalization: int,
+) -> bytes: ...
 def decrypt(ciphertext: bytes, passphrase: bytes) -> bytes: ...
 
 class Params:
@@ -35,3 +43,6 @@ class Params:
 class Format:
     HEADER_SIZE: Final[int]
     TAG_SIZE: Final[int]
+
+class AbcryptError(ValueError):
+    kind: str
@@ -4,7 +4,15 @@
 
 //! Error types for this crate.
 
-use pyo3::{PyErr, exceptions::PyValueError};
+use pyo3::{PyErr, Python, create_exception, exceptions::PyValueError, types::PyAnyMethods};
+
+create_exception!(
+    abcrypt_py,
+    AbcryptError,
+    PyValueError,
+    "The error type for the abcrypt encrypted data format.\n\nThe `kind` attribute is the \
+     name of the category of the error, such as `\"InvalidHeaderMac\"`."
+);
 
 /// The error type for the abcrypt encrypted data format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -13,7 +21,13 @@ pub struct Error(abcrypt::Error);
 impl From<Error> for PyErr {
     #[inline]
     fn from(err: Error) -> Self {
-        PyValueError::new_err(err.0.to_string())
+        let kind = err.0.kind().as_str();
+        let err = AbcryptError::new_err(err.0.to_string());
+        Python::with_gil(|py| {
+            err.value(py)
+                .setattr("kind", kind)
+                .map_or_else(|e| e, |()| err)
+        })
     }
 }
 
@@ -9,20 +9,23 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 // Lint levels of Clippy.
-#![allow(clippy::redundant_pub_crate)]
+#![allow(clippy::multiple_crate_versions, clippy::redundant_pub_crate)]
 
 mod error;
 mod params;
 
 use std::borrow::Cow;
 
-use abcrypt::argon2::{self, Algorithm, Version};
+use abcrypt::{
+    Encryptor, Normalization,
+    argon2::{self, Algorithm, Version},
+};
 use pyo3::{
     Bound, PyResult, exceptions::PyValueError, prelude::PyModuleMethods, pyclass, pyfunction,
     pymethods, pymodule, types::PyModule, wrap_pyfunction,
 };
 
-use crate::error::Error;
+use crate::error::{AbcryptError, Error};
 pub use crate::params::Params;
 
 /// Specifications of the abcrypt encrypted data format.
@@ -124,6 +127,48 @@ pub fn encrypt_with_context<'a>(
     Ok(ciphertext.into())
 }
 
+/// Encrypts `plaintext` with the specified Argon2 parameters after normalizing
+/// `passphrase` to the specified Unicode normalization form, and into a newly
+/// allocated `bytes`.
+///
+/// `normalization` is 0 for NFC and 1 for NFKC. The normalization form is
+/// stored in the header, and the passphrase is normalized in the same way when
+/// decrypting. This uses Argon2id as the Argon2 type and version 0x13 as the
+/// Argon2 version.
+///
+/// # Errors
+///
+/// Returns an error if any of the following are true:
+///
+/// - The normalization form is invalid.
+/// - The Argon2 parameters are invalid.
+/// - The Argon2 context is invalid.
+#[inline]
+#[pyfunction]
+pub fn encrypt_with_normalization<'a>(
+    plaintext: &[u8],
+    passphrase: &str,
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    normalization: u8,
+) -> PyResult<Cow<'a, [u8]>> {
+    let normalization = Normalization::try_from(normalization).map_err(Error::from)?;
+    let params = argon2::Params::new(memory_cost, time_cost, parallelism, None)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let ciphertext = Encryptor::with_normalization(
+        &plaintext,
+        passphrase,
+        Algorithm::default(),
+        Version::default(),
+        params,
+        normalization,
+    )
+    .map(|c| c.encrypt_to_vec())
+    .map_err(Error::from)?;
+    Ok(ciphertext.into())
+}
+
 /// Decrypts `ciphertext` and into a newly allocated `bytes`.
 ///
 /// # Errors
@@ -137,6 +182,7 @@ pub fn encrypt_with_context<'a>(
 /// - The Argon2 type is invalid.
 /// - The Argon2 version is invalid.
 /// - The Argon2 parameters are invalid.
+/// - The passphrase normalization form is unrecognized.
 /// - The Argon2 context is invalid.
 /// - The MAC (authentication tag) of the header is invalid.
 /// - The MAC (authentication tag) of the ciphertext is invalid.
@@ -153,8 +199,10 @@ fn abcrypt_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(encrypt, m)?)?;
     m.add_function(wrap_pyfunction!(encrypt_with_params, m)?)?;
     m.add_function(wrap_pyfunction!(encrypt_with_context, m)?)?;
+    m.add_function(wrap_pyfunction!(encrypt_with_normalization, m)?)?;
     m.add_function(wrap_pyfunction!(decrypt, m)?)?;
     m.add_class::<Params>()?;
     m.add_class::<Format>()?;
+    m.add("AbcryptError", m.py().get_type::<AbcryptError>())?;
     Ok(())
 }
@@ -63,19 +63,29 @@ def test_success_from_argon2id_and_v0x13() -> None:
     assert plaintext == TEST_DATA
 
 
+def test_success_with_normalization() -> None:
+    data = Path(TEST_DIR / "data/v2/normalized/data.txt.abcrypt").read_bytes()
+    plaintext = abcrypt_py.decrypt(data, PASSPHRASE)
+    assert plaintext == TEST_DATA
+
+
 def test_incorrect_passphrase() -> None:
     with pytest.raises(ValueError) as e:
         abcrypt_py.decrypt(TEST_DATA_ENC, b"password")
-    assert str(e.value) == "invalid header MAC"
+    assert str(e.value) == (
+        "invalid header MAC (passphrase is incorrect or header is corrupted)"
+    )
+    assert e.value.kind == "InvalidHeaderMac"
 
 
 def test_invalid_input_length_1() -> None:
     data = bytes(
         (abcrypt_py.Format.HEADER_SIZE + abcrypt_py.Format.TAG_SIZE) - 1
     )
-    with pytest.raises(ValueError) as e:
+    with pytest.raises(abcrypt_py.AbcryptError) as e:
         abcrypt_py.decrypt(data, PASSPHRASE)
-    assert str(e.value) == "encrypted data is shorter than 164 bytes"
+    assert str(e.value) == "encrypted data is shorter than 164 bytes (got 163 bytes)"
+    assert e.value.kind == "InvalidLength"
 
 
 def test_invalid_input_length_2() -> None:
@@ -102,10 +112,10 @@ def test_unsupported_version() -> None:
 
 def test_unknown_version() -> None:
     data = bytearray(TEST_DATA_ENC)
-    data[7] = 2
+    data[7] = 4
     with pytest.raises(ValueError) as e:
         abcrypt_py.decrypt(bytes(data), PASSPHRASE)
-    assert str(e.value) == "unknown version number `2`"
+    assert str(e.value) == "unknown version number `4`"
 
 
 def test_invalid_memory_cost() -> None:
@@ -139,7 +149,9 @@ def test_invalid_header_mac() -> None:
     data[84:148] = header_mac
     with pytest.raises(ValueError) as e:
         abcrypt_py.decrypt(bytes(data), PASSPHRASE)
-    assert str(e.value) == "invalid header MAC"
+    assert str(e.value) == (
+        "invalid header MAC (passphrase is incorrect or header is corrupted)"
+    )
 
 
 def test_invalid_mac() -> None:
@@ -148,6 +160,7 @@ def test_invalid_mac() -> None:
     mac = data[start_mac:]
     mac.reverse()
     data[start_mac:] = mac
-    with pytest.raises(ValueError) as e:
+    with pytest.raises(abcrypt_py.AbcryptError) as e:
         abcrypt_py.decrypt(bytes(data), PASSPHRASE)
-    assert str(e.value) == "invalid ciphertext MAC"
+    assert str(e.value) == "invalid ciphertext MAC (corrupted at byte offset 148)"
+    assert e.value.kind == "InvalidMac"
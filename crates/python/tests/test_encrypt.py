@@ -6,6 +6,7 @@ from pathlib import Path
 from typing import Final
 
 import abcrypt_py
+import pytest
 
 PASSPHRASE: Final[bytes] = b"passphrase"
 TEST_DIR: Final[Path] = Path(__file__).resolve().parent
@@ -178,6 +179,37 @@ def test_success_with_context_to_argon2id_and_v0x13() -> None:
     assert plaintext == TEST_DATA
 
 
+def test_success_with_normalization() -> None:
+    ciphertext = abcrypt_py.encrypt_with_normalization(
+        TEST_DATA, "cafe\u0301", 32, 3, 4, 0
+    )
+    assert ciphertext != TEST_DATA
+    assert (
+        len(ciphertext)
+        == len(TEST_DATA)
+        + abcrypt_py.Format.HEADER_SIZE
+        + abcrypt_py.Format.TAG_SIZE
+        + 14
+    )
+
+    params = abcrypt_py.Params(ciphertext)
+    assert params.memory_cost == 32
+    assert params.time_cost == 3
+    assert params.parallelism == 4
+
+    plaintext = abcrypt_py.decrypt(ciphertext, "caf\u00e9".encode())
+    assert plaintext == TEST_DATA
+
+
+def test_invalid_normalization() -> None:
+    with pytest.raises(abcrypt_py.AbcryptError) as e:
+        abcrypt_py.encrypt_with_normalization(
+            TEST_DATA, PASSPHRASE.decode(), 32, 3, 4, 2
+        )
+    assert str(e.value) == "unsupported passphrase normalization form `2`"
+    assert e.value.kind == "UnsupportedNormalization"
+
+
 def test_minimum_output_length() -> None:
     ciphertext = abcrypt_py.encrypt_with_params(b"", PASSPHRASE, 32, 3, 4)
     assert (
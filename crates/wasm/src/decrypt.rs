@@ -4,7 +4,9 @@
 
 //! Decrypts from the abcrypt encrypted data format.
 
-use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::Error;
 
 /// Decrypts `ciphertext` and into a newly allocated `Uint8Array`.
 ///
@@ -23,6 +25,6 @@ use wasm_bindgen::{JsError, prelude::wasm_bindgen};
 /// - The MAC (authentication tag) of the header is invalid.
 /// - The MAC (authentication tag) of the ciphertext is invalid.
 #[wasm_bindgen]
-pub fn decrypt(ciphertext: &[u8], passphrase: &[u8]) -> Result<Vec<u8>, JsError> {
-    abcrypt::decrypt(ciphertext, passphrase).map_err(JsError::from)
+pub fn decrypt(ciphertext: &[u8], passphrase: &[u8]) -> Result<Vec<u8>, Error> {
+    abcrypt::decrypt(ciphertext, passphrase).map_err(Error::from)
 }
@@ -5,10 +5,12 @@
 //! Encrypts to the abcrypt encrypted data format.
 
 use abcrypt::{
-    Error,
-    argon2::{Algorithm, Params},
+    Encryptor, Normalization,
+    argon2::{Algorithm, Params, Version},
 };
-use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::Error;
 
 /// Encrypts `plaintext` and into a newly allocated `Uint8Array`.
 ///
@@ -20,8 +22,8 @@ use wasm_bindgen::{JsError, prelude::wasm_bindgen};
 ///
 /// Returns an error if the Argon2 context is invalid.
 #[wasm_bindgen]
-pub fn encrypt(plaintext: &[u8], passphrase: &[u8]) -> Result<Vec<u8>, JsError> {
-    abcrypt::encrypt(plaintext, passphrase).map_err(JsError::from)
+pub fn encrypt(plaintext: &[u8], passphrase: &[u8]) -> Result<Vec<u8>, Error> {
+    abcrypt::encrypt(plaintext, passphrase).map_err(Error::from)
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -44,9 +46,10 @@ pub fn encrypt_with_params(
     memory_cost: u32,
     time_cost: u32,
     parallelism: u32,
-) -> Result<Vec<u8>, JsError> {
-    let params = Params::new(memory_cost, time_cost, parallelism, None)?;
-    abcrypt::encrypt_with_params(plaintext, passphrase, params).map_err(JsError::from)
+) -> Result<Vec<u8>, Error> {
+    let params = Params::new(memory_cost, time_cost, parallelism, None)
+        .map_err(abcrypt::Error::InvalidArgon2Params)?;
+    abcrypt::encrypt_with_params(plaintext, passphrase, params).map_err(Error::from)
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -70,15 +73,59 @@ pub fn encrypt_with_context(
     memory_cost: u32,
     time_cost: u32,
     parallelism: u32,
-) -> Result<Vec<u8>, JsError> {
+) -> Result<Vec<u8>, Error> {
     let argon2_type = match argon2_type {
         0 => Ok(Algorithm::Argon2d),
         1 => Ok(Algorithm::Argon2i),
         2 => Ok(Algorithm::Argon2id),
-        t => Err(Error::InvalidArgon2Type(t)),
+        t => Err(abcrypt::Error::InvalidArgon2Type(t)),
     }?;
-    let argon2_version = argon2_version.try_into()?;
-    let params = Params::new(memory_cost, time_cost, parallelism, None)?;
+    let argon2_version = argon2_version
+        .try_into()
+        .map_err(|_| abcrypt::Error::InvalidArgon2Version(argon2_version))?;
+    let params = Params::new(memory_cost, time_cost, parallelism, None)
+        .map_err(abcrypt::Error::InvalidArgon2Params)?;
     abcrypt::encrypt_with_context(plaintext, passphrase, argon2_type, argon2_version, params)
-        .map_err(JsError::from)
+        .map_err(Error::from)
+}
+
+#[allow(clippy::module_name_repetitions)]
+/// Encrypts `plaintext` with the specified Argon2 parameters after normalizing
+/// `passphrase` to the specified Unicode normalization form, and into a newly
+/// allocated `Uint8Array`.
+///
+/// `normalization` is 0 for NFC and 1 for NFKC. The normalization form is
+/// stored in the header, and the passphrase is normalized in the same way when
+/// decrypting. This uses Argon2id as the Argon2 type and version 0x13 as the
+/// Argon2 version.
+///
+/// # Errors
+///
+/// Returns an error if any of the following are true:
+///
+/// - The normalization form is invalid.
+/// - The Argon2 parameters are invalid.
+/// - The Argon2 context is invalid.
+#[wasm_bindgen(js_name = encryptWithNormalization)]
+pub fn encrypt_with_normalization(
+    plaintext: &[u8],
+    passphrase: &str,
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    normalization: u8,
+) -> Result<Vec<u8>, Error> {
+    let normalization = Normalization::try_from(normalization)?;
+    let params = Params::new(memory_cost, time_cost, parallelism, None)
+        .map_err(abcrypt::Error::InvalidArgon2Params)?;
+    Encryptor::with_normalization(
+        &plaintext,
+        passphrase,
+        Algorithm::default(),
+        Version::default(),
+        params,
+        normalization,
+    )
+    .map(|c| c.encrypt_to_vec())
+    .map_err(Error::from)
 }
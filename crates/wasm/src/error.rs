@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: 2026 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Error types for this crate.
+
+use wasm_bindgen::JsValue;
+
+/// The error type for the abcrypt encrypted data format.
+///
+/// This is thrown as a JavaScript `Error` whose `name` property is the kind of
+/// the error, such as `"InvalidHeaderMac"`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Error(abcrypt::Error);
+
+impl From<Error> for JsValue {
+    #[inline]
+    fn from(err: Error) -> Self {
+        let error = js_sys::Error::new(&err.0.to_string());
+        error.set_name(err.0.kind().as_str());
+        error.into()
+    }
+}
+
+impl From<abcrypt::Error> for Error {
+    #[inline]
+    fn from(err: abcrypt::Error) -> Self {
+        Self(err)
+    }
+}
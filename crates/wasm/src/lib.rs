@@ -8,16 +8,20 @@
 // Lint levels of rustc.
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
+// Lint levels of Clippy.
+#![allow(clippy::multiple_crate_versions)]
 
 mod decrypt;
 mod encrypt;
+mod error;
 mod params;
 
 use wasm_bindgen::prelude::wasm_bindgen;
 
 pub use crate::{
     decrypt::decrypt,
-    encrypt::{encrypt, encrypt_with_context, encrypt_with_params},
+    encrypt::{encrypt, encrypt_with_context, encrypt_with_normalization, encrypt_with_params},
+    error::Error,
     params::Params,
 };
 
@@ -4,7 +4,9 @@
 
 //! The Argon2 parameters.
 
-use wasm_bindgen::{JsError, prelude::wasm_bindgen};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::Error;
 
 /// The Argon2 parameters used for the encrypted data.
 #[derive(Clone, Copy, Debug)]
@@ -27,10 +29,10 @@ impl Params {
     /// - The Argon2 version is invalid.
     /// - The Argon2 parameters are invalid.
     #[wasm_bindgen(constructor)]
-    pub fn new(ciphertext: &[u8]) -> Result<Self, JsError> {
+    pub fn new(ciphertext: &[u8]) -> Result<Self, Error> {
         abcrypt::Params::new(ciphertext)
             .map(Self)
-            .map_err(JsError::from)
+            .map_err(Error::from)
     }
 
     #[allow(clippy::missing_const_for_fn)]
@@ -65,6 +65,17 @@ fn success() {
     }
 }
 
+#[wasm_bindgen_test]
+fn success_with_normalization() {
+    let plaintext = abcrypt_wasm::decrypt(
+        include_bytes!("data/v2/normalized/data.txt.abcrypt"),
+        PASSPHRASE,
+    )
+    .map_err(JsValue::from)
+    .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
 #[wasm_bindgen_test]
 fn incorrect_passphrase() {
     let result = abcrypt_wasm::decrypt(TEST_DATA_ENC, b"password");
@@ -104,7 +115,7 @@ fn unsupported_version() {
 #[wasm_bindgen_test]
 fn unknown_version() {
     let mut data: [u8; TEST_DATA_ENC.len()] = TEST_DATA_ENC.try_into().unwrap();
-    data[7] = 2;
+    data[7] = 4;
     let result = abcrypt_wasm::decrypt(&data, PASSPHRASE);
     assert!(result.is_err());
 }
@@ -183,6 +183,35 @@ fn success_with_context() {
     }
 }
 
+#[wasm_bindgen_test]
+fn success_with_normalization() {
+    let ciphertext =
+        abcrypt_wasm::encrypt_with_normalization(TEST_DATA, "cafe\u{301}", 32, 3, 4, 0)
+            .map_err(JsValue::from)
+            .unwrap();
+    assert_ne!(ciphertext, TEST_DATA);
+    assert_eq!(
+        ciphertext.len(),
+        TEST_DATA.len() + abcrypt_wasm::header_size() + abcrypt_wasm::tag_size() + 14
+    );
+
+    let params = Params::new(&ciphertext).map_err(JsValue::from).unwrap();
+    assert_eq!(params.memory_cost(), 32);
+    assert_eq!(params.time_cost(), 3);
+    assert_eq!(params.parallelism(), 4);
+
+    let plaintext = abcrypt_wasm::decrypt(&ciphertext, "caf\u{e9}".as_bytes())
+        .map_err(JsValue::from)
+        .unwrap();
+    assert_eq!(plaintext, TEST_DATA);
+}
+
+#[wasm_bindgen_test]
+fn invalid_normalization() {
+    let result = abcrypt_wasm::encrypt_with_normalization(TEST_DATA, "passphrase", 32, 3, 4, 2);
+    assert!(result.is_err());
+}
+
 #[wasm_bindgen_test]
 fn minimum_output_length() {
     let ciphertext = abcrypt_wasm::encrypt_with_params(&[], PASSPHRASE, 32, 3, 4)